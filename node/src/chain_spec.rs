@@ -10,7 +10,7 @@ use pontem_runtime::{
     GenesisConfig, SudoConfig, SystemConfig, BalancesConfig, WASM_BINARY, ParachainInfoConfig,
     VestingConfig, MvmConfig, TransactionPauseConfig, ParachainStakingConfig, InflationInfo,
     Range, AuthorFilterConfig, AuthorMappingConfig, TreasuryConfig, TokensConfig,
-    DemocracyConfig, PolkadotXcmConfig, EligibilityValue,
+    DemocracyConfig, PolkadotXcmConfig, EligibilityValue, CurrenciesConfig, KnownCurrencies, Get,
 };
 use primitives::{currency::CurrencyId, AccountId, Signature, Balance, BlockNumber};
 use constants::SS58_PREFIX;
@@ -121,13 +121,21 @@ fn nox_bootnodes() -> Vec<MultiaddrWithPeerId> {
 }
 
 /// The list of paused extrinsics (mostly used for Nox mainnet).
-fn paused_extrinsics() -> Vec<(Vec<u8>, Vec<u8>)> {
+fn paused_extrinsics() -> Vec<(Vec<u8>, Vec<u8>, Option<BlockNumber>)> {
     vec![
         (
             "Balances",
             vec!["transfer", "transfer_all", "transfer_keep_alive"],
         ),
-        ("Currencies", vec!["transfer", "transfer_native_currency"]),
+        (
+            "Currencies",
+            vec![
+                "transfer",
+                "transfer_native_currency",
+                "transfer_batch",
+                "transfer_all",
+            ],
+        ),
         (
             "Vesting",
             vec!["merge_schedules", "vest", "vest_other", "vested_transfer"],
@@ -164,7 +172,7 @@ fn paused_extrinsics() -> Vec<(Vec<u8>, Vec<u8>)> {
                 "cancel_as_multi",
             ],
         ),
-        ("Groupsign", vec!["groupsign_call"]),
+        ("Groupsign", vec!["groupsign_call", "groupsign_batch_call"]),
         (
             "Democracy",
             vec!["propose", "note_preimage", "note_imminent_preimage"],
@@ -178,8 +186,8 @@ fn paused_extrinsics() -> Vec<(Vec<u8>, Vec<u8>)> {
     .flat_map(|i| {
         let pallet_name = i.0.as_bytes().to_vec();
         i.1.iter()
-            .map(|ex_name| (pallet_name.clone(), ex_name.as_bytes().to_vec()))
-            .collect::<Vec<(Vec<u8>, Vec<u8>)>>()
+            .map(|ex_name| (pallet_name.clone(), ex_name.as_bytes().to_vec(), None))
+            .collect::<Vec<(Vec<u8>, Vec<u8>, Option<BlockNumber>)>>()
     })
     .collect()
 }
@@ -204,7 +212,7 @@ pub fn development_config() -> Result<ChainSpec, String> {
                 vec![(
                     get_account_id_from_seed::<sr25519::Public>("Alice"),
                     get_from_seed::<NimbusId>("Alice"),
-                    CurrencyId::NATIVE * 10_000,
+                    CurrencyId::native_times(10_000),
                 )],
                 // Nominators
                 vec![],
@@ -212,19 +220,19 @@ pub fn development_config() -> Result<ChainSpec, String> {
                 vec![
                     (
                         get_account_id_from_seed::<sr25519::Public>("Alice"),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     (
                         get_account_id_from_seed::<sr25519::Public>("Bob"),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     (
                         get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     (
                         get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                 ],
                 // Vesting
@@ -232,7 +240,7 @@ pub fn development_config() -> Result<ChainSpec, String> {
                     get_account_id_from_seed::<sr25519::Public>("Bob"),
                     1000,
                     150,
-                    CurrencyId::NATIVE * 50_000,
+                    CurrencyId::native_times(50_000),
                 )],
                 // Paused extrinsics
                 vec![],
@@ -278,7 +286,7 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
                 vec![(
                     get_account_id_from_seed::<sr25519::Public>("Alice"),
                     get_from_seed::<NimbusId>("Alice"),
-                    CurrencyId::NATIVE * 10_000,
+                    CurrencyId::native_times(10_000),
                 )],
                 // Nominators
                 vec![],
@@ -286,27 +294,27 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
                 vec![
                     (
                         get_account_id_from_seed::<sr25519::Public>("Alice"),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     (
                         get_account_id_from_seed::<sr25519::Public>("Bob"),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     (
                         get_account_id_from_seed::<sr25519::Public>("Charlie"),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     (
                         get_account_id_from_seed::<sr25519::Public>("Dave"),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     (
                         get_account_id_from_seed::<sr25519::Public>("Eve"),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     (
                         get_account_id_from_seed::<sr25519::Public>("Ferdie"),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                 ],
                 // Vesting accounts
@@ -315,13 +323,13 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
                         get_account_id_from_seed::<sr25519::Public>("Bob"),
                         1000,
                         150,
-                        CurrencyId::NATIVE * 50_000,
+                        CurrencyId::native_times(50_000),
                     ),
                     (
                         get_account_id_from_seed::<sr25519::Public>("Charlie"),
                         1000,
                         150,
-                        CurrencyId::NATIVE * 50_000,
+                        CurrencyId::native_times(50_000),
                     ),
                 ],
                 // Paused extrinsics
@@ -374,7 +382,7 @@ pub fn westend_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkLsuHAWUiJL8tCrSYMKJjBBNyyZF2TFSs1tcTcsyHpD6x7Lr",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 2.
                     (
@@ -384,7 +392,7 @@ pub fn westend_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkPp7Scc7zPvdPfA7YHWxsxtrzLPEW4AodGRZz9U6vqd5LFtf",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 3.
                     (
@@ -394,7 +402,7 @@ pub fn westend_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkLkCGJohbgtNfXi9TkyxscHEodLvPzVUZ28MfCybvU6vN4Xn",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 4.
                     (
@@ -404,7 +412,7 @@ pub fn westend_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkR2sZmh7tS2KgQLsByjUFHMukmGJwKgcBUshxNRAPXV5ZcZL",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                 ],
                 // Nominators
@@ -417,42 +425,42 @@ pub fn westend_config() -> Result<ChainSpec, String> {
                         get_account_id_from_address(
                             "gkLsuHAWUiJL8tCrSYMKJjBBNyyZF2TFSs1tcTcsyHpD6x7Lr",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 2.
                         get_account_id_from_address(
                             "gkPp7Scc7zPvdPfA7YHWxsxtrzLPEW4AodGRZz9U6vqd5LFtf",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 3.
                         get_account_id_from_address(
                             "gkLkCGJohbgtNfXi9TkyxscHEodLvPzVUZ28MfCybvU6vN4Xn",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 4.
                         get_account_id_from_address(
                             "gkR2sZmh7tS2KgQLsByjUFHMukmGJwKgcBUshxNRAPXV5ZcZL",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Sudo.
                         get_account_id_from_address(
                             "gkPQdcMrECsnUbVnCqTUuTaS9o72LM179rmRu3hzkC5zovUgB",
                         ),
-                        CurrencyId::NATIVE * 10_000,
+                        CurrencyId::native_times(10_000),
                     ),
                     (
                         // Bank.
                         get_account_id_from_address(
                             "gkLdwjgcSFtoEvKbsgLuFBc2k6TgZxgrfj61CjcduCvgyKeux",
                         ),
-                        CurrencyId::NATIVE * 200_000,
+                        CurrencyId::native_times(200_000),
                     ),
                 ],
                 // Vesting accounts
@@ -508,7 +516,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkPie4Vc57KSTDNmG7vyZRCHuuFbnx7m64AqrSgcG8hejuemS",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 2.
                     (
@@ -518,7 +526,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkQMs9aemyMsFJWBBes95pkLD5dQ6Vture2PwEPBWJ8y4ubuR",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 3.
                     (
@@ -528,7 +536,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkMeEtHVsBL7MSrdQCJnbEvwZ3XGPAeH3ojL72WTDPL46EpET",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 4.
                     (
@@ -538,7 +546,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkLCjGZNEmLKoMrACf3Av8VNS8WzRi3cwKxAScYfxBXZpUpi1",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 5.
                     (
@@ -548,7 +556,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkQ3Hcy3Lk954hV2NtY8MGxP3MtVemSepEGChhuKk7UuHLALB",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 6.
                     (
@@ -558,7 +566,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkQfFjHhMitdXnJXU3SLy2Fmc2F2fW4n2BbZPzu2UVvKGaFc8",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 7.
                     (
@@ -568,7 +576,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkL1f4fX4PAhFLpMrfVy8BXB3dfArbYvkZxQ8hpujKqwmU5sK",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 8.
                     (
@@ -578,7 +586,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkN1FFFwf3YuSE283f52mjnTQthri5goZkaXhGUmsjkRxJKrN",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 9.
                     (
@@ -588,7 +596,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkQUtpCMfemhhXCB2Mk9TEamDfjbC2GVEzSzCusMKkreooGzq",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 10.
                     (
@@ -598,7 +606,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkLX45RaBmFm1uJzskr6WktGiWRgENpqQMYTDLZfrahv177yH",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                     // Node 11.
                     (
@@ -608,7 +616,7 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_public_from_address::<NimbusId>(
                             "gkQb84kxpjeytTCJ12DPxf2Fi8nmsZWqJLTRv8U78RE7fGMY5",
                         ),
-                        CurrencyId::NATIVE * 100_000,
+                        CurrencyId::native_times(100_000),
                     ),
                 ],
                 // Nominators
@@ -621,84 +629,84 @@ pub fn nox_config() -> Result<ChainSpec, String> {
                         get_account_id_from_address(
                             "gkPie4Vc57KSTDNmG7vyZRCHuuFbnx7m64AqrSgcG8hejuemS",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 2.
                         get_account_id_from_address(
                             "gkQMs9aemyMsFJWBBes95pkLD5dQ6Vture2PwEPBWJ8y4ubuR",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 3.
                         get_account_id_from_address(
                             "gkMeEtHVsBL7MSrdQCJnbEvwZ3XGPAeH3ojL72WTDPL46EpET",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 4.
                         get_account_id_from_address(
                             "gkLCjGZNEmLKoMrACf3Av8VNS8WzRi3cwKxAScYfxBXZpUpi1",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 5.
                         get_account_id_from_address(
                             "gkQ3Hcy3Lk954hV2NtY8MGxP3MtVemSepEGChhuKk7UuHLALB",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         //Node 6.
                         get_account_id_from_address(
                             "gkQfFjHhMitdXnJXU3SLy2Fmc2F2fW4n2BbZPzu2UVvKGaFc8",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 7.
                         get_account_id_from_address(
                             "gkL1f4fX4PAhFLpMrfVy8BXB3dfArbYvkZxQ8hpujKqwmU5sK",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 8.
                         get_account_id_from_address(
                             "gkN1FFFwf3YuSE283f52mjnTQthri5goZkaXhGUmsjkRxJKrN",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 9.
                         get_account_id_from_address(
                             "gkQUtpCMfemhhXCB2Mk9TEamDfjbC2GVEzSzCusMKkreooGzq",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 10.
                         get_account_id_from_address(
                             "gkLX45RaBmFm1uJzskr6WktGiWRgENpqQMYTDLZfrahv177yH",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Node 11.
                         get_account_id_from_address(
                             "gkQb84kxpjeytTCJ12DPxf2Fi8nmsZWqJLTRv8U78RE7fGMY5",
                         ),
-                        CurrencyId::NATIVE * 110_000,
+                        CurrencyId::native_times(110_000),
                     ),
                     (
                         // Sudo.
                         get_account_id_from_address(
                             "gkPQdcMrECsnUbVnCqTUuTaS9o72LM179rmRu3hzkC5zovUgB",
                         ),
-                        CurrencyId::NATIVE * 10_000,
+                        CurrencyId::native_times(10_000),
                     ),
                 ],
                 // Vesting accounts
@@ -735,7 +743,7 @@ fn genesis(
     delegations: Vec<(AccountId, AccountId, Balance)>,
     balances: Vec<(AccountId, Balance)>,
     vesting: Vec<(AccountId, BlockNumber, BlockNumber, Balance)>,
-    paused: Vec<(Vec<u8>, Vec<u8>)>,
+    paused: Vec<(Vec<u8>, Vec<u8>, Option<BlockNumber>)>,
     id: ParaId,
 ) -> GenesisConfig {
     let (init_module, init_func, init_args) = build_vm_config();
@@ -747,6 +755,9 @@ fn genesis(
 
     GenesisConfig {
         tokens: TokensConfig { balances: vec![] },
+        currencies: CurrenciesConfig {
+            currencies: KnownCurrencies::get(),
+        },
         system: SystemConfig {
             // Add Wasm runtime to storage.
             code: wasm_binary.to_vec(),
@@ -807,9 +818,9 @@ pub fn pontem_inflation_config() -> InflationInfo<Balance> {
     InflationInfo {
         // How much staked coins we expect.
         expect: Range {
-            min: CurrencyId::NATIVE * 10_000_000, // We expect to have staked at least 10M coins.
-            ideal: CurrencyId::NATIVE * 20_000_000, // We expect to have staked ideal 20M coins.
-            max: CurrencyId::NATIVE * 50_000_000, // We expect to have staked maximum 50M coins.
+            min: CurrencyId::native_times(10_000_000), // We expect to have staked at least 10M coins.
+            ideal: CurrencyId::native_times(20_000_000), // We expect to have staked ideal 20M coins.
+            max: CurrencyId::native_times(50_000_000), // We expect to have staked maximum 50M coins.
         },
         annual: Range {
             min: Perbill::from_percent(4),   // We expect minimum inflation is 4%.
@@ -6,7 +6,7 @@
 #![warn(missing_docs)]
 
 use std::sync::Arc;
-use primitives::{AccountId, Balance, Index};
+use primitives::{AccountId, Balance, BlockNumber, Index, currency::CurrencyId};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_block_builder::BlockBuilder;
@@ -14,6 +14,12 @@ pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_mvm_rpc_runtime::MVMApiRuntime;
 use sp_mvm_rpc::{MVMApiRpc, MVMApi};
+use transaction_pause_rpc_runtime::TransactionPauseApiRuntime;
+use transaction_pause_rpc::{TransactionPauseApiRpc, TransactionPauseApi};
+use currencies_rpc_runtime::CurrenciesApiRuntime;
+use currencies_rpc::{CurrenciesApiRpc, CurrenciesApi};
+use parachain_staking_rpc_runtime::ParachainStakingApiRuntime;
+use parachain_staking_rpc::{ParachainStakingApiRpc, ParachainStakingApi};
 
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
@@ -33,6 +39,9 @@ where
     C: ProvideRuntimeApi<B>,
     C: HeaderBackend<B>,
     C::Api: MVMApiRuntime<B, AccountId>,
+    C::Api: CurrenciesApiRuntime<B, AccountId, CurrencyId, Balance>,
+    C::Api: ParachainStakingApiRuntime<B, AccountId, Balance>,
+    C::Api: TransactionPauseApiRuntime<B, BlockNumber>,
     C::Api: BlockBuilder<B>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<B, Balance>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<B, AccountId, Index>,
@@ -65,5 +74,17 @@ where
 
     io.extend_with(MVMApiRpc::to_delegate(MVMApi::new(client.clone())));
 
+    io.extend_with(CurrenciesApiRpc::to_delegate(CurrenciesApi::new(
+        client.clone(),
+    )));
+
+    io.extend_with(ParachainStakingApiRpc::to_delegate(
+        ParachainStakingApi::new(client.clone()),
+    ));
+
+    io.extend_with(TransactionPauseApiRpc::to_delegate(
+        TransactionPauseApi::new(client.clone()),
+    ));
+
     io
 }
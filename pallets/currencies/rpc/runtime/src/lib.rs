@@ -0,0 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+// clippy doesn't likes sp- macros
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use sp_std::prelude::*;
+pub use module_currencies::{CurrencyBalance, CurrencyMetadata};
+
+// Describe Runtime API for Currencies module.
+sp_api::decl_runtime_apis! {
+    #[api_version(2)]
+    pub trait CurrenciesApiRuntime<AccountId, CurrencyId, Balance> where
+        AccountId: codec::Codec,
+        CurrencyId: codec::Codec,
+        Balance: codec::Codec,
+    {
+        // Get metadata of the registered currency.
+        fn currency_metadata(currency_id: CurrencyId) -> Option<CurrencyMetadata<Balance>>;
+
+        // Get metadata of all registered currencies.
+        fn currencies_metadata() -> Vec<(CurrencyId, CurrencyMetadata<Balance>)>;
+
+        // Get balances of the account in all currencies it holds.
+        fn balances(who: AccountId) -> Vec<(CurrencyId, CurrencyBalance<Balance>)>;
+    }
+}
@@ -0,0 +1,139 @@
+use std::{fmt::Display, str::FromStr, sync::Arc};
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT},
+};
+use sp_api::ProvideRuntimeApi;
+use currencies_rpc_runtime::CurrenciesApiRuntime;
+use serde::{Serialize, Deserialize};
+
+// Balance lock struct with serde.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(bound(serialize = "Balance: Display", deserialize = "Balance: FromStr"))]
+pub struct BalanceLock<Balance> {
+    pub id: String,
+    #[serde(with = "as_string")]
+    pub amount: Balance,
+}
+
+// Currency balance struct with serde, balances are strings as they don't fit JSON numbers.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(bound(
+    serialize = "CurrencyId: Serialize, Balance: Display",
+    deserialize = "CurrencyId: Deserialize<'de>, Balance: FromStr"
+))]
+pub struct CurrencyBalance<CurrencyId, Balance> {
+    pub currency_id: CurrencyId,
+    #[serde(with = "as_string")]
+    pub free: Balance,
+    #[serde(with = "as_string")]
+    pub reserved: Balance,
+    #[serde(with = "as_string")]
+    pub frozen: Balance,
+    pub locks: Vec<BalanceLock<Balance>>,
+}
+
+impl<CurrencyId, Balance> From<(CurrencyId, currencies_rpc_runtime::CurrencyBalance<Balance>)>
+    for CurrencyBalance<CurrencyId, Balance>
+{
+    fn from(
+        (currency_id, balance): (CurrencyId, currencies_rpc_runtime::CurrencyBalance<Balance>),
+    ) -> Self {
+        Self {
+            currency_id,
+            free: balance.free,
+            reserved: balance.reserved,
+            frozen: balance.frozen,
+            locks: balance
+                .locks
+                .into_iter()
+                .map(|(id, amount)| BalanceLock {
+                    id: String::from_utf8_lossy(&id).into(),
+                    amount,
+                })
+                .collect(),
+        }
+    }
+}
+
+mod as_string {
+    use super::*;
+    use serde::{de::Error, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, T: Display>(
+        value: &T,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, T: FromStr>(
+        deserializer: D,
+    ) -> std::result::Result<T, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value
+            .parse()
+            .map_err(|_| D::Error::custom("can't parse balance"))
+    }
+}
+
+// RPC calls.
+#[rpc]
+pub trait CurrenciesApiRpc<BlockHash, AccountId, ResponseType> {
+    #[rpc(name = "currencies_getBalances")]
+    fn balances(&self, who: AccountId, at: Option<BlockHash>) -> Result<ResponseType>;
+}
+
+pub struct CurrenciesApi<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> CurrenciesApi<C, P> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, CurrencyId, Balance>
+    CurrenciesApiRpc<
+        <Block as BlockT>::Hash,
+        AccountId,
+        Vec<CurrencyBalance<CurrencyId, Balance>>,
+    > for CurrenciesApi<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec,
+    CurrencyId: Codec + Serialize + Send + Sync + 'static,
+    Balance: Codec + Display + FromStr + Send + Sync + 'static,
+    C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: CurrenciesApiRuntime<Block, AccountId, CurrencyId, Balance>,
+{
+    fn balances(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<CurrencyBalance<CurrencyId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api.balances(&at, who).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during requesting Runtime API".into(),
+            data: Some(format!("{:?}", e).into()),
+        })?;
+
+        Ok(res.into_iter().map(CurrencyBalance::from).collect())
+    }
+}
@@ -0,0 +1,165 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarks of the currencies module.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
+use xcm::latest::{Junction, Junctions::X1};
+
+const SEED: u32 = 0;
+
+/// Amount of `currency_id` transferred to a new account.
+fn transfer_amount<T: Config>(currency_id: CurrencyIdOf<T>) -> BalanceOf<T> {
+    Pallet::<T>::minimum_balance(currency_id)
+        .max(1u32.into())
+        .saturating_mul(10u32.into())
+}
+
+/// Create an account funded with `amount` of `currency_id`.
+fn funded_caller<T: Config>(currency_id: CurrencyIdOf<T>, amount: BalanceOf<T>) -> T::AccountId {
+    let caller: T::AccountId = whitelisted_caller();
+    <Pallet<T> as MultiCurrency<T::AccountId>>::deposit(currency_id, &caller, amount).unwrap();
+    caller
+}
+
+/// Register a currency other than the native one, with existential deposit of one unit.
+fn registered_currency<T: Config>() -> Result<CurrencyIdOf<T>, &'static str> {
+    let currency_id = T::BenchmarkHelper::registrable_currency();
+    Pallet::<T>::set_metadata(currency_id, currency_metadata::<T>(1000))?;
+    Ok(currency_id)
+}
+
+/// Metadata with the longest name and symbol, located at sibling parachain `para_id`.
+fn currency_metadata<T: Config>(para_id: u32) -> CurrencyMetadata<BalanceOf<T>> {
+    let string = sp_std::vec![b'x'; T::StringLimit::get() as usize];
+    CurrencyMetadata {
+        name: string.clone(),
+        symbol: string,
+        decimals: 12,
+        existential_deposit: 1u32.into(),
+        location: Some(MultiLocation::new(1, X1(Junction::Parachain(para_id))).into()),
+    }
+}
+
+benchmarks! {
+    transfer_non_native_currency {
+        let currency_id = registered_currency::<T>()?;
+        let amount = transfer_amount::<T>(currency_id);
+        let caller = funded_caller::<T>(currency_id, amount);
+        let dest: T::AccountId = account("dest", 0, SEED);
+    }: transfer(RawOrigin::Signed(caller), T::Lookup::unlookup(dest.clone()), currency_id, amount)
+    verify {
+        assert_eq!(Pallet::<T>::free_balance(currency_id, &dest), amount);
+    }
+
+    transfer_native_currency {
+        let currency_id = T::GetNativeCurrencyId::get();
+        let amount = transfer_amount::<T>(currency_id);
+        let caller = funded_caller::<T>(currency_id, amount);
+        let dest: T::AccountId = account("dest", 0, SEED);
+    }: _(RawOrigin::Signed(caller), T::Lookup::unlookup(dest.clone()), amount)
+    verify {
+        assert_eq!(Pallet::<T>::free_balance(currency_id, &dest), amount);
+    }
+
+    transfer_batch {
+        let c in 1 .. T::MaxBatchTransfers::get();
+
+        let currency_id = T::GetNativeCurrencyId::get();
+        let amount = transfer_amount::<T>(currency_id);
+        let caller = funded_caller::<T>(currency_id, amount.saturating_mul((c + 1).into()));
+        let transfers: Vec<_> = (0..c)
+            .map(|i| (T::Lookup::unlookup(account("dest", i, SEED)), currency_id, amount))
+            .collect();
+    }: _(RawOrigin::Signed(caller), transfers)
+    verify {
+        for i in 0..c {
+            let dest: T::AccountId = account("dest", i, SEED);
+            assert_eq!(Pallet::<T>::free_balance(currency_id, &dest), amount);
+        }
+    }
+
+    transfer_all {
+        let currency_id = T::GetNativeCurrencyId::get();
+        let amount = transfer_amount::<T>(currency_id);
+        let caller = funded_caller::<T>(currency_id, amount);
+        let dest: T::AccountId = account("dest", 0, SEED);
+    }: _(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(dest.clone()), currency_id, false)
+    verify {
+        assert_eq!(Pallet::<T>::free_balance(currency_id, &caller), Zero::zero());
+        assert_eq!(Pallet::<T>::free_balance(currency_id, &dest), amount);
+    }
+
+    update_balance_non_native_currency {
+        let currency_id = registered_currency::<T>()?;
+        let amount = transfer_amount::<T>(currency_id);
+        let update = AmountOf::<T>::try_from(amount).map_err(|_| "amount overflow")?;
+        let dest: T::AccountId = account("dest", 0, SEED);
+    }: update_balance(RawOrigin::Root, T::Lookup::unlookup(dest.clone()), currency_id, update)
+    verify {
+        assert_eq!(Pallet::<T>::free_balance(currency_id, &dest), amount);
+    }
+
+    update_balance_native_currency_creating {
+        let currency_id = T::GetNativeCurrencyId::get();
+        let amount = transfer_amount::<T>(currency_id);
+        let update = AmountOf::<T>::try_from(amount).map_err(|_| "amount overflow")?;
+        let dest: T::AccountId = account("dest", 0, SEED);
+    }: update_balance(RawOrigin::Root, T::Lookup::unlookup(dest.clone()), currency_id, update)
+    verify {
+        assert_eq!(Pallet::<T>::free_balance(currency_id, &dest), amount);
+    }
+
+    update_balance_native_currency_killing {
+        let currency_id = T::GetNativeCurrencyId::get();
+        let amount = transfer_amount::<T>(currency_id);
+        let update = AmountOf::<T>::try_from(amount).map_err(|_| "amount overflow")?;
+        let dest = funded_caller::<T>(currency_id, amount);
+    }: update_balance(RawOrigin::Root, T::Lookup::unlookup(dest.clone()), currency_id, -update)
+    verify {
+        assert_eq!(Pallet::<T>::free_balance(currency_id, &dest), Zero::zero());
+    }
+
+    sweep_dust {
+        let c in 1 .. T::MaxDustSweepAccounts::get().max(1);
+
+        let currency_id = registered_currency::<T>()?;
+        let amount = transfer_amount::<T>(currency_id);
+        let accounts: Vec<T::AccountId> = (0..c).map(|i| account("dust", i, SEED)).collect();
+        for who in &accounts {
+            <Pallet<T> as MultiCurrency<T::AccountId>>::deposit(currency_id, who, amount)?;
+        }
+        // Balances become dust when existential deposit is raised above them.
+        let mut metadata = currency_metadata::<T>(1000);
+        metadata.existential_deposit = amount.saturating_add(1u32.into());
+        Pallet::<T>::set_metadata(currency_id, metadata)?;
+        let origin = T::SweepOrigin::successful_origin();
+    }: _<T::Origin>(origin, currency_id, accounts)
+
+    register_currency {
+        let origin = T::RegistryOrigin::successful_origin();
+        let currency_id = T::BenchmarkHelper::registrable_currency();
+        let metadata = currency_metadata::<T>(1000);
+    }: _<T::Origin>(origin, currency_id, metadata.clone())
+    verify {
+        assert_eq!(Pallet::<T>::currency_metadata(currency_id), Some(metadata));
+    }
+
+    update_currency_metadata {
+        let origin = T::RegistryOrigin::successful_origin();
+        let currency_id = T::BenchmarkHelper::registrable_currency();
+        Pallet::<T>::set_metadata(currency_id, currency_metadata::<T>(1000))?;
+        // The location is moved, so the old one is unindexed.
+        let metadata = currency_metadata::<T>(1001);
+    }: _<T::Origin>(origin, currency_id, metadata.clone())
+    verify {
+        assert_eq!(Pallet::<T>::currency_metadata(currency_id), Some(metadata));
+    }
+}
+
+impl_benchmark_test_suite!(
+    Pallet,
+    crate::mock::ExtBuilder::default().build(),
+    crate::mock::Runtime
+);
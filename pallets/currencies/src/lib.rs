@@ -27,10 +27,11 @@ use frame_support::{
     pallet_prelude::*,
     traits::{
         tokens::{fungibles, fungible, DepositConsequence, WithdrawConsequence},
-        Currency as PalletCurrency, ExistenceRequirement, Get, Imbalance,
+        Contains, Currency as PalletCurrency, ExistenceRequirement, Get, Imbalance,
         LockableCurrency as PalletLockableCurrency,
         ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
     },
+    storage::{KeyPrefixIterator, StoragePrefixedMap},
     transactional,
 };
 use frame_system::pallet_prelude::*;
@@ -42,8 +43,8 @@ use orml_traits::{
     MultiLockableCurrency, MultiReservableCurrency, OnDust,
 };
 use sp_runtime::{
-    traits::{CheckedSub, MaybeSerializeDeserialize, StaticLookup, Zero},
-    DispatchError, DispatchResult,
+    traits::{CheckedSub, Convert, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
+    DispatchError, DispatchResult, RuntimeDebug,
 };
 use sp_std::{
     convert::{TryFrom, TryInto},
@@ -51,7 +52,10 @@ use sp_std::{
     marker, result,
     vec::Vec,
 };
+use xcm::{latest::MultiLocation, VersionedMultiLocation};
 
+mod benchmarking;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -66,10 +70,70 @@ type CurrencyIdOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<
     <T as frame_system::Config>::AccountId,
 >>::CurrencyId;
 
+/// Currency metadata as kept in storage.
+pub type CurrencyMetadataOf<T> =
+    CurrencyMetadata<BalanceOf<T>, BoundedVec<u8, <T as Config>::StringLimit>>;
+
 type AmountOf<T> = <<T as Config>::MultiCurrency as MultiCurrencyExtended<
     <T as frame_system::Config>::AccountId,
 >>::Amount;
 
+/// Currency metadata stored in the registry.
+///
+/// Stored with name and symbol bounded by `Config::StringLimit`, see [`CurrencyMetadataOf`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct CurrencyMetadata<Balance, BoundedString = Vec<u8>> {
+    /// Currency name, e.g. `Kusama`.
+    pub name: BoundedString,
+    /// Currency symbol (ticker), e.g. `KSM`.
+    pub symbol: BoundedString,
+    /// Number of decimals used to display balances.
+    pub decimals: u8,
+    /// Minimal balance of account.
+    pub existential_deposit: Balance,
+    /// Location of the currency in XCM, if it's transferable via XCM.
+    pub location: Option<VersionedMultiLocation>,
+}
+
+impl<Balance, S> From<CurrencyMetadata<Balance, BoundedVec<u8, S>>>
+    for CurrencyMetadata<Balance>
+{
+    fn from(metadata: CurrencyMetadata<Balance, BoundedVec<u8, S>>) -> Self {
+        CurrencyMetadata {
+            name: metadata.name.into_inner(),
+            symbol: metadata.symbol.into_inner(),
+            decimals: metadata.decimals,
+            existential_deposit: metadata.existential_deposit,
+            location: metadata.location,
+        }
+    }
+}
+
+/// Balance of an account in a currency.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct CurrencyBalance<Balance> {
+    /// Free balance, including frozen.
+    pub free: Balance,
+    /// Reserved balance.
+    pub reserved: Balance,
+    /// Part of free balance which can't be withdrawn because of locks.
+    pub frozen: Balance,
+    /// Balance locks: lock identifier and amount.
+    pub locks: Vec<(LockIdentifier, Balance)>,
+}
+
+/// Currency registered in genesis: currency id, name, symbol, decimals and existential deposit.
+///
+/// XCM location is derived from the currency id by `Config::CurrencyIdConvert`.
+pub type GenesisCurrency<CurrencyId, Balance> = (CurrencyId, Vec<u8>, Vec<u8>, u8, Balance);
+
+/// Currencies used by benchmarks of the registry.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<CurrencyId> {
+    /// Currency which isn't built-in, so it can be registered by `RegistryOrigin`.
+    fn registrable_currency() -> CurrencyId;
+}
+
 #[frame_support::pallet]
 pub mod module {
     use super::*;
@@ -110,6 +174,36 @@ pub mod module {
 
         /// Handler to burn or transfer account's dust
         type OnDust: OnDust<Self::AccountId, Self::CurrencyId, BalanceOf<Self>>;
+
+        /// Accounts holding currencies, walked by the dust sweep in `on_idle`.
+        type DustAccounts: DustAccounts<Self::AccountId, Self::CurrencyId>;
+
+        /// Maximum number of accounts checked for dust in `on_idle` of a block,
+        /// zero disables the sweep.
+        #[pallet::constant]
+        type MaxDustSweepAccounts: Get<u32>;
+
+        /// Maximum number of transfers in `transfer_batch`.
+        #[pallet::constant]
+        type MaxBatchTransfers: Get<u32>;
+
+        /// The origin which may register currencies and update their metadata.
+        type RegistryOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Maximum length of currency name and symbol.
+        #[pallet::constant]
+        type StringLimit: Get<u32>;
+
+        /// Convert currency id into XCM location for currencies registered in genesis.
+        type CurrencyIdConvert: Convert<Self::CurrencyId, Option<MultiLocation>>;
+
+        /// Currencies whose metadata is defined by the runtime (e.g. native currency),
+        /// they can't be registered or updated by `RegistryOrigin`.
+        type BuiltInCurrencies: Contains<Self::CurrencyId>;
+
+        /// Provides currencies for benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::CurrencyId>;
     }
 
     #[pallet::error]
@@ -120,6 +214,18 @@ pub mod module {
         BalanceTooLow,
         /// Deposit result is not expected
         DepositFailed,
+        /// Currency is already registered.
+        CurrencyAlreadyRegistered,
+        /// Currency is not registered.
+        CurrencyNotRegistered,
+        /// Currency name or symbol is empty or too long, or location is unsupported.
+        BadMetadata,
+        /// Location is already registered for other currency.
+        LocationAlreadyRegistered,
+        /// Too many transfers in the batch.
+        TooManyTransfers,
+        /// Metadata of built-in currency can't be changed.
+        BuiltInCurrency,
     }
 
     #[pallet::event]
@@ -135,13 +241,66 @@ pub mod module {
         Withdrawn(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
         /// Dust swept. \[currency_id, who, amount\]
         DustSwept(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
+        /// Currency registered. \[currency_id, metadata\]
+        CurrencyRegistered(CurrencyIdOf<T>, CurrencyMetadata<BalanceOf<T>>),
+        /// Currency metadata updated. \[currency_id, metadata\]
+        CurrencyMetadataUpdated(CurrencyIdOf<T>, CurrencyMetadata<BalanceOf<T>>),
+    }
+
+    /// Metadata of registered currencies.
+    ///
+    /// map CurrencyId => Option<CurrencyMetadata>
+    #[pallet::storage]
+    pub type CurrencyMetadatas<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyIdOf<T>, CurrencyMetadataOf<T>, OptionQuery>;
+
+    /// Registered currencies by their XCM locations.
+    ///
+    /// map MultiLocation => Option<CurrencyId>
+    #[pallet::storage]
+    #[pallet::getter(fn currency_id_by_location)]
+    pub type CurrencyIdByLocation<T: Config> =
+        StorageMap<_, Twox64Concat, MultiLocation, CurrencyIdOf<T>, OptionQuery>;
+
+    /// Position of the dust sweep in `on_idle`: the raw storage key of the last checked account,
+    /// `None` to start from the first one.
+    #[pallet::storage]
+    #[pallet::getter(fn dust_sweep_cursor)]
+    pub type DustSweepCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Currencies registered in genesis.
+        pub currencies: Vec<GenesisCurrency<CurrencyIdOf<T>, BalanceOf<T>>>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            GenesisConfig { currencies: vec![] }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            for currency in &self.currencies {
+                let (currency_id, metadata) = Pallet::<T>::genesis_metadata(currency.clone());
+                Pallet::<T>::set_metadata(currency_id, metadata)
+                    .expect("can't register currency in genesis");
+            }
+        }
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            Self::sweep_dust_on_idle(remaining_weight)
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -185,6 +344,58 @@ pub mod module {
             Ok(())
         }
 
+        /// Transfer balances of currencies to several accounts,
+        /// all transfers are applied or none of them.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the
+        /// transactor.
+        #[pallet::weight(T::WeightInfo::transfer_batch(transfers.len() as u32))]
+        #[transactional]
+        pub fn transfer_batch(
+            origin: OriginFor<T>,
+            transfers: Vec<(
+                <T::Lookup as StaticLookup>::Source,
+                CurrencyIdOf<T>,
+                BalanceOf<T>,
+            )>,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            ensure!(
+                transfers.len() <= T::MaxBatchTransfers::get() as usize,
+                Error::<T>::TooManyTransfers
+            );
+
+            for (dest, currency_id, amount) in transfers {
+                let to = T::Lookup::lookup(dest)?;
+                <Self as MultiCurrency<T::AccountId>>::transfer(currency_id, &from, &to, amount)?;
+            }
+            Ok(())
+        }
+
+        /// Transfer the whole transferable balance under `currency_id` to another account,
+        /// leaving locked balance, and existential deposit if `keep_alive`.
+        ///
+        /// The dispatch origin for this call must be `Signed` by the
+        /// transactor.
+        #[pallet::weight(T::WeightInfo::transfer_all())]
+        pub fn transfer_all(
+            origin: OriginFor<T>,
+            dest: <T::Lookup as StaticLookup>::Source,
+            currency_id: CurrencyIdOf<T>,
+            keep_alive: bool,
+        ) -> DispatchResult {
+            let from = ensure_signed(origin)?;
+            let to = T::Lookup::lookup(dest)?;
+
+            let amount = <Self as fungibles::Inspect<T::AccountId>>::reducible_balance(
+                currency_id,
+                &from,
+                keep_alive,
+            );
+            <Self as MultiCurrency<T::AccountId>>::transfer(currency_id, &from, &to, amount)?;
+            Ok(())
+        }
+
         /// update amount of account `who` under `currency_id`.
         ///
         /// The dispatch origin of this call must be _Root_.
@@ -214,21 +425,199 @@ pub mod module {
             T::SweepOrigin::ensure_origin(origin)?;
 
             for account in accounts {
-                let free_balance = Self::free_balance(currency_id, &account);
-                if free_balance.is_zero() {
-                    continue;
-                }
-                let total_balance = Self::total_balance(currency_id, &account);
-                if free_balance != total_balance {
-                    continue;
-                }
-                if free_balance < Self::minimum_balance(currency_id) {
-                    T::OnDust::on_dust(&account, currency_id, free_balance);
-                    Self::deposit_event(Event::DustSwept(currency_id, account, free_balance));
-                }
+                Self::sweep_account_dust(currency_id, account);
             }
             Ok(())
         }
+
+        /// Register currency with metadata.
+        ///
+        /// The dispatch origin of this call must be `RegistryOrigin`.
+        #[pallet::weight(T::WeightInfo::register_currency())]
+        pub fn register_currency(
+            origin: OriginFor<T>,
+            currency_id: CurrencyIdOf<T>,
+            metadata: CurrencyMetadata<BalanceOf<T>>,
+        ) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+            ensure!(
+                !T::BuiltInCurrencies::contains(&currency_id),
+                Error::<T>::BuiltInCurrency
+            );
+            ensure!(
+                !CurrencyMetadatas::<T>::contains_key(currency_id),
+                Error::<T>::CurrencyAlreadyRegistered
+            );
+
+            Self::set_metadata(currency_id, metadata.clone())?;
+            Self::deposit_event(Event::CurrencyRegistered(currency_id, metadata));
+            Ok(())
+        }
+
+        /// Update metadata of registered currency.
+        ///
+        /// The dispatch origin of this call must be `RegistryOrigin`.
+        #[pallet::weight(T::WeightInfo::update_currency_metadata())]
+        pub fn update_currency_metadata(
+            origin: OriginFor<T>,
+            currency_id: CurrencyIdOf<T>,
+            metadata: CurrencyMetadata<BalanceOf<T>>,
+        ) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+            ensure!(
+                !T::BuiltInCurrencies::contains(&currency_id),
+                Error::<T>::BuiltInCurrency
+            );
+            ensure!(
+                CurrencyMetadatas::<T>::contains_key(currency_id),
+                Error::<T>::CurrencyNotRegistered
+            );
+
+            Self::set_metadata(currency_id, metadata.clone())?;
+            Self::deposit_event(Event::CurrencyMetadataUpdated(currency_id, metadata));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Get metadata of the registered currency.
+    pub fn currency_metadata(
+        currency_id: CurrencyIdOf<T>,
+    ) -> Option<CurrencyMetadata<BalanceOf<T>>> {
+        CurrencyMetadatas::<T>::get(currency_id).map(Into::into)
+    }
+
+    /// Get metadata of all registered currencies.
+    pub fn currencies_metadata() -> Vec<(CurrencyIdOf<T>, CurrencyMetadata<BalanceOf<T>>)> {
+        CurrencyMetadatas::<T>::iter()
+            .map(|(currency_id, metadata)| (currency_id, metadata.into()))
+            .collect()
+    }
+
+    /// Build metadata of currency registered in genesis or by migration.
+    pub fn genesis_metadata(
+        (currency_id, name, symbol, decimals, existential_deposit): GenesisCurrency<
+            CurrencyIdOf<T>,
+            BalanceOf<T>,
+        >,
+    ) -> (CurrencyIdOf<T>, CurrencyMetadata<BalanceOf<T>>) {
+        let metadata = CurrencyMetadata {
+            name,
+            symbol,
+            decimals,
+            existential_deposit,
+            location: T::CurrencyIdConvert::convert(currency_id).map(Into::into),
+        };
+        (currency_id, metadata)
+    }
+
+    /// Get XCM location of the registered currency.
+    pub fn location(currency_id: CurrencyIdOf<T>) -> Option<MultiLocation> {
+        CurrencyMetadatas::<T>::get(currency_id)?
+            .location
+            .and_then(|location| MultiLocation::try_from(location).ok())
+    }
+
+    /// Get existential deposit of the registered currency.
+    pub fn existential_deposit(currency_id: CurrencyIdOf<T>) -> Option<BalanceOf<T>> {
+        CurrencyMetadatas::<T>::get(currency_id).map(|metadata| metadata.existential_deposit)
+    }
+
+    /// Store currency metadata and index its XCM location.
+    pub fn set_metadata(
+        currency_id: CurrencyIdOf<T>,
+        metadata: CurrencyMetadata<BalanceOf<T>>,
+    ) -> DispatchResult {
+        let metadata = Self::bounded_metadata(metadata)?;
+
+        let location = metadata
+            .location
+            .clone()
+            .map(MultiLocation::try_from)
+            .transpose()
+            .map_err(|_| Error::<T>::BadMetadata)?;
+        if let Some(location) = &location {
+            if let Some(registered) = CurrencyIdByLocation::<T>::get(location) {
+                ensure!(
+                    registered == currency_id,
+                    Error::<T>::LocationAlreadyRegistered
+                );
+            }
+        }
+
+        if let Some(previous) = Self::location(currency_id) {
+            CurrencyIdByLocation::<T>::remove(previous);
+        }
+        if let Some(location) = location {
+            CurrencyIdByLocation::<T>::insert(location, currency_id);
+        }
+        CurrencyMetadatas::<T>::insert(currency_id, metadata);
+        Ok(())
+    }
+
+    /// Check that name and symbol are not empty and bound them by `StringLimit`.
+    fn bounded_metadata(
+        metadata: CurrencyMetadata<BalanceOf<T>>,
+    ) -> Result<CurrencyMetadataOf<T>, DispatchError> {
+        let bounded = |s: Vec<u8>| {
+            ensure!(!s.is_empty(), Error::<T>::BadMetadata);
+            BoundedVec::try_from(s).map_err(|_| Error::<T>::BadMetadata)
+        };
+        Ok(CurrencyMetadata {
+            name: bounded(metadata.name)?,
+            symbol: bounded(metadata.symbol)?,
+            decimals: metadata.decimals,
+            existential_deposit: metadata.existential_deposit,
+            location: metadata.location,
+        })
+    }
+
+    /// Pass free balance below existential deposit to `OnDust`, if nothing is reserved.
+    fn sweep_account_dust(currency_id: CurrencyIdOf<T>, account: T::AccountId) {
+        let free_balance = Self::free_balance(currency_id, &account);
+        if free_balance.is_zero() {
+            return;
+        }
+        let total_balance = Self::total_balance(currency_id, &account);
+        if free_balance != total_balance {
+            return;
+        }
+        if free_balance < Self::minimum_balance(currency_id) {
+            T::OnDust::on_dust(&account, currency_id, free_balance);
+            Self::deposit_event(Event::DustSwept(currency_id, account, free_balance));
+        }
+    }
+
+    /// Sweep dust of the accounts following `DustSweepCursor` within `remaining_weight`.
+    ///
+    /// Accounts are walked once per sweep, each one checked in its currency if it's registered,
+    /// starting over after the last one.
+    fn sweep_dust_on_idle(remaining_weight: Weight) -> Weight {
+        // read and write the cursor
+        let base_weight = T::DbWeight::get()
+            .reads_writes(1, 1)
+            .saturating_add(T::WeightInfo::sweep_dust(0));
+        // sweep the account, read its currency in the registry
+        let account_weight = T::WeightInfo::sweep_dust(1)
+            .saturating_sub(T::WeightInfo::sweep_dust(0))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .max(1);
+        let limit = (remaining_weight.saturating_sub(base_weight) / account_weight)
+            .min(T::MaxDustSweepAccounts::get().into()) as u32;
+        if limit.is_zero() {
+            return 0;
+        }
+
+        let (accounts, cursor) = T::DustAccounts::accounts(DustSweepCursor::<T>::get(), limit);
+        for (account, currency_id) in accounts {
+            if CurrencyMetadatas::<T>::contains_key(currency_id) {
+                Self::sweep_account_dust(currency_id, account);
+            }
+        }
+        DustSweepCursor::<T>::set(cursor);
+
+        base_weight.saturating_add(account_weight.saturating_mul(limit.into()))
     }
 }
 
@@ -1017,3 +1406,74 @@ where
         };
     }
 }
+
+/// Accounts holding currencies, walked by the dust sweep in `on_idle`.
+pub trait DustAccounts<AccountId, CurrencyId> {
+    /// Up to `limit` accounts with their currencies after the `cursor` raw key,
+    /// and the cursor to continue from, `None` if all accounts are walked.
+    fn accounts(
+        cursor: Option<Vec<u8>>,
+        limit: u32,
+    ) -> (Vec<(AccountId, CurrencyId)>, Option<Vec<u8>>);
+}
+
+impl<AccountId, CurrencyId> DustAccounts<AccountId, CurrencyId> for () {
+    fn accounts(_: Option<Vec<u8>>, _: u32) -> (Vec<(AccountId, CurrencyId)>, Option<Vec<u8>>) {
+        (Vec::new(), None)
+    }
+}
+
+/// Walks native currency accounts in `frame_system`, then accounts of other currencies
+/// in `orml_tokens`.
+pub struct SystemAndTokensAccounts<T>(marker::PhantomData<T>);
+impl<T> DustAccounts<T::AccountId, CurrencyIdOf<T>> for SystemAndTokensAccounts<T>
+where
+    T: Config + orml_tokens::Config<CurrencyId = CurrencyIdOf<T>>,
+{
+    fn accounts(
+        cursor: Option<Vec<u8>>,
+        limit: u32,
+    ) -> (Vec<(T::AccountId, CurrencyIdOf<T>)>, Option<Vec<u8>>) {
+        let tokens_prefix = orml_tokens::Accounts::<T>::final_prefix();
+        let (mut accounts, cursor) = match cursor {
+            Some(cursor) if cursor.starts_with(&tokens_prefix) => {
+                let iter = orml_tokens::Accounts::<T>::iter_keys_from(cursor);
+                take_keys(iter, limit, |key| key)
+            }
+            cursor => {
+                let iter = match cursor {
+                    Some(cursor) => frame_system::Account::<T>::iter_keys_from(cursor),
+                    None => frame_system::Account::<T>::iter_keys(),
+                };
+                let native_currency_id = T::GetNativeCurrencyId::get();
+                let (mut accounts, mut cursor) =
+                    take_keys(iter, limit, |account| (account, native_currency_id));
+                if cursor.is_none() {
+                    // the map prefix is followed by the first key
+                    let iter = orml_tokens::Accounts::<T>::iter_keys_from(tokens_prefix.to_vec());
+                    let (tokens, tokens_cursor) =
+                        take_keys(iter, limit - accounts.len() as u32, |key| key);
+                    accounts.extend(tokens);
+                    cursor = tokens_cursor;
+                }
+                (accounts, cursor)
+            }
+        };
+        // whitelisted accounts may hold balances below existential deposit
+        accounts.retain(|(account, _)| {
+            !<T as orml_tokens::Config>::DustRemovalWhitelist::contains(account)
+        });
+        (accounts, cursor)
+    }
+}
+
+/// Take up to `limit` keys, with the raw key of the last one if the iterator isn't exhausted.
+fn take_keys<K, A>(
+    mut iter: KeyPrefixIterator<K>,
+    limit: u32,
+    map: impl Fn(K) -> A,
+) -> (Vec<A>, Option<Vec<u8>>) {
+    let accounts: Vec<_> = iter.by_ref().take(limit as usize).map(map).collect();
+    let cursor = (accounts.len() == limit as usize).then(|| iter.last_raw_key().to_vec());
+    (accounts, cursor)
+}
@@ -0,0 +1,39 @@
+//! Storage migrations for the currencies module.
+
+use crate::{BalanceOf, Config, CurrencyIdOf, CurrencyMetadatas, GenesisCurrency, Pallet};
+use frame_support::{
+    pallet_prelude::PhantomData,
+    traits::{Get, OnRuntimeUpgrade},
+    weights::Weight,
+};
+use sp_std::vec::Vec;
+
+/// Registers currencies missing in the registry, e.g. currencies existed before the registry.
+///
+/// Metadata of already registered currencies is kept, so the migration is idempotent.
+pub struct RegisterCurrencies<T, Currencies>(PhantomData<(T, Currencies)>);
+impl<T, Currencies> OnRuntimeUpgrade for RegisterCurrencies<T, Currencies>
+where
+    T: Config,
+    Currencies: Get<Vec<GenesisCurrency<CurrencyIdOf<T>, BalanceOf<T>>>>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 0;
+
+        for currency in Currencies::get() {
+            reads += 1;
+            if CurrencyMetadatas::<T>::contains_key(currency.0) {
+                continue;
+            }
+
+            let (currency_id, metadata) = Pallet::<T>::genesis_metadata(currency);
+            if Pallet::<T>::set_metadata(currency_id, metadata).is_ok() {
+                reads += 2;
+                writes += 2;
+            }
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
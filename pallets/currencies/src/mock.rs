@@ -180,6 +180,41 @@ impl Config for Runtime {
     type WeightInfo = ();
     type SweepOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
     type OnDust = crate::TransferDust<Runtime, DustAccount>;
+    type DustAccounts = SystemAndTokensAccounts<Runtime>;
+    type MaxDustSweepAccounts = ConstU32<2>;
+    type MaxBatchTransfers = ConstU32<10>;
+    type RegistryOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+    type StringLimit = ConstU32<8>;
+    type CurrencyIdConvert = CurrencyIdConvert;
+    type BuiltInCurrencies = BuiltInCurrencies;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<CurrencyId> for BenchmarkHelper {
+    fn registrable_currency() -> CurrencyId {
+        X_TOKEN_ID
+    }
+}
+
+pub struct BuiltInCurrencies;
+impl Contains<CurrencyId> for BuiltInCurrencies {
+    fn contains(id: &CurrencyId) -> bool {
+        *id == NATIVE_CURRENCY_ID
+    }
+}
+
+pub struct CurrencyIdConvert;
+impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
+    fn convert(id: CurrencyId) -> Option<MultiLocation> {
+        match id {
+            CurrencyId::KSM => Some(MultiLocation::parent()),
+            _ => None,
+        }
+    }
 }
 
 pub type NativeCurrency = Currency<Runtime, GetNativeCurrencyId>;
@@ -197,7 +232,7 @@ frame_support::construct_runtime!(
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
-        Currencies: currencies::{Pallet, Call, Event<T>},
+        Currencies: currencies::{Pallet, Call, Storage, Config<T>, Event<T>},
     }
 );
 
@@ -217,11 +252,15 @@ pub const ID_1: LockIdentifier = *b"1       ";
 
 pub struct ExtBuilder {
     balances: Vec<(AccountId, CurrencyId, Balance)>,
+    currencies: Vec<GenesisCurrency<CurrencyId, Balance>>,
 }
 
 impl Default for ExtBuilder {
     fn default() -> Self {
-        Self { balances: vec![] }
+        Self {
+            balances: vec![],
+            currencies: vec![],
+        }
     }
 }
 
@@ -231,6 +270,11 @@ impl ExtBuilder {
         self
     }
 
+    pub fn currencies(mut self, currencies: Vec<GenesisCurrency<CurrencyId, Balance>>) -> Self {
+        self.currencies = currencies;
+        self
+    }
+
     pub fn one_hundred_for_alice_n_bob(self) -> Self {
         self.balances(vec![
             (alice(), NATIVE_CURRENCY_ID, 100),
@@ -267,6 +311,12 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .unwrap();
 
+        currencies::GenesisConfig::<Runtime> {
+            currencies: self.currencies,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
         let mut ext = sp_io::TestExternalities::new(t);
         ext.execute_with(|| System::set_block_number(1));
         ext
@@ -28,6 +28,7 @@ use mock::{
     ID_1, NATIVE_CURRENCY_ID, X_TOKEN_ID,
 };
 use sp_runtime::traits::BadOrigin;
+use xcm::latest::{Junction, Junctions::X1};
 
 #[test]
 fn multi_lockable_currency_should_work() {
@@ -371,6 +372,112 @@ fn call_event_should_work() {
         });
 }
 
+#[test]
+fn transfer_batch_should_work() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            assert_ok!(Currencies::transfer_batch(
+                Some(alice()).into(),
+                vec![(bob(), X_TOKEN_ID, 30), (eva(), NATIVE_CURRENCY_ID, 20)]
+            ));
+            assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 70);
+            assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 130);
+            assert_eq!(NativeCurrency::free_balance(&alice()), 80);
+            assert_eq!(NativeCurrency::free_balance(&eva()), 20);
+            System::assert_has_event(Event::Currencies(crate::Event::Transferred(
+                X_TOKEN_ID,
+                alice(),
+                bob(),
+                30,
+            )));
+            System::assert_last_event(Event::Currencies(crate::Event::Transferred(
+                NATIVE_CURRENCY_ID,
+                alice(),
+                eva(),
+                20,
+            )));
+
+            // all transfers are reverted if one fails
+            assert_noop!(
+                Currencies::transfer_batch(
+                    Some(alice()).into(),
+                    vec![(bob(), X_TOKEN_ID, 10), (eva(), X_TOKEN_ID, 100)]
+                ),
+                orml_tokens::Error::<Runtime>::BalanceTooLow
+            );
+
+            assert_noop!(
+                Currencies::transfer_batch(
+                    Some(alice()).into(),
+                    vec![(bob(), X_TOKEN_ID, 1); 11]
+                ),
+                Error::<Runtime>::TooManyTransfers
+            );
+        });
+}
+
+#[test]
+fn transfer_all_should_work() {
+    ExtBuilder::default()
+        .one_hundred_for_alice_n_bob()
+        .build()
+        .execute_with(|| {
+            // existential deposit is kept
+            assert_ok!(Currencies::transfer_all(
+                Some(alice()).into(),
+                bob(),
+                NATIVE_CURRENCY_ID,
+                true
+            ));
+            assert_eq!(NativeCurrency::free_balance(&alice()), 2);
+            assert_eq!(NativeCurrency::free_balance(&bob()), 198);
+            System::assert_last_event(Event::Currencies(crate::Event::Transferred(
+                NATIVE_CURRENCY_ID,
+                alice(),
+                bob(),
+                98,
+            )));
+
+            assert_ok!(Currencies::transfer_all(
+                Some(alice()).into(),
+                bob(),
+                X_TOKEN_ID,
+                false
+            ));
+            assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 0);
+            assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 200);
+            System::assert_last_event(Event::Currencies(crate::Event::Transferred(
+                X_TOKEN_ID,
+                alice(),
+                bob(),
+                100,
+            )));
+
+            // locked balance is kept
+            assert_ok!(Currencies::set_lock(ID_1, NATIVE_CURRENCY_ID, &bob(), 150));
+            assert_ok!(Currencies::transfer_all(
+                Some(bob()).into(),
+                alice(),
+                NATIVE_CURRENCY_ID,
+                false
+            ));
+            assert_eq!(NativeCurrency::free_balance(&bob()), 150);
+            assert_eq!(NativeCurrency::free_balance(&alice()), 50);
+
+            assert_ok!(Currencies::set_lock(ID_1, X_TOKEN_ID, &bob(), 50));
+            assert_ok!(Currencies::transfer_all(
+                Some(bob()).into(),
+                alice(),
+                X_TOKEN_ID,
+                false
+            ));
+            assert_eq!(Currencies::free_balance(X_TOKEN_ID, &bob()), 50);
+            assert_eq!(Currencies::free_balance(X_TOKEN_ID, &alice()), 150);
+        });
+}
+
 #[test]
 fn sweep_dust_tokens_works() {
     ExtBuilder::default().build().execute_with(|| {
@@ -527,3 +634,295 @@ fn sweep_dust_native_currency_works() {
         assert_eq!(Currencies::total_issuance(NATIVE_CURRENCY_ID), 104);
     });
 }
+
+#[test]
+fn sweep_dust_on_idle_works() {
+    ExtBuilder::default()
+        .currencies(vec![(DOT, b"Kusama".to_vec(), b"KSM".to_vec(), 12, 2)])
+        .build()
+        .execute_with(|| {
+            for (account, free, reserved) in [
+                (bob(), 1, 0),
+                (eva(), 2, 0),
+                (alice(), 1, 1),
+                (DustAccount::get(), 100, 0),
+            ] {
+                orml_tokens::Accounts::<Runtime>::insert(
+                    account,
+                    DOT,
+                    orml_tokens::AccountData {
+                        free,
+                        frozen: 0,
+                        reserved,
+                    },
+                );
+            }
+            orml_tokens::TotalIssuance::<Runtime>::insert(DOT, 105);
+
+            // no weight to check any account
+            assert_eq!(Currencies::on_idle(1, 0), 0);
+            assert_eq!(Currencies::dust_sweep_cursor(), None);
+
+            // two of four accounts are checked in a block
+            assert!(Currencies::on_idle(1, Weight::MAX) > 0);
+            assert!(Currencies::dust_sweep_cursor().is_some());
+            Currencies::on_idle(2, Weight::MAX);
+            assert!(Currencies::dust_sweep_cursor().is_some());
+            // all accounts are swept, start over in the next block
+            Currencies::on_idle(3, Weight::MAX);
+            assert_eq!(Currencies::dust_sweep_cursor(), None);
+
+            System::assert_has_event(Event::Currencies(crate::Event::DustSwept(DOT, bob(), 1)));
+            assert_eq!(
+                orml_tokens::Accounts::<Runtime>::contains_key(bob(), DOT),
+                false
+            );
+            // not below ED
+            assert_eq!(Currencies::free_balance(DOT, &eva()), 2);
+            // has reserved balance
+            assert_eq!(Currencies::free_balance(DOT, &alice()), 1);
+
+            assert_eq!(Currencies::free_balance(DOT, &DustAccount::get()), 101);
+            assert_eq!(Currencies::total_issuance(DOT), 105);
+        });
+}
+
+#[test]
+fn genesis_currencies_registered() {
+    ExtBuilder::default()
+        .currencies(vec![
+            (DOT, b"Kusama".to_vec(), b"KSM".to_vec(), 12, 2),
+            (
+                NATIVE_CURRENCY_ID,
+                b"Pontem".to_vec(),
+                b"PONT".to_vec(),
+                10,
+                2,
+            ),
+        ])
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                Currencies::currency_metadata(DOT),
+                Some(CurrencyMetadata {
+                    name: b"Kusama".to_vec(),
+                    symbol: b"KSM".to_vec(),
+                    decimals: 12,
+                    existential_deposit: 2,
+                    location: Some(MultiLocation::parent().into()),
+                })
+            );
+            assert_eq!(
+                Currencies::currency_metadata(NATIVE_CURRENCY_ID).map(|m| m.location),
+                Some(None)
+            );
+            assert_eq!(Currencies::currencies_metadata().len(), 2);
+            assert_eq!(Currencies::currency_metadata(X_TOKEN_ID), None);
+
+            // Built-in currency metadata can't be changed.
+            let metadata = Currencies::currency_metadata(NATIVE_CURRENCY_ID).unwrap();
+            assert_noop!(
+                Currencies::update_currency_metadata(
+                    Origin::signed(CouncilAccount::get()),
+                    NATIVE_CURRENCY_ID,
+                    CurrencyMetadata {
+                        existential_deposit: 1,
+                        ..metadata
+                    }
+                ),
+                Error::<Runtime>::BuiltInCurrency
+            );
+        });
+}
+
+#[test]
+fn register_currency_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let metadata = CurrencyMetadata {
+            name: b"Test".to_vec(),
+            symbol: b"TEST".to_vec(),
+            decimals: 6,
+            existential_deposit: 1,
+            location: None,
+        };
+
+        assert_noop!(
+            Currencies::register_currency(Origin::signed(bob()), X_TOKEN_ID, metadata.clone()),
+            BadOrigin
+        );
+        assert_noop!(
+            Currencies::update_currency_metadata(
+                Origin::signed(CouncilAccount::get()),
+                X_TOKEN_ID,
+                metadata.clone()
+            ),
+            Error::<Runtime>::CurrencyNotRegistered
+        );
+        assert_noop!(
+            Currencies::register_currency(
+                Origin::signed(CouncilAccount::get()),
+                X_TOKEN_ID,
+                CurrencyMetadata {
+                    symbol: vec![],
+                    ..metadata.clone()
+                }
+            ),
+            Error::<Runtime>::BadMetadata
+        );
+        assert_noop!(
+            Currencies::register_currency(
+                Origin::signed(CouncilAccount::get()),
+                X_TOKEN_ID,
+                CurrencyMetadata {
+                    name: b"Too long name".to_vec(),
+                    ..metadata.clone()
+                }
+            ),
+            Error::<Runtime>::BadMetadata
+        );
+
+        assert_ok!(Currencies::register_currency(
+            Origin::signed(CouncilAccount::get()),
+            X_TOKEN_ID,
+            metadata.clone()
+        ));
+        System::assert_last_event(Event::Currencies(crate::Event::CurrencyRegistered(
+            X_TOKEN_ID,
+            metadata.clone(),
+        )));
+        assert_eq!(
+            Currencies::currency_metadata(X_TOKEN_ID),
+            Some(metadata.clone())
+        );
+        assert_noop!(
+            Currencies::register_currency(
+                Origin::signed(CouncilAccount::get()),
+                X_TOKEN_ID,
+                metadata.clone()
+            ),
+            Error::<Runtime>::CurrencyAlreadyRegistered
+        );
+
+        let updated = CurrencyMetadata {
+            decimals: 8,
+            ..metadata
+        };
+        assert_noop!(
+            Currencies::update_currency_metadata(
+                Origin::signed(bob()),
+                X_TOKEN_ID,
+                updated.clone()
+            ),
+            BadOrigin
+        );
+        assert_ok!(Currencies::update_currency_metadata(
+            Origin::signed(CouncilAccount::get()),
+            X_TOKEN_ID,
+            updated.clone()
+        ));
+        System::assert_last_event(Event::Currencies(crate::Event::CurrencyMetadataUpdated(
+            X_TOKEN_ID,
+            updated.clone(),
+        )));
+        assert_eq!(Currencies::currency_metadata(X_TOKEN_ID), Some(updated));
+    });
+}
+
+#[test]
+fn register_currencies_migration_should_work() {
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    frame_support::parameter_types! {
+        pub KnownCurrencies: Vec<GenesisCurrency<mock::CurrencyId, u128>> = vec![
+            (DOT, b"Kusama".to_vec(), b"KSM".to_vec(), 12, 2),
+            (X_TOKEN_ID, b"Test".to_vec(), b"TEST".to_vec(), 6, 1),
+        ];
+    }
+
+    ExtBuilder::default()
+        .currencies(vec![(X_TOKEN_ID, b"Test".to_vec(), b"TST".to_vec(), 8, 1)])
+        .build()
+        .execute_with(|| {
+            migrations::RegisterCurrencies::<Runtime, KnownCurrencies>::on_runtime_upgrade();
+
+            assert_eq!(
+                Currencies::currency_metadata(DOT).map(|m| m.location),
+                Some(Some(MultiLocation::parent().into()))
+            );
+            // Registered currency is kept.
+            assert_eq!(
+                Currencies::currency_metadata(X_TOKEN_ID).map(|m| m.symbol),
+                Some(b"TST".to_vec())
+            );
+        });
+}
+
+#[test]
+fn currency_locations_should_be_indexed() {
+    ExtBuilder::default()
+        .currencies(vec![(DOT, b"Kusama".to_vec(), b"KSM".to_vec(), 12, 2)])
+        .build()
+        .execute_with(|| {
+            let sibling_asset = MultiLocation::new(1, X1(Junction::Parachain(2001)));
+            let metadata = CurrencyMetadata {
+                name: b"Test".to_vec(),
+                symbol: b"TEST".to_vec(),
+                decimals: 6,
+                existential_deposit: 1,
+                location: Some(MultiLocation::parent().into()),
+            };
+
+            assert_eq!(
+                Currencies::currency_id_by_location(MultiLocation::parent()),
+                Some(DOT)
+            );
+            assert_eq!(Currencies::location(DOT), Some(MultiLocation::parent()));
+            assert_eq!(Currencies::existential_deposit(DOT), Some(2));
+
+            assert_noop!(
+                Currencies::register_currency(
+                    Origin::signed(CouncilAccount::get()),
+                    X_TOKEN_ID,
+                    metadata.clone()
+                ),
+                Error::<Runtime>::LocationAlreadyRegistered
+            );
+
+            assert_ok!(Currencies::register_currency(
+                Origin::signed(CouncilAccount::get()),
+                X_TOKEN_ID,
+                CurrencyMetadata {
+                    location: Some(sibling_asset.clone().into()),
+                    ..metadata.clone()
+                }
+            ));
+            assert_eq!(
+                Currencies::currency_id_by_location(sibling_asset.clone()),
+                Some(X_TOKEN_ID)
+            );
+
+            // Location is moved to other currency.
+            assert_ok!(Currencies::update_currency_metadata(
+                Origin::signed(CouncilAccount::get()),
+                DOT,
+                CurrencyMetadata {
+                    location: None,
+                    ..metadata.clone()
+                }
+            ));
+            assert_eq!(
+                Currencies::currency_id_by_location(MultiLocation::parent()),
+                None
+            );
+            assert_ok!(Currencies::update_currency_metadata(
+                Origin::signed(CouncilAccount::get()),
+                X_TOKEN_ID,
+                metadata
+            ));
+            assert_eq!(
+                Currencies::currency_id_by_location(MultiLocation::parent()),
+                Some(X_TOKEN_ID)
+            );
+            assert_eq!(Currencies::currency_id_by_location(sibling_asset), None);
+        });
+}
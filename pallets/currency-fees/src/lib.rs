@@ -0,0 +1,361 @@
+// Copyright 2020-2022 Pontem Foundation LTD.
+// This file is part of Pontem Network.
+// Apache 2.0
+
+//! The pallet to pay transaction fees in non-native currencies.
+//!
+//! `ChargeTransactionFee` signed extension replaces `pallet_transaction_payment::ChargeTransactionPayment`:
+//! transactions without fee currency (or with the native one) are charged by `pallet_transaction_payment`,
+//! otherwise the native fee is converted with the currency fee rate and withdrawn in the currency.
+//! Fee rates are set by `UpdateOrigin`, or taken from `DefaultFeeRates`.
+//! Fees paid in non-native currencies aren't burned, but deposited to `FeeReceiver` (e.g. treasury).
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    weights::{DispatchInfo, PostDispatchInfo},
+};
+use frame_system::pallet_prelude::*;
+use orml_traits::{GetByKey, MultiCurrency};
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use sp_runtime::{
+    traits::{
+        DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SaturatedConversion, Saturating,
+        SignedExtension, Zero,
+    },
+    transaction_validity::{
+        InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    },
+    FixedPointNumber, FixedPointOperand, FixedU128,
+};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// Balance type of `pallet_transaction_payment`.
+pub type BalanceOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+/// Currency id type of `Config::MultiCurrency`.
+pub type CurrencyIdOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<
+    <T as frame_system::Config>::AccountId,
+>>::CurrencyId;
+
+#[frame_support::pallet]
+pub mod module {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// Currencies used to pay fees.
+        type MultiCurrency: MultiCurrency<Self::AccountId, Balance = BalanceOf<Self>>;
+
+        /// The native currency id, fees in it are charged by `pallet_transaction_payment`.
+        #[pallet::constant]
+        type NativeCurrencyId: Get<CurrencyIdOf<Self>>;
+
+        /// Fee rates of currencies without rate set by `UpdateOrigin`.
+        type DefaultFeeRates: GetByKey<CurrencyIdOf<Self>, Option<FixedU128>>;
+
+        /// Account receiving fees paid in non-native currencies.
+        type FeeReceiver: Get<Self::AccountId>;
+
+        /// The origin which may set fee rates.
+        type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Weight information for the extrinsics in this module.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// fees in native currency are charged without rate
+        NativeCurrency,
+        /// fee rate must be positive
+        InvalidRate,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Fee rate set or removed . \[currency_id, rate\]
+        FeeRateSet(CurrencyIdOf<T>, Option<FixedU128>),
+        /// Transaction fee paid in non-native currency . \[who, currency_id, amount\]
+        FeePaid(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
+    }
+
+    /// Fee rates set by `UpdateOrigin`: amount of the currency paid per unit of native fee.
+    ///
+    /// map CurrencyId => Option<Rate>
+    #[pallet::storage]
+    #[pallet::getter(fn fee_rates)]
+    pub type FeeRates<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyIdOf<T>, FixedU128, OptionQuery>;
+
+    /// Fees which couldn't be deposited to `FeeReceiver` yet (e.g. below existential deposit),
+    /// they're deposited with the next fee paid in the currency.
+    ///
+    /// map CurrencyId => Balance
+    #[pallet::storage]
+    #[pallet::getter(fn unpaid_fees)]
+    pub type UnpaidFees<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the fee rate of the currency, `None` falls back to the default rate.
+        #[pallet::weight(T::WeightInfo::set_fee_rate())]
+        pub fn set_fee_rate(
+            origin: OriginFor<T>,
+            currency_id: CurrencyIdOf<T>,
+            rate: Option<FixedU128>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(
+                currency_id != T::NativeCurrencyId::get(),
+                Error::<T>::NativeCurrency
+            );
+            ensure!(
+                rate.map_or(true, |rate| !rate.is_zero()),
+                Error::<T>::InvalidRate
+            );
+
+            FeeRates::<T>::set(currency_id, rate);
+            Self::deposit_event(Event::FeeRateSet(currency_id, rate));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Fee rate of the currency, `None` if fees can't be paid in the currency.
+    pub fn fee_rate(currency_id: CurrencyIdOf<T>) -> Option<FixedU128> {
+        FeeRates::<T>::get(currency_id).or_else(|| T::DefaultFeeRates::get(&currency_id))
+    }
+
+    /// Convert fee in the native currency to the currency.
+    pub fn convert_fee(currency_id: CurrencyIdOf<T>, fee: BalanceOf<T>) -> Option<BalanceOf<T>> {
+        let rate = Self::fee_rate(currency_id)?;
+        Some(
+            rate.saturating_mul_int(fee.saturated_into::<u128>())
+                .saturated_into(),
+        )
+    }
+}
+
+/// Data passed by `ChargeTransactionFee` from `pre_dispatch` to `post_dispatch`.
+pub enum FeePre<T: Config>
+where
+    <T as frame_system::Config>::Call:
+        Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+    CurrencyIdOf<T>: Send + Sync,
+{
+    /// Fee withdrawn by `pallet_transaction_payment`.
+    Native(<ChargeTransactionPayment<T> as SignedExtension>::Pre),
+    /// Fee withdrawn in non-native currency.
+    Currency {
+        /// The transaction signer.
+        who: T::AccountId,
+        /// The currency of the fee.
+        currency_id: CurrencyIdOf<T>,
+        /// The tip in native currency, included into the fee.
+        tip: BalanceOf<T>,
+        /// The withdrawn fee in the currency.
+        paid: BalanceOf<T>,
+    },
+}
+
+/// Charges transaction fee and tip in the native currency or in the chosen currency.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeTransactionFee<T: Config> {
+    #[codec(compact)]
+    tip: BalanceOf<T>,
+    currency_id: Option<CurrencyIdOf<T>>,
+}
+
+impl<T: Config> ChargeTransactionFee<T>
+where
+    <T as frame_system::Config>::Call:
+        Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+    CurrencyIdOf<T>: Send + Sync,
+{
+    /// Create new `SignedExtension` paying fee in the currency, native one if `None`.
+    pub fn new(tip: BalanceOf<T>, currency_id: Option<CurrencyIdOf<T>>) -> Self {
+        Self { tip, currency_id }
+    }
+
+    /// Non-native currency to pay the fee.
+    fn fee_currency(&self) -> Option<CurrencyIdOf<T>> {
+        self.currency_id
+            .filter(|currency_id| *currency_id != T::NativeCurrencyId::get())
+    }
+
+    /// Fee in the native currency and in the fee currency.
+    fn compute_fee(
+        currency_id: CurrencyIdOf<T>,
+        info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
+        len: usize,
+        tip: BalanceOf<T>,
+    ) -> Result<(BalanceOf<T>, BalanceOf<T>), TransactionValidityError> {
+        let native_fee =
+            pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, tip);
+        let fee = Pallet::<T>::convert_fee(currency_id, native_fee)
+            .ok_or(InvalidTransaction::Payment)?;
+        Ok((native_fee, fee))
+    }
+}
+
+impl<T: Config> sp_std::fmt::Debug for ChargeTransactionFee<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(
+            f,
+            "ChargeTransactionFee<{:?}, {:?}>",
+            self.tip, self.currency_id
+        )
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config> SignedExtension for ChargeTransactionFee<T>
+where
+    <T as frame_system::Config>::Call:
+        Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+    CurrencyIdOf<T>: Send + Sync,
+{
+    const IDENTIFIER: &'static str = "ChargeTransactionFee";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::Call;
+    type AdditionalSigned = ();
+    type Pre = Option<FeePre<T>>;
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> TransactionValidity {
+        let currency_id = match self.fee_currency() {
+            Some(currency_id) => currency_id,
+            None => {
+                return ChargeTransactionPayment::<T>::from(self.tip)
+                    .validate(who, call, info, len)
+            }
+        };
+
+        let (native_fee, fee) = Self::compute_fee(currency_id, info, len, self.tip)?;
+        T::MultiCurrency::ensure_can_withdraw(currency_id, who, fee)
+            .map_err(|_| InvalidTransaction::Payment)?;
+
+        Ok(ValidTransaction {
+            priority: ChargeTransactionPayment::<T>::get_priority(
+                info, len, self.tip, native_fee,
+            ),
+            ..Default::default()
+        })
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        let currency_id = match self.fee_currency() {
+            Some(currency_id) => currency_id,
+            None => {
+                let pre = ChargeTransactionPayment::<T>::from(self.tip)
+                    .pre_dispatch(who, call, info, len)?;
+                return Ok(Some(FeePre::Native(pre)));
+            }
+        };
+
+        let (_, fee) = Self::compute_fee(currency_id, info, len, self.tip)?;
+        T::MultiCurrency::withdraw(currency_id, who, fee)
+            .map_err(|_| InvalidTransaction::Payment)?;
+
+        Ok(Some(FeePre::Currency {
+            who: who.clone(),
+            currency_id,
+            tip: self.tip,
+            paid: fee,
+        }))
+    }
+
+    fn post_dispatch(
+        pre: Self::Pre,
+        info: &DispatchInfoOf<Self::Call>,
+        post_info: &PostDispatchInfoOf<Self::Call>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        let (who, currency_id, tip, paid) = match pre {
+            Some(FeePre::Native(pre)) => {
+                return ChargeTransactionPayment::<T>::post_dispatch(
+                    pre, info, post_info, len, result,
+                )
+            }
+            Some(FeePre::Currency {
+                who,
+                currency_id,
+                tip,
+                paid,
+            }) => (who, currency_id, tip, paid),
+            None => return Ok(()),
+        };
+
+        let actual_native_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+            len as u32, info, post_info, tip,
+        );
+        let actual_fee = Pallet::<T>::convert_fee(currency_id, actual_native_fee)
+            .unwrap_or(paid)
+            .min(paid);
+
+        // Refund could fail if the account is reaped after the withdrawal, it's charged then.
+        let refund = paid.saturating_sub(actual_fee);
+        let fee =
+            if refund.is_zero() || T::MultiCurrency::deposit(currency_id, &who, refund).is_ok() {
+                actual_fee
+            } else {
+                paid
+            };
+
+        let unpaid = UnpaidFees::<T>::get(currency_id).saturating_add(fee);
+        if T::MultiCurrency::deposit(currency_id, &T::FeeReceiver::get(), unpaid).is_ok() {
+            if unpaid != fee {
+                UnpaidFees::<T>::remove(currency_id);
+            }
+        } else {
+            UnpaidFees::<T>::insert(currency_id, unpaid);
+        }
+        Pallet::<T>::deposit_event(Event::FeePaid(who, currency_id, fee));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+    construct_runtime, ord_parameter_types, parameter_types,
+    traits::{ConstU32, Everything, GenesisBuild, Nothing},
+    weights::IdentityFee,
+};
+use frame_system::EnsureSignedBy;
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, Balance, currency::CurrencyId};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+
+pub type AccountId = u128;
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const TREASURY: AccountId = 10;
+pub const NATIVE: CurrencyId = CurrencyId::NATIVE;
+pub const KSM: CurrencyId = CurrencyId::KSM;
+pub const FOREIGN: CurrencyId = CurrencyId::ForeignAsset(1);
+
+mod currency_fees {
+    pub use super::super::*;
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type Header = Header;
+    type Event = Event;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<12>;
+}
+
+parameter_types! {
+    pub const NativeTokenExistentialDeposit: Balance = 10;
+    pub const MaxReserves: u32 = 50;
+}
+
+impl pallet_balances::Config for Runtime {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type Event = Event;
+    type ExistentialDeposit = NativeTokenExistentialDeposit;
+    type AccountStore = System;
+    type MaxLocks = ();
+    type MaxReserves = MaxReserves;
+    type ReserveIdentifier = ();
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub static ForeignExistentialDeposit: Balance = 0;
+}
+
+parameter_type_with_key! {
+    pub ExistentialDeposits: |currency_id: CurrencyId| -> Balance {
+        match currency_id {
+            &FOREIGN => ForeignExistentialDeposit::get(),
+            _ => Default::default(),
+        }
+    };
+}
+
+impl orml_tokens::Config for Runtime {
+    type Event = Event;
+    type Balance = Balance;
+    type Amount = Amount;
+    type CurrencyId = CurrencyId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type OnDust = ();
+    type MaxLocks = ();
+    type DustRemovalWhitelist = Nothing;
+}
+
+parameter_types! {
+    pub const TransactionByteFee: Balance = 1;
+    pub const OperationalFeeMultiplier: u8 = 5;
+}
+
+impl pallet_transaction_payment::Config for Runtime {
+    type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, ()>;
+    type TransactionByteFee = TransactionByteFee;
+    type WeightToFee = IdentityFee<Balance>;
+    type FeeMultiplierUpdate = ();
+    type OperationalFeeMultiplier = OperationalFeeMultiplier;
+}
+
+parameter_type_with_key! {
+    pub DefaultFeeRates: |currency_id: CurrencyId| -> Option<FixedU128> {
+        match currency_id {
+            CurrencyId::KSM => Some(FixedU128::saturating_from_rational(1, 2)),
+            _ => None,
+        }
+    };
+}
+
+ord_parameter_types! {
+    pub const One: AccountId = 1;
+}
+
+parameter_types! {
+    pub const GetNativeCurrencyId: CurrencyId = NATIVE;
+    pub const TreasuryAccount: AccountId = TREASURY;
+}
+
+impl Config for Runtime {
+    type Event = Event;
+    type MultiCurrency = Tokens;
+    type NativeCurrencyId = GetNativeCurrencyId;
+    type DefaultFeeRates = DefaultFeeRates;
+    type FeeReceiver = TreasuryAccount;
+    type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+    type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Storage, Call, Config<T>, Event<T>},
+        Tokens: orml_tokens::{Pallet, Storage, Call, Config<T>, Event<T>},
+        TransactionPayment: pallet_transaction_payment::{Pallet, Storage},
+        CurrencyFees: currency_fees::{Pallet, Storage, Call, Event<T>},
+    }
+);
+
+#[derive(Default)]
+pub struct ExtBuilder {
+    balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl ExtBuilder {
+    pub fn balances(mut self, balances: Vec<(AccountId, CurrencyId, Balance)>) -> Self {
+        self.balances = balances;
+        self
+    }
+
+    pub fn build(self) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::default()
+            .build_storage::<Runtime>()
+            .unwrap();
+
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: self
+                .balances
+                .iter()
+                .filter(|(_, currency_id, _)| *currency_id == NATIVE)
+                .map(|(account_id, _, amount)| (*account_id, *amount))
+                .collect(),
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        orml_tokens::GenesisConfig::<Runtime> {
+            balances: self
+                .balances
+                .into_iter()
+                .filter(|(_, currency_id, _)| *currency_id != NATIVE)
+                .collect(),
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
@@ -0,0 +1,234 @@
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+    assert_noop, assert_ok,
+    weights::{DispatchClass, Pays},
+};
+use mock::{Event, *};
+use orml_traits::MultiCurrency as _;
+use sp_runtime::traits::BadOrigin;
+
+const LEN: usize = 10;
+
+fn call() -> Call {
+    Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn info(weight: Weight) -> DispatchInfo {
+    DispatchInfo {
+        weight,
+        class: DispatchClass::Normal,
+        pays_fee: Pays::Yes,
+    }
+}
+
+fn post_info(actual_weight: Weight) -> PostDispatchInfo {
+    PostDispatchInfo {
+        actual_weight: Some(actual_weight),
+        pays_fee: Pays::Yes,
+    }
+}
+
+fn native_fee(weight: Weight, tip: Balance) -> Balance {
+    TransactionPayment::compute_fee(LEN as u32, &info(weight), tip)
+}
+
+#[test]
+fn set_fee_rate_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let rate = FixedU128::saturating_from_integer(2);
+        let foreign = CurrencyId::ForeignAsset(1);
+
+        assert_noop!(
+            CurrencyFees::set_fee_rate(Origin::signed(BOB), foreign, Some(rate)),
+            BadOrigin
+        );
+        assert_noop!(
+            CurrencyFees::set_fee_rate(Origin::signed(ALICE), NATIVE, Some(rate)),
+            Error::<Runtime>::NativeCurrency
+        );
+        assert_noop!(
+            CurrencyFees::set_fee_rate(Origin::signed(ALICE), foreign, Some(Zero::zero())),
+            Error::<Runtime>::InvalidRate
+        );
+
+        assert_eq!(CurrencyFees::fee_rate(foreign), None);
+        assert_ok!(CurrencyFees::set_fee_rate(
+            Origin::signed(ALICE),
+            foreign,
+            Some(rate)
+        ));
+        System::assert_last_event(Event::CurrencyFees(crate::Event::FeeRateSet(
+            foreign,
+            Some(rate),
+        )));
+        assert_eq!(CurrencyFees::fee_rates(foreign), Some(rate));
+        assert_eq!(CurrencyFees::fee_rate(foreign), Some(rate));
+        assert_eq!(CurrencyFees::convert_fee(foreign, 100), Some(200));
+
+        // removed rate falls back to the default one
+        assert_ok!(CurrencyFees::set_fee_rate(
+            Origin::signed(ALICE),
+            KSM,
+            Some(rate)
+        ));
+        assert_eq!(CurrencyFees::fee_rate(KSM), Some(rate));
+        assert_ok!(CurrencyFees::set_fee_rate(Origin::signed(ALICE), KSM, None));
+        System::assert_last_event(Event::CurrencyFees(crate::Event::FeeRateSet(KSM, None)));
+        assert_eq!(CurrencyFees::fee_rates(KSM), None);
+        assert_eq!(
+            CurrencyFees::fee_rate(KSM),
+            Some(FixedU128::saturating_from_rational(1, 2))
+        );
+        assert_eq!(CurrencyFees::convert_fee(KSM, 100), Some(50));
+    });
+}
+
+#[test]
+fn charge_fee_in_currency_work() {
+    let initial = 1_000_000_000_000;
+    ExtBuilder::default()
+        .balances(vec![(ALICE, NATIVE, initial), (ALICE, KSM, initial)])
+        .build()
+        .execute_with(|| {
+            let tip = 100;
+            let paid = native_fee(1_000, tip) / 2;
+            let extension = ChargeTransactionFee::<Runtime>::new(tip, Some(KSM));
+            assert_ok!(extension.validate(&ALICE, &call(), &info(1_000), LEN));
+
+            let pre = extension
+                .pre_dispatch(&ALICE, &call(), &info(1_000), LEN)
+                .unwrap();
+            assert_eq!(Tokens::free_balance(KSM, &ALICE), initial - paid);
+
+            // unused weight is refunded
+            let actual_fee = TransactionPayment::compute_actual_fee(
+                LEN as u32,
+                &info(1_000),
+                &post_info(500),
+                tip,
+            ) / 2;
+            assert!(actual_fee < paid);
+            assert_ok!(ChargeTransactionFee::<Runtime>::post_dispatch(
+                pre,
+                &info(1_000),
+                &post_info(500),
+                LEN,
+                &Ok(())
+            ));
+
+            assert_eq!(Tokens::free_balance(KSM, &ALICE), initial - actual_fee);
+            assert_eq!(Tokens::free_balance(KSM, &TREASURY), actual_fee);
+            assert_eq!(Balances::free_balance(&ALICE), initial);
+            System::assert_last_event(Event::CurrencyFees(crate::Event::FeePaid(
+                ALICE, KSM, actual_fee,
+            )));
+        });
+}
+
+#[test]
+fn unpaid_fees_deposited_with_next_fee() {
+    let initial = 1_000_000_000_000;
+    ExtBuilder::default()
+        .balances(vec![(ALICE, FOREIGN, initial)])
+        .build()
+        .execute_with(|| {
+            let fee = native_fee(1_000, 0);
+            ForeignExistentialDeposit::set(fee + 1);
+            assert_ok!(CurrencyFees::set_fee_rate(
+                Origin::signed(ALICE),
+                FOREIGN,
+                Some(FixedU128::saturating_from_integer(1))
+            ));
+            let pay_fee = || {
+                let extension = ChargeTransactionFee::<Runtime>::new(0, Some(FOREIGN));
+                let pre = extension
+                    .pre_dispatch(&ALICE, &call(), &info(1_000), LEN)
+                    .unwrap();
+                assert_ok!(ChargeTransactionFee::<Runtime>::post_dispatch(
+                    pre,
+                    &info(1_000),
+                    &post_info(1_000),
+                    LEN,
+                    &Ok(())
+                ));
+            };
+
+            // below existential deposit of the receiver
+            pay_fee();
+            assert_eq!(Tokens::free_balance(FOREIGN, &TREASURY), 0);
+            assert_eq!(CurrencyFees::unpaid_fees(FOREIGN), fee);
+
+            pay_fee();
+            assert_eq!(Tokens::free_balance(FOREIGN, &TREASURY), 2 * fee);
+            assert_eq!(CurrencyFees::unpaid_fees(FOREIGN), 0);
+            assert_eq!(Tokens::free_balance(FOREIGN, &ALICE), initial - 2 * fee);
+        });
+}
+
+#[test]
+fn charge_fee_in_native_currency_work() {
+    let initial = 1_000_000_000_000;
+    ExtBuilder::default()
+        .balances(vec![(ALICE, NATIVE, initial), (ALICE, KSM, initial)])
+        .build()
+        .execute_with(|| {
+            let fee = native_fee(1_000, 0);
+            for currency_id in [None, Some(NATIVE)] {
+                let before = Balances::free_balance(&ALICE);
+                let pre = ChargeTransactionFee::<Runtime>::new(0, currency_id)
+                    .pre_dispatch(&ALICE, &call(), &info(1_000), LEN)
+                    .unwrap();
+                assert_ok!(ChargeTransactionFee::<Runtime>::post_dispatch(
+                    pre,
+                    &info(1_000),
+                    &post_info(1_000),
+                    LEN,
+                    &Ok(())
+                ));
+                assert_eq!(Balances::free_balance(&ALICE), before - fee);
+            }
+
+            assert_eq!(Tokens::free_balance(KSM, &ALICE), initial);
+            assert_eq!(Tokens::free_balance(KSM, &TREASURY), 0);
+        });
+}
+
+#[test]
+fn charge_fee_fails_without_rate_or_balance() {
+    let foreign = CurrencyId::ForeignAsset(1);
+    ExtBuilder::default()
+        .balances(vec![(ALICE, foreign, 1_000_000_000_000), (BOB, KSM, 1)])
+        .build()
+        .execute_with(|| {
+            let payment_error = TransactionValidityError::Invalid(InvalidTransaction::Payment);
+
+            // no fee rate for the currency
+            let extension = ChargeTransactionFee::<Runtime>::new(0, Some(foreign));
+            assert_eq!(
+                extension.validate(&ALICE, &call(), &info(1_000), LEN),
+                Err(payment_error)
+            );
+            assert_eq!(
+                extension
+                    .pre_dispatch(&ALICE, &call(), &info(1_000), LEN)
+                    .err(),
+                Some(payment_error)
+            );
+
+            // not enough balance to pay the fee
+            let extension = ChargeTransactionFee::<Runtime>::new(0, Some(KSM));
+            assert_eq!(
+                extension.validate(&BOB, &call(), &info(1_000), LEN),
+                Err(payment_error)
+            );
+            assert_eq!(
+                extension
+                    .pre_dispatch(&BOB, &call(), &info(1_000), LEN)
+                    .err(),
+                Some(payment_error)
+            );
+            assert_eq!(Tokens::free_balance(KSM, &BOB), 1);
+        });
+}
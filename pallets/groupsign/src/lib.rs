@@ -7,6 +7,8 @@
 //! It's useful for some kinds of multisignatures implementations, e.g. Move VM supports multisignature out of the box,
 //! yet it asks for signers of the current transaction.
 //! Signers should sign hash `(blake2_256)` generated from data contains encoded: `call`, `valid_since`, `valid_thru`, `caller`, `nonce`.
+//! Several calls can be signed at once and executed atomically with `groupsign_batch_call`: in this case signers sign
+//! the encoded vector of calls prefixed with `groupsign:batch` instead of a single call.
 //!
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -31,12 +33,14 @@ pub mod pallet {
         dispatch::{DispatchResultWithPostInfo, Dispatchable, GetDispatchInfo, PostDispatchInfo},
         ensure,
         pallet_prelude::*,
+        storage::with_transaction,
+        weights::{extract_actual_weight, DispatchClass, Weight},
     };
     use frame_system::pallet_prelude::*;
     use sp_std::vec::Vec;
     use sp_runtime::{
         traits::{Verify, IdentifyAccount},
-        verify_encoded_lazy,
+        verify_encoded_lazy, DispatchError, TransactionOutcome,
     };
 
     use crate::weights::WeightInfo;
@@ -76,6 +80,10 @@ pub mod pallet {
             + PartialEq
             + sp_std::fmt::Debug;
 
+        /// Maximum amount of calls in one `groupsign_batch_call`.
+        #[pallet::constant]
+        type MaxBatchCalls: Get<u32>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -100,6 +108,24 @@ pub mod pallet {
             // Hash of call data.
             Vec<u8>,
         ),
+
+        /// When all calls of batch executed.
+        BatchExecuted(
+            // Caller.
+            T::AccountId,
+            // Hash of batch data.
+            Vec<u8>,
+        ),
+
+        /// When one of batch calls failed, all batch changes reverted.
+        BatchInterrupted(
+            // Caller.
+            T::AccountId,
+            // Index of failed call.
+            u32,
+            // Error of failed call.
+            DispatchError,
+        ),
     }
 
     // Errors inform users that something went wrong.
@@ -119,6 +145,12 @@ pub mod pallet {
 
         // Can't execute call.
         ExecutionFailed,
+
+        // When batch doesn't contain calls.
+        EmptyBatch,
+
+        // When batch contains more calls than `MaxBatchCalls`.
+        TooManyCalls,
     }
 
     #[pallet::call]
@@ -148,23 +180,6 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let caller = ensure_signed(origin)?;
 
-            // Check signatures length match.
-            ensure!(!signatures.is_empty(), Error::<T>::ZeroSignatureCall);
-
-            // Check signatures length match.
-            ensure!(
-                signatures.len() == signers.len(),
-                Error::<T>::SignaturesLengthDoesntMatch
-            );
-
-            // Check era.
-            let current_block = frame_system::Pallet::<T>::block_number();
-
-            ensure!(
-                current_block >= valid_since && current_block < valid_thru,
-                Error::<T>::EraValidationError,
-            );
-
             let preimage = crate::utils::generate_preimage::<T>(
                 &caller,
                 &signed_call,
@@ -173,11 +188,7 @@ pub mod pallet {
                 valid_thru,
             );
 
-            // Verify signature.
-            let verified = Iterator::zip(signatures.into_iter(), signers.clone().into_iter())
-                .all(|(sig, signer)| verify_encoded_lazy(&sig, &preimage, &signer));
-
-            ensure!(verified, Error::<T>::SignatureVerificationError);
+            Self::verify_signatures(&preimage, &signers, signatures, valid_since, valid_thru)?;
 
             // Needed for weight function
             let call_len = signed_call.using_encoded(|c| c.len());
@@ -207,5 +218,141 @@ pub mod pallet {
                 })
                 .into())
         }
+
+        /// Do groupsign call of several calls at once.
+        ///
+        /// Calls are dispatched in order inside one storage transaction: if any of them fails,
+        /// all changes made by the batch are reverted and `BatchInterrupted` with the index of
+        /// the failed call is emitted.
+        #[pallet::weight({
+            let dispatch_infos = calls.iter().map(|call| call.get_dispatch_info()).collect::<Vec<_>>();
+            let dispatch_weight = dispatch_infos
+                .iter()
+                .fold(0 as Weight, |total, info| total.saturating_add(info.weight));
+            let dispatch_class = if dispatch_infos
+                .iter()
+                .all(|info| info.class == DispatchClass::Operational)
+            {
+                DispatchClass::Operational
+            } else {
+                DispatchClass::Normal
+            };
+            (
+                T::WeightInfo::groupsign_batch_call(
+                    signers.len() as u32,
+                    calls.using_encoded(|c| c.len() as u32),
+                    calls.len() as u32,
+                ).saturating_add(dispatch_weight),
+
+                dispatch_class,
+            )
+        })]
+        pub fn groupsign_batch_call(
+            origin: OriginFor<T>,
+            calls: Vec<<T as Config>::Call>,
+            signers: Vec<T::AccountId>,
+            signatures: Vec<T::Signature>,
+            valid_since: T::BlockNumber,
+            valid_thru: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            let caller = ensure_signed(origin)?;
+
+            ensure!(!calls.is_empty(), Error::<T>::EmptyBatch);
+            ensure!(
+                calls.len() <= T::MaxBatchCalls::get() as usize,
+                Error::<T>::TooManyCalls
+            );
+
+            let preimage = crate::utils::generate_batch_preimage::<T>(
+                &caller,
+                &calls,
+                &signers,
+                valid_since,
+                valid_thru,
+            );
+
+            Self::verify_signatures(&preimage, &signers, signatures, valid_since, valid_thru)?;
+
+            // Needed for weight function
+            let calls_len = calls.using_encoded(|c| c.len());
+            let calls_count = calls.len();
+
+            let mut calls_weight: Weight = 0;
+            let result = with_transaction(|| {
+                for (index, call) in calls.into_iter().enumerate() {
+                    let info = call.get_dispatch_info();
+
+                    // Do dispatch call.
+                    let origin = Origin {
+                        caller: caller.clone(),
+                        signers: signers.clone(),
+                    };
+                    let result = call.dispatch(T::MyOrigin::from(origin).into());
+                    calls_weight =
+                        calls_weight.saturating_add(extract_actual_weight(&result, &info));
+
+                    if let Err(err) = result {
+                        return TransactionOutcome::Rollback(Err((index as u32, err.error)));
+                    }
+                }
+
+                TransactionOutcome::Commit(Ok(()))
+            });
+
+            match result {
+                Ok(()) => {
+                    <Pallet<T>>::deposit_event(Event::BatchExecuted(caller, preimage.to_vec()));
+                }
+                Err((index, err)) => {
+                    <Pallet<T>>::deposit_event(Event::BatchInterrupted(caller, index, err));
+                }
+            }
+
+            Ok(Some(
+                T::WeightInfo::groupsign_batch_call(
+                    signers.len() as u32,
+                    calls_len as u32,
+                    calls_count as u32,
+                )
+                .saturating_add(calls_weight),
+            )
+            .into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Check era and that every signer signed the preimage.
+        fn verify_signatures(
+            preimage: &[u8; 32],
+            signers: &[T::AccountId],
+            signatures: Vec<T::Signature>,
+            valid_since: T::BlockNumber,
+            valid_thru: T::BlockNumber,
+        ) -> DispatchResult {
+            // Check signatures length match.
+            ensure!(!signatures.is_empty(), Error::<T>::ZeroSignatureCall);
+
+            // Check signatures length match.
+            ensure!(
+                signatures.len() == signers.len(),
+                Error::<T>::SignaturesLengthDoesntMatch
+            );
+
+            // Check era.
+            let current_block = frame_system::Pallet::<T>::block_number();
+
+            ensure!(
+                current_block >= valid_since && current_block < valid_thru,
+                Error::<T>::EraValidationError,
+            );
+
+            // Verify signature.
+            let verified = Iterator::zip(signatures.into_iter(), signers.iter())
+                .all(|(sig, signer)| verify_encoded_lazy(&sig, preimage, signer));
+
+            ensure!(verified, Error::<T>::SignatureVerificationError);
+
+            Ok(())
+        }
     }
 }
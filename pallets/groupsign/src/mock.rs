@@ -28,6 +28,7 @@ frame_support::construct_runtime!(
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const SS58Prefix: u8 = 42;
+    pub const MaxBatchCalls: u32 = 8;
 }
 
 impl system::Config for Test {
@@ -66,6 +67,7 @@ impl groupsign::Config for Test {
     type Call = Call;
     type Public = AccountId;
     type Signature = AnySignature;
+    type MaxBatchCalls = MaxBatchCalls;
 }
 
 #[derive(Eq, PartialEq, Clone, Encode, Decode, TypeInfo, Debug)]
@@ -2,10 +2,10 @@ use std::convert::TryInto;
 
 use crate::{
     mock::{*, self},
-    utils::generate_preimage,
+    utils::{generate_batch_preimage, generate_preimage},
 };
 use frame_support::{
-    assert_err_ignore_postinfo, assert_ok, dispatch::PostDispatchInfo, weights::Pays,
+    assert_err_ignore_postinfo, assert_ok, dispatch::PostDispatchInfo, traits::Get, weights::Pays,
 };
 use sp_core::{sr25519};
 use sp_keystore::{SyncCryptoStore, testing::KeyStore};
@@ -201,5 +201,220 @@ fn correct_call_test() {
     });
 }
 
+/// Sign message with all provided keys.
+fn sign_all(keystore: &KeyStore, keys: &[AccountId], msg: &[u8]) -> Vec<AnySignature> {
+    keystore
+        .sign_with_all(
+            key_types::ACCOUNT,
+            keys.iter().map(|key| (*key).into()).collect(),
+            msg,
+        )
+        .expect("Successful signing")
+        .iter()
+        .map(|f| {
+            f.as_ref()
+                .expect("ref hell ring #1")
+                .as_ref()
+                .expect("ref hell ring #2")
+        })
+        .map(|raw_sig| {
+            sr25519::Signature(raw_sig.as_slice().try_into().expect("ref hell ring #3")).into()
+        })
+        .collect()
+}
+
+#[test]
+fn empty_batch_test() {
+    let keystore = KeyStore::default();
+    let key_a = keystore
+        .sr25519_generate_new(key_types::ACCOUNT, Some("//Alice"))
+        .expect("Generated key");
+
+    new_test_ext().execute_with(move || {
+        let f = crate::Pallet::<mock::Test>::groupsign_batch_call(
+            mock::Origin::signed(key_a),
+            vec![],
+            vec![key_a],
+            vec![],
+            0,
+            10000,
+        );
+        assert_err_ignore_postinfo!(f, crate::Error::<mock::Test>::EmptyBatch)
+    });
+}
+
+#[test]
+fn too_many_calls_batch_test() {
+    let keystore = KeyStore::default();
+    let key_a = keystore
+        .sr25519_generate_new(key_types::ACCOUNT, Some("//Alice"))
+        .expect("Generated key");
+
+    let calls = (0..MaxBatchCalls::get() + 1)
+        .map(|_| {
+            Call::System(frame_system::Call::remark {
+                remark: b"Too much.".to_vec(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    new_test_ext().execute_with(move || {
+        let f = crate::Pallet::<mock::Test>::groupsign_batch_call(
+            mock::Origin::signed(key_a),
+            calls,
+            vec![key_a],
+            vec![],
+            0,
+            10000,
+        );
+        assert_err_ignore_postinfo!(f, crate::Error::<mock::Test>::TooManyCalls)
+    });
+}
+
+#[test]
+fn single_call_signatures_batch_test() {
+    let keystore = KeyStore::default();
+    let key_a = keystore
+        .sr25519_generate_new(key_types::ACCOUNT, Some("//Alice"))
+        .expect("Generated key");
+    let key_b = keystore
+        .sr25519_generate_new(key_types::ACCOUNT, Some("//Bob"))
+        .expect("Generated key");
+
+    let call = Call::System(frame_system::Call::remark {
+        remark: b"Signed once.".to_vec(),
+    });
+
+    new_test_ext().execute_with(move || {
+        // Signatures of a single call can't be used to dispatch a batch.
+        let preimage = generate_preimage::<mock::Test>(&key_a, &call, &[key_a, key_b], 0, 10000);
+        let signatures = sign_all(&keystore, &[key_a, key_b], &preimage);
+
+        let f = crate::Pallet::<mock::Test>::groupsign_batch_call(
+            mock::Origin::signed(key_a),
+            vec![call],
+            vec![key_a, key_b],
+            signatures,
+            0,
+            10000,
+        );
+        assert_err_ignore_postinfo!(f, crate::Error::<mock::Test>::SignatureVerificationError)
+    });
+}
+
+#[test]
+fn correct_batch_call_test() {
+    let keystore = KeyStore::default();
+    let key_a = keystore
+        .sr25519_generate_new(key_types::ACCOUNT, Some("//Alice"))
+        .expect("Generated key");
+    let key_b = keystore
+        .sr25519_generate_new(key_types::ACCOUNT, Some("//Bob"))
+        .expect("Generated key");
+
+    let calls = vec![
+        Call::System(frame_system::Call::remark_with_event {
+            remark: b"Publish package.".to_vec(),
+        }),
+        Call::System(frame_system::Call::remark_with_event {
+            remark: b"Run init script.".to_vec(),
+        }),
+    ];
+
+    new_test_ext().execute_with(move || {
+        System::set_block_number(123);
+
+        let preimage =
+            generate_batch_preimage::<mock::Test>(&key_a, &calls, &[key_a, key_b], 10, 10000);
+        let signatures = sign_all(&keystore, &[key_a, key_b], &preimage);
+
+        assert_ok!(crate::Pallet::<mock::Test>::groupsign_batch_call(
+            mock::Origin::signed(key_a),
+            calls,
+            vec![key_a, key_b],
+            signatures,
+            10,
+            10000,
+        ));
+
+        let events = System::events()
+            .into_iter()
+            .map(|record| record.event)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| matches!(
+                    event,
+                    Event::System(frame_system::Event::Remarked { .. })
+                ))
+                .count(),
+            2
+        );
+        assert_eq!(
+            events.last(),
+            Some(&Event::Groupsign(crate::Event::BatchExecuted(
+                key_a,
+                preimage.to_vec()
+            )))
+        );
+    });
+}
+
+#[test]
+fn failed_batch_call_rollback_test() {
+    let keystore = KeyStore::default();
+    let key_a = keystore
+        .sr25519_generate_new(key_types::ACCOUNT, Some("//Alice"))
+        .expect("Generated key");
+    let key_b = keystore
+        .sr25519_generate_new(key_types::ACCOUNT, Some("//Bob"))
+        .expect("Generated key");
+
+    let calls = vec![
+        Call::System(frame_system::Call::remark_with_event {
+            remark: b"Publish package.".to_vec(),
+        }),
+        // Requires root, so fails with groupsign origin.
+        Call::System(frame_system::Call::set_heap_pages { pages: 42 }),
+        Call::System(frame_system::Call::remark_with_event {
+            remark: b"Never executed.".to_vec(),
+        }),
+    ];
+
+    new_test_ext().execute_with(move || {
+        System::set_block_number(123);
+
+        let preimage =
+            generate_batch_preimage::<mock::Test>(&key_a, &calls, &[key_a, key_b], 10, 10000);
+        let signatures = sign_all(&keystore, &[key_a, key_b], &preimage);
+
+        assert_ok!(crate::Pallet::<mock::Test>::groupsign_batch_call(
+            mock::Origin::signed(key_a),
+            calls,
+            vec![key_a, key_b],
+            signatures,
+            10,
+            10000,
+        ));
+
+        let events = System::events()
+            .into_iter()
+            .map(|record| record.event)
+            .collect::<Vec<_>>();
+
+        // The first call changes are reverted too.
+        assert_eq!(
+            events,
+            vec![Event::Groupsign(crate::Event::BatchInterrupted(
+                key_a,
+                1,
+                frame_support::error::BadOrigin.into()
+            ))]
+        );
+    });
+}
+
 #[test]
 fn bench_groupsign() {}
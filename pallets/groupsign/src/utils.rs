@@ -2,6 +2,9 @@ use codec::{Encode};
 use sp_io::{hashing::blake2_256};
 use frame_support::error::BadOrigin;
 
+/// Prefix of batch preimage, so signatures of batch can't be reused for a single call.
+pub const BATCH_PREIMAGE_PREFIX: &[u8] = b"groupsign:batch";
+
 /// Ensure this origin represents a groupsign origin
 pub fn ensure_groupsign<T, OuterOrigin>(o: OuterOrigin) -> Result<crate::Origin<T>, BadOrigin>
 where
@@ -20,11 +23,32 @@ pub fn generate_preimage<T: crate::Config>(
     signers: &[T::AccountId],
     valid_since: T::BlockNumber,
     valid_thru: T::BlockNumber,
+) -> [u8; 32] {
+    hash_preimage::<T>(call.encode(), caller, signers, valid_since, valid_thru)
+}
+
+pub fn generate_batch_preimage<T: crate::Config>(
+    caller: &T::AccountId,
+    calls: &[<T as crate::Config>::Call],
+    signers: &[T::AccountId],
+    valid_since: T::BlockNumber,
+    valid_thru: T::BlockNumber,
+) -> [u8; 32] {
+    let mut calls_preimage = BATCH_PREIMAGE_PREFIX.to_vec();
+    calls_preimage.extend(calls.encode());
+    hash_preimage::<T>(calls_preimage, caller, signers, valid_since, valid_thru)
+}
+
+fn hash_preimage<T: crate::Config>(
+    mut call_preimage: sp_std::vec::Vec<u8>,
+    caller: &T::AccountId,
+    signers: &[T::AccountId],
+    valid_since: T::BlockNumber,
+    valid_thru: T::BlockNumber,
 ) -> [u8; 32] {
     let nonce: <T as frame_system::Config>::Index =
         frame_system::Pallet::<T>::account_nonce(&caller);
 
-    let mut call_preimage = call.encode();
     call_preimage.extend(valid_since.encode());
     call_preimage.extend(valid_thru.encode());
     call_preimage.extend(caller.encode());
@@ -10,6 +10,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for groupsign pallet.
 pub trait WeightInfo {
 	fn groupsign_call(signatures: u32, call_length: u32) -> Weight;
+	fn groupsign_batch_call(signatures: u32, calls_length: u32, calls: u32) -> Weight;
 }
 
 /// Just like SubstrateWeights, but measured in Pontem.
@@ -18,4 +19,7 @@ impl<T: frame_system::Config> WeightInfo for PontemWeights<T> {
     fn groupsign_call(signatures: u32, call_length: u32) -> Weight {
         (signatures * 42 + call_length * 34).into() // TODO: Needs benches
     }
+    fn groupsign_batch_call(signatures: u32, calls_length: u32, calls: u32) -> Weight {
+        (signatures * 42 + calls_length * 34 + calls * 1_000).into() // TODO: Needs benches
+    }
 }
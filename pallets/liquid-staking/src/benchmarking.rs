@@ -0,0 +1,139 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+//! Benchmarks of the liquid staking pallet.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::OnInitialize;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+type Staking<T> = parachain_staking::Pallet<T>;
+
+/// Amount deposited at once, enough for a new delegation.
+fn deposit_amount<T: Config>() -> BalanceOf<T> {
+    T::MinDeposit::get()
+        .max(<T as parachain_staking::Config>::MinDelegatorStk::get())
+        .saturating_mul(10u32.into())
+}
+
+/// Set the native balance of `who` to `amount`.
+fn fund<T: Config>(who: &T::AccountId, amount: BalanceOf<T>) {
+    <T as parachain_staking::Config>::Currency::make_free_balance_be(who, amount);
+}
+
+/// Start the next staking round.
+fn start_next_round<T: Config>() {
+    let round = Staking::<T>::round();
+    let n = round.first + round.length.into();
+    frame_system::Pallet::<T>::set_block_number(n);
+    Staking::<T>::on_initialize(n);
+}
+
+/// Join enough candidates to fill the selected set, so the pool can delegate to
+/// `MaxDelegationsPerDelegator` of them, and select them in the next round.
+///
+/// They outbid the existing candidates, so only the new ones are selected.
+fn select_candidates<T: Config>() -> Result<(), &'static str> {
+    let total_selected = Staking::<T>::total_selected();
+    let count =
+        <T as parachain_staking::Config>::MaxDelegationsPerDelegator::get().max(total_selected);
+    let bond =
+        <T as parachain_staking::Config>::MinCandidateStk::get().saturating_mul(1000u32.into());
+    for i in 0..count {
+        let candidate: T::AccountId = account("candidate", i, SEED);
+        fund::<T>(&candidate, bond.saturating_mul(2u32.into()));
+        let candidate_count = Staking::<T>::candidate_pool().0.len() as u32;
+        Staking::<T>::join_candidates(
+            RawOrigin::Signed(candidate).into(),
+            bond,
+            candidate_count,
+        )?;
+    }
+    if count > total_selected {
+        Staking::<T>::set_total_selected(RawOrigin::Root.into(), count)?;
+    }
+    start_next_round::<T>();
+    Ok(())
+}
+
+/// Deposit `amount` into the pool `times` times from a new account.
+fn deposited<T: Config>(amount: BalanceOf<T>, times: u32) -> Result<T::AccountId, &'static str> {
+    let who: T::AccountId = account("depositor", 0, SEED);
+    fund::<T>(&who, amount.saturating_mul((times + 1).into()));
+    for _ in 0..times {
+        Pallet::<T>::deposit(RawOrigin::Signed(who.clone()).into(), amount)?;
+    }
+    Ok(who)
+}
+
+benchmarks! {
+    deposit {
+        let max_delegations = <T as parachain_staking::Config>::MaxDelegationsPerDelegator::get();
+        select_candidates::<T>()?;
+        let amount = deposit_amount::<T>();
+        // The pool delegates to a new candidate after all the others.
+        deposited::<T>(amount, max_delegations - 1)?;
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller, amount.saturating_mul(2u32.into()));
+    }: _(RawOrigin::Signed(caller.clone()), amount)
+    verify {
+        assert!(!T::MultiCurrency::free_balance(T::LiquidCurrencyId::get(), &caller).is_zero());
+        let pool = Staking::<T>::delegator_state(Pallet::<T>::account_id()).unwrap();
+        assert_eq!(pool.delegations.0.len() as u32, max_delegations);
+    }
+
+    redeem {
+        select_candidates::<T>()?;
+        let caller = deposited::<T>(deposit_amount::<T>(), 1)?;
+        let liquid_amount = T::MultiCurrency::free_balance(T::LiquidCurrencyId::get(), &caller);
+    }: _(RawOrigin::Signed(caller.clone()), liquid_amount)
+    verify {
+        assert_eq!(Pallet::<T>::redemptions(caller).len(), 1);
+    }
+
+    claim {
+        select_candidates::<T>()?;
+        let caller = deposited::<T>(deposit_amount::<T>(), 1)?;
+        let liquid_amount = T::MultiCurrency::free_balance(T::LiquidCurrencyId::get(), &caller);
+        Pallet::<T>::redeem(RawOrigin::Signed(caller.clone()).into(), liquid_amount)?;
+        // The queue is paid from idle funds.
+        let mut queue = Pallet::<T>::redemption_queue();
+        queue.scheduled = queue.requested;
+        queue.paid = queue.requested;
+        Queue::<T>::put(queue);
+        let pool = Pallet::<T>::account_id();
+        let minimum_balance = <T as parachain_staking::Config>::Currency::minimum_balance();
+        fund::<T>(&pool, queue.paid.saturating_add(minimum_balance));
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(Pallet::<T>::redemptions(caller).is_empty());
+    }
+
+    rebalance {
+        let x in 1 .. <T as parachain_staking::Config>::MaxDelegationsPerDelegator::get();
+
+        select_candidates::<T>()?;
+        let amount = deposit_amount::<T>();
+        let who = deposited::<T>(amount, x)?;
+        // Redeem as much as can be undelegated, so a decrease is scheduled for every delegation.
+        let kept = <T as parachain_staking::Config>::MinDelegation::get()
+            .saturating_mul(x.into())
+            .saturating_add(<T as parachain_staking::Config>::MinDelegatorStk::get());
+        let redeemed = amount.saturating_mul(x.into()).saturating_sub(kept);
+        Pallet::<T>::redeem(RawOrigin::Signed(who).into(), redeemed)?;
+        Pallet::<T>::rebalance(Staking::<T>::round().current);
+        for _ in 0..<T as parachain_staking::Config>::DelegationBondLessDelay::get() {
+            start_next_round::<T>();
+        }
+        let now = Staking::<T>::round().current;
+    }: {
+        Pallet::<T>::rebalance(now);
+    }
+    verify {
+        assert!(!Pallet::<T>::redemption_queue().paid.is_zero());
+    }
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::default_ext(), crate::mock::Runtime);
@@ -0,0 +1,501 @@
+// Copyright 2020-2022 Pontem Foundation LTD.
+// This file is part of Pontem Network.
+// Apache 2.0
+
+//! The pallet to stake native currency without locking it up.
+//!
+//! Deposits are pooled on the pallet account, which delegates them through `parachain_staking`
+//! to the selected candidate it delegates the least to, and a derivative currency
+//! (`LiquidCurrencyId`) is minted for them through `MultiCurrency`. The derivative is a regular
+//! currency, so it's transferable and usable in Move through `BalancesAdapter`.
+//!
+//! The derivative is minted and redeemed at the exchange rate of the pool: native currency
+//! backing the pool (delegated or idle, excluding redeemed amounts) per derivative issued.
+//! Staking rewards are paid to the pool account and delegated again, so the rate grows over time.
+//!
+//! Redemptions burn the derivative and are queued: at the start of every round the pool
+//! executes its due delegation decreases, pays the queue from idle funds and schedules
+//! delegation decreases (delayed by `DelegationBondLessDelay` rounds) for the rest.
+//! Redeemed amounts are claimed with `claim` once the queue is paid up to them.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    storage::{with_transaction, TransactionOutcome},
+    traits::{Currency, ExistenceRequirement},
+    transactional, PalletId,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use orml_traits::MultiCurrency;
+use parachain_staking::RoundIndex;
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational,
+    traits::{AccountIdConversion, SaturatedConversion, Saturating, Zero},
+    DispatchResult, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// Balance type of `parachain_staking`.
+pub type BalanceOf<T> = parachain_staking::BalanceOf<T>;
+
+/// Currency id type of `Config::MultiCurrency`.
+pub type CurrencyIdOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<
+    <T as frame_system::Config>::AccountId,
+>>::CurrencyId;
+
+/// Redemption waiting in the queue.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Redemption<Balance> {
+    /// Redeemed amount of native currency.
+    pub amount: Balance,
+    /// Total amount requested by the queue up to and including this redemption,
+    /// it's claimable once the queue is paid up to it.
+    pub position: Balance,
+}
+
+/// Running totals of the redemption queue.
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RedemptionQueue<Balance> {
+    /// Total amount redeemed.
+    pub requested: Balance,
+    /// Total amount paid from idle funds or scheduled to be undelegated.
+    pub scheduled: Balance,
+    /// Total amount paid from idle funds or undelegated, available to claim.
+    pub paid: Balance,
+    /// Total amount claimed.
+    pub claimed: Balance,
+}
+
+impl<Balance: Copy + Saturating> RedemptionQueue<Balance> {
+    /// Amount redeemed but not claimed yet, excluded from the pool.
+    pub fn owed(&self) -> Balance {
+        self.requested.saturating_sub(self.claimed)
+    }
+
+    /// Amount paid but not claimed yet, kept on the pool account.
+    pub fn claimable(&self) -> Balance {
+        self.paid.saturating_sub(self.claimed)
+    }
+
+    /// Amount neither paid nor scheduled to be undelegated yet.
+    pub fn outstanding(&self) -> Balance {
+        self.requested.saturating_sub(self.scheduled)
+    }
+}
+
+#[frame_support::pallet]
+pub mod module {
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + parachain_staking::Config {
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// Currencies the derivative is minted in.
+        type MultiCurrency: MultiCurrency<Self::AccountId, Balance = BalanceOf<Self>>;
+
+        /// The derivative currency id.
+        #[pallet::constant]
+        type LiquidCurrencyId: Get<CurrencyIdOf<Self>>;
+
+        /// The pallet id, the pool account is derived from it.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Minimal amount of native currency deposited at once.
+        #[pallet::constant]
+        type MinDeposit: Get<BalanceOf<Self>>;
+
+        /// Weight information for the extrinsics in this module.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// deposit is below the minimal one
+        DepositTooLow,
+        /// amount is too low to mint or redeem anything
+        AmountTooLow,
+        /// no selected candidate to delegate to
+        NoCandidates,
+        /// no redemption is claimable yet
+        NothingToClaim,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Native currency deposited and derivative minted. \[who, amount, liquid_amount\]
+        Deposited(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+        /// Derivative burned and native currency queued for redemption.
+        /// \[who, liquid_amount, amount\]
+        Redeemed(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+        /// Redeemed native currency claimed. \[who, amount\]
+        Claimed(T::AccountId, BalanceOf<T>),
+        /// Pool delegated to the candidate. \[candidate, amount\]
+        Delegated(T::AccountId, BalanceOf<T>),
+        /// Pool scheduled the decrease of its delegation. \[candidate, amount\]
+        UndelegationScheduled(T::AccountId, BalanceOf<T>),
+    }
+
+    /// Redemptions of the account waiting to be claimed.
+    ///
+    /// map AccountId => Vec<Redemption>
+    #[pallet::storage]
+    #[pallet::getter(fn redemptions)]
+    pub type Redemptions<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, Vec<Redemption<BalanceOf<T>>>, ValueQuery>;
+
+    /// Running totals of the redemption queue.
+    #[pallet::storage]
+    #[pallet::getter(fn redemption_queue)]
+    pub type Queue<T: Config> = StorageValue<_, RedemptionQueue<BalanceOf<T>>, ValueQuery>;
+
+    /// Amount the pool scheduled to undelegate from the candidate, counted in
+    /// `RedemptionQueue::scheduled` until it's executed or the request is gone.
+    ///
+    /// map AccountId => Balance
+    #[pallet::storage]
+    #[pallet::getter(fn undelegating)]
+    pub type Undelegating<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Last staking round the pool was rebalanced in.
+    #[pallet::storage]
+    #[pallet::getter(fn last_round)]
+    pub type LastRound<T: Config> = StorageValue<_, RoundIndex, ValueQuery>;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        fn on_initialize(_n: T::BlockNumber) -> Weight {
+            let round = parachain_staking::Pallet::<T>::round().current;
+            if round <= LastRound::<T>::get() {
+                return T::DbWeight::get().reads(2);
+            }
+            LastRound::<T>::put(round);
+            Self::rebalance(round)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Deposit native currency into the pool and mint the derivative at the pool rate.
+        #[pallet::weight(<T as Config>::WeightInfo::deposit())]
+        #[transactional]
+        pub fn deposit(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(amount >= T::MinDeposit::get(), Error::<T>::DepositTooLow);
+
+            let liquid_amount = Self::liquid_amount(amount);
+            ensure!(!liquid_amount.is_zero(), Error::<T>::AmountTooLow);
+
+            <T as parachain_staking::Config>::Currency::transfer(
+                &who,
+                &Self::account_id(),
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            Self::delegate(amount)?;
+            T::MultiCurrency::deposit(T::LiquidCurrencyId::get(), &who, liquid_amount)?;
+
+            Self::deposit_event(Event::Deposited(who, amount, liquid_amount));
+            Ok(())
+        }
+
+        /// Burn the derivative and queue its native value at the pool rate for redemption.
+        #[pallet::weight(<T as Config>::WeightInfo::redeem())]
+        #[transactional]
+        pub fn redeem(origin: OriginFor<T>, liquid_amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let amount = Self::native_amount(liquid_amount);
+            ensure!(!amount.is_zero(), Error::<T>::AmountTooLow);
+
+            T::MultiCurrency::withdraw(T::LiquidCurrencyId::get(), &who, liquid_amount)?;
+            let mut queue = Queue::<T>::get();
+            queue.requested = queue.requested.saturating_add(amount);
+            Redemptions::<T>::append(
+                &who,
+                Redemption {
+                    amount,
+                    position: queue.requested,
+                },
+            );
+            Queue::<T>::put(queue);
+
+            Self::deposit_event(Event::Redeemed(who, liquid_amount, amount));
+            Ok(())
+        }
+
+        /// Claim the redemptions the queue is paid up to.
+        #[pallet::weight(<T as Config>::WeightInfo::claim())]
+        #[transactional]
+        pub fn claim(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let mut queue = Queue::<T>::get();
+            let (claimable, pending): (Vec<_>, Vec<_>) = Redemptions::<T>::get(&who)
+                .into_iter()
+                .partition(|redemption| redemption.position <= queue.paid);
+            let amount = claimable
+                .iter()
+                .fold(Zero::zero(), |total: BalanceOf<T>, redemption| {
+                    total.saturating_add(redemption.amount)
+                });
+            ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+            <T as parachain_staking::Config>::Currency::transfer(
+                &Self::account_id(),
+                &who,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            queue.claimed = queue.claimed.saturating_add(amount);
+            Queue::<T>::put(queue);
+            if pending.is_empty() {
+                Redemptions::<T>::remove(&who);
+            } else {
+                Redemptions::<T>::insert(&who, pending);
+            }
+
+            Self::deposit_event(Event::Claimed(who, amount));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The pool account delegating the deposits.
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account()
+    }
+
+    /// Native currency backing the derivative: delegated and idle funds of the pool,
+    /// excluding redeemed amounts.
+    pub fn total_pooled() -> BalanceOf<T> {
+        let pool = Self::account_id();
+        let delegated = parachain_staking::Pallet::<T>::delegator_state(&pool)
+            .map(|state| state.total)
+            .unwrap_or_else(Zero::zero);
+        let free = <T as parachain_staking::Config>::Currency::free_balance(&pool);
+        delegated
+            .saturating_add(free)
+            .saturating_sub(Queue::<T>::get().owed())
+    }
+
+    /// Amount of derivative minted for `amount` of native currency.
+    pub fn liquid_amount(amount: BalanceOf<T>) -> BalanceOf<T> {
+        let issuance = T::MultiCurrency::total_issuance(T::LiquidCurrencyId::get());
+        Self::convert(amount, issuance, Self::total_pooled())
+    }
+
+    /// Amount of native currency redeemed for `liquid_amount` of derivative.
+    pub fn native_amount(liquid_amount: BalanceOf<T>) -> BalanceOf<T> {
+        let issuance = T::MultiCurrency::total_issuance(T::LiquidCurrencyId::get());
+        Self::convert(liquid_amount, Self::total_pooled(), issuance)
+    }
+
+    /// `amount * numerator / denominator`, one to one while nothing is pooled or issued.
+    ///
+    /// Zero if only one side is zero, e.g. while the pool holds funds but no derivative is
+    /// issued, deposits would mint nothing and fail.
+    fn convert(
+        amount: BalanceOf<T>,
+        numerator: BalanceOf<T>,
+        denominator: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        if numerator.is_zero() && denominator.is_zero() {
+            return amount;
+        }
+        if numerator.is_zero() || denominator.is_zero() {
+            return Zero::zero();
+        }
+        multiply_by_rational(
+            amount.saturated_into(),
+            numerator.saturated_into(),
+            denominator.saturated_into(),
+        )
+        .map(SaturatedConversion::saturated_into)
+        .unwrap_or_else(|_| Zero::zero())
+    }
+
+    /// Run the staking call as the pool, reverting its changes on failure.
+    fn as_pool(call: impl FnOnce(OriginFor<T>) -> DispatchResultWithPostInfo) -> DispatchResult {
+        with_transaction(
+            || match call(RawOrigin::Signed(Self::account_id()).into()) {
+                Ok(_) => TransactionOutcome::Commit(Ok(())),
+                Err(err) => TransactionOutcome::Rollback(Err(err.error)),
+            },
+        )
+    }
+
+    /// Delegate `amount` from the pool to the selected candidate it delegates the least to.
+    fn delegate(amount: BalanceOf<T>) -> DispatchResult {
+        let pool = Self::account_id();
+        let delegations = parachain_staking::Pallet::<T>::delegator_state(&pool)
+            .map(|state| state.delegations.0)
+            .unwrap_or_default();
+        let can_add = (delegations.len() as u32)
+            < <T as parachain_staking::Config>::MaxDelegationsPerDelegator::get();
+        let delegated = |candidate: &T::AccountId| {
+            delegations
+                .iter()
+                .find(|bond| &bond.owner == candidate)
+                .map(|bond| bond.amount)
+        };
+        let (candidate, current) = parachain_staking::Pallet::<T>::selected_candidates()
+            .into_iter()
+            .filter_map(|candidate| match delegated(&candidate) {
+                Some(amount) => Some((candidate, Some(amount))),
+                None if can_add => Some((candidate, None)),
+                None => None,
+            })
+            .min_by_key(|(_, current)| current.unwrap_or_else(Zero::zero))
+            .ok_or(Error::<T>::NoCandidates)?;
+
+        match current {
+            Some(_) => Self::as_pool(|origin| {
+                parachain_staking::Pallet::<T>::delegator_bond_more(
+                    origin,
+                    candidate.clone(),
+                    amount,
+                )
+            })?,
+            None => {
+                let candidate_delegation_count =
+                    parachain_staking::Pallet::<T>::candidate_info(&candidate)
+                        .map(|state| state.delegation_count)
+                        .unwrap_or_default();
+                Self::as_pool(|origin| {
+                    parachain_staking::Pallet::<T>::delegate(
+                        origin,
+                        candidate.clone(),
+                        amount,
+                        candidate_delegation_count,
+                        delegations.len() as u32,
+                    )
+                })?
+            }
+        }
+
+        Self::deposit_event(Event::Delegated(candidate, amount));
+        Ok(())
+    }
+
+    /// Pay the redemption queue and delegate idle funds at the start of round `now`:
+    /// - execute due delegation decreases, rescheduling the failed ones and the ones
+    ///   removed by staking (e.g. cancelled by a slash)
+    /// - pay the queue from idle funds
+    /// - schedule delegation decreases for the rest of the queue, the largest delegations first
+    /// - delegate the rest of idle funds, e.g. staking rewards
+    fn rebalance(now: RoundIndex) -> Weight {
+        let pool = Self::account_id();
+        let mut queue = Queue::<T>::get();
+        let state = parachain_staking::Pallet::<T>::delegator_state(&pool);
+        let delegation_count = state
+            .as_ref()
+            .map(|state| state.delegations.0.len() as u32)
+            .unwrap_or_default();
+        let requests = state.map(|state| state.requests()).unwrap_or_default();
+
+        for (candidate, amount) in Undelegating::<T>::iter().collect::<Vec<_>>() {
+            let request = match requests.get(&candidate) {
+                Some(request) if request.when_executable > now => continue,
+                Some(request) => request,
+                None => {
+                    // The request was removed by staking, schedule the amount again.
+                    queue.scheduled = queue.scheduled.saturating_sub(amount);
+                    Undelegating::<T>::remove(&candidate);
+                    continue;
+                }
+            };
+            let executed = Self::as_pool(|origin| {
+                parachain_staking::Pallet::<T>::execute_delegation_request(
+                    origin,
+                    pool.clone(),
+                    candidate.clone(),
+                )
+            });
+            if executed.is_ok() {
+                queue.paid = queue.paid.saturating_add(request.amount);
+                Undelegating::<T>::remove(&candidate);
+            } else if Self::as_pool(|origin| {
+                parachain_staking::Pallet::<T>::cancel_delegation_request(
+                    origin,
+                    candidate.clone(),
+                )
+            })
+            .is_ok()
+            {
+                queue.scheduled = queue.scheduled.saturating_sub(amount);
+                Undelegating::<T>::remove(&candidate);
+            }
+        }
+
+        let free = <T as parachain_staking::Config>::Currency::free_balance(&pool);
+        let mut idle = free
+            .saturating_sub(queue.claimable())
+            .saturating_sub(<T as parachain_staking::Config>::Currency::minimum_balance());
+        let paid = idle.min(queue.outstanding());
+        queue.scheduled = queue.scheduled.saturating_add(paid);
+        queue.paid = queue.paid.saturating_add(paid);
+        idle = idle.saturating_sub(paid);
+
+        if !queue.outstanding().is_zero() {
+            let mut delegations = parachain_staking::Pallet::<T>::delegator_state(&pool)
+                .map(|state| {
+                    let requests = state.requests();
+                    state
+                        .delegations
+                        .0
+                        .into_iter()
+                        .filter(|bond| !requests.contains_key(&bond.owner))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            delegations.sort_by(|a, b| b.amount.cmp(&a.amount));
+            for bond in delegations {
+                let outstanding = queue.outstanding();
+                if outstanding.is_zero() {
+                    break;
+                }
+                let less = outstanding.min(
+                    bond.amount
+                        .saturating_sub(<T as parachain_staking::Config>::MinDelegation::get()),
+                );
+                if less.is_zero() {
+                    continue;
+                }
+                let scheduled = Self::as_pool(|origin| {
+                    parachain_staking::Pallet::<T>::schedule_delegator_bond_less(
+                        origin,
+                        bond.owner.clone(),
+                        less,
+                    )
+                });
+                if scheduled.is_ok() {
+                    queue.scheduled = queue.scheduled.saturating_add(less);
+                    Undelegating::<T>::insert(&bond.owner, less);
+                    Self::deposit_event(Event::UndelegationScheduled(bond.owner, less));
+                }
+            }
+        }
+        Queue::<T>::put(queue);
+
+        if idle >= <T as parachain_staking::Config>::MinDelegation::get() {
+            let _ = Self::delegate(idle);
+        }
+
+        <T as Config>::WeightInfo::rebalance(delegation_count)
+    }
+}
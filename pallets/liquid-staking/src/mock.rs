@@ -0,0 +1,295 @@
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, Everything, GenesisBuild, Nothing, OnFinalize, OnInitialize},
+};
+use orml_traits::parameter_type_with_key;
+use parachain_staking::{InflationInfo, Range};
+use primitives::{currency::CurrencyId, Amount};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    Perbill, Percent,
+};
+
+pub type AccountId = u64;
+pub type Balance = u128;
+pub const COLLATOR_1: AccountId = 1;
+pub const COLLATOR_2: AccountId = 2;
+pub const ALICE: AccountId = 3;
+pub const BOB: AccountId = 4;
+pub const TREASURY: AccountId = 100;
+pub const LIQUID: CurrencyId = CurrencyId::LIQUID;
+
+mod liquid_staking {
+    pub use super::super::*;
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type Header = Header;
+    type Event = Event;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type Event = Event;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 4];
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const MinimumPeriod: u64 = 1000;
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+parameter_type_with_key! {
+    pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+        Default::default()
+    };
+}
+
+impl orml_tokens::Config for Runtime {
+    type Event = Event;
+    type Balance = Balance;
+    type Amount = Amount;
+    type CurrencyId = CurrencyId;
+    type WeightInfo = ();
+    type ExistentialDeposits = ExistentialDeposits;
+    type OnDust = ();
+    type MaxLocks = ();
+    type DustRemovalWhitelist = Nothing;
+}
+
+parameter_types! {
+    pub const MinBlocksPerRound: u32 = 3;
+    pub const DefaultBlocksPerRound: u32 = 5;
+    pub const DefaultBlockTime: u64 = 12_000;
+    pub const LeaveCandidatesDelay: u32 = 2;
+    pub const CandidateBondLessDelay: u32 = 2;
+    pub const CandidateCommissionDelay: u32 = 2;
+    pub const LeaveDelegatorsDelay: u32 = 2;
+    pub const RevokeDelegationDelay: u32 = 2;
+    pub const DelegationBondLessDelay: u32 = 2;
+    pub const RewardPaymentDelay: u32 = 2;
+    pub const MissedSlotsThreshold: Percent = Percent::from_percent(50);
+    pub static MaxMissedRounds: u32 = 0;
+    pub const DowntimeSlashFraction: Perbill = Perbill::from_percent(10);
+    pub static DelegatorDowntimeSlashFraction: Perbill = Perbill::from_percent(10);
+    pub static SlashDeferDuration: u32 = 2;
+    pub const MinSelectedCandidates: u32 = 5;
+    pub const MaxTopDelegationsPerCandidate: u32 = 4;
+    pub const MaxBottomDelegationsPerCandidate: u32 = 4;
+    pub const MaxDelegationsPerDelegator: u32 = 4;
+    pub const DefaultCollatorCommission: Perbill = Perbill::from_percent(20);
+    pub const DefaultParachainBondReservePercent: Percent = Percent::from_percent(30);
+    pub const MinCollatorStk: Balance = 10;
+    pub const MinDelegatorStk: Balance = 5;
+    pub const MinDelegation: Balance = 3;
+    pub const TreasuryAccount: AccountId = TREASURY;
+}
+
+impl parachain_staking::Config for Runtime {
+    type Event = Event;
+    type Currency = Balances;
+    type MonetaryGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type MinBlocksPerRound = MinBlocksPerRound;
+    type DefaultBlocksPerRound = DefaultBlocksPerRound;
+    type DefaultBlockTime = DefaultBlockTime;
+    type UnixTime = Timestamp;
+    type LeaveCandidatesDelay = LeaveCandidatesDelay;
+    type CandidateBondLessDelay = CandidateBondLessDelay;
+    type CandidateCommissionDelay = CandidateCommissionDelay;
+    type LeaveDelegatorsDelay = LeaveDelegatorsDelay;
+    type RevokeDelegationDelay = RevokeDelegationDelay;
+    type DelegationBondLessDelay = DelegationBondLessDelay;
+    type RewardPaymentDelay = RewardPaymentDelay;
+    type MissedSlotsThreshold = MissedSlotsThreshold;
+    type MaxMissedRounds = MaxMissedRounds;
+    type DowntimeSlashFraction = DowntimeSlashFraction;
+    type DelegatorDowntimeSlashFraction = DelegatorDowntimeSlashFraction;
+    type SlashDeferDuration = SlashDeferDuration;
+    type MinSelectedCandidates = MinSelectedCandidates;
+    type MaxTopDelegationsPerCandidate = MaxTopDelegationsPerCandidate;
+    type MaxBottomDelegationsPerCandidate = MaxBottomDelegationsPerCandidate;
+    type MaxDelegationsPerDelegator = MaxDelegationsPerDelegator;
+    type DefaultCollatorCommission = DefaultCollatorCommission;
+    type DefaultParachainBondReservePercent = DefaultParachainBondReservePercent;
+    type MinCollatorStk = MinCollatorStk;
+    type MinCandidateStk = MinCollatorStk;
+    type MinDelegatorStk = MinDelegatorStk;
+    type MinDelegation = MinDelegation;
+    type TreasuryAccount = TreasuryAccount;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const LiquidCurrencyId: CurrencyId = LIQUID;
+    pub const LiquidStakingPalletId: PalletId = PalletId(*b"pont/lqs");
+    pub const MinDeposit: Balance = 5;
+}
+
+impl Config for Runtime {
+    type Event = Event;
+    type MultiCurrency = Tokens;
+    type LiquidCurrencyId = LiquidCurrencyId;
+    type PalletId = LiquidStakingPalletId;
+    type MinDeposit = MinDeposit;
+    type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Storage, Call, Config<T>, Event<T>},
+        Timestamp: pallet_timestamp::{Pallet, Call, Storage},
+        Tokens: orml_tokens::{Pallet, Storage, Call, Config<T>, Event<T>},
+        ParachainStaking: parachain_staking::{Pallet, Call, Storage, Config<T>, Event<T>},
+        LiquidStaking: liquid_staking::{Pallet, Storage, Call, Event<T>},
+    }
+);
+
+#[derive(Default)]
+pub struct ExtBuilder {
+    balances: Vec<(AccountId, Balance)>,
+    candidates: Vec<(AccountId, Balance)>,
+}
+
+impl ExtBuilder {
+    pub fn balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+        self.balances = balances;
+        self
+    }
+
+    pub fn candidates(mut self, candidates: Vec<(AccountId, Balance)>) -> Self {
+        self.candidates = candidates;
+        self
+    }
+
+    pub fn build(self) -> sp_io::TestExternalities {
+        MaxMissedRounds::set(0);
+        DelegatorDowntimeSlashFraction::set(Perbill::from_percent(10));
+        SlashDeferDuration::set(2);
+
+        let mut t = frame_system::GenesisConfig::default()
+            .build_storage::<Runtime>()
+            .unwrap();
+
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: self.balances,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        // No inflation, rewards are simulated by topping up the pool account.
+        parachain_staking::GenesisConfig::<Runtime> {
+            candidates: self.candidates,
+            delegations: vec![],
+            inflation_config: InflationInfo {
+                expect: Range {
+                    min: 0,
+                    ideal: 0,
+                    max: 0,
+                },
+                annual: Range {
+                    min: Perbill::zero(),
+                    ideal: Perbill::zero(),
+                    max: Perbill::zero(),
+                },
+                round: Range {
+                    min: Perbill::zero(),
+                    ideal: Perbill::zero(),
+                    max: Perbill::zero(),
+                },
+            },
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
+
+/// Two candidates and two funded delegators.
+pub fn default_ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .balances(vec![
+            (COLLATOR_1, 100),
+            (COLLATOR_2, 100),
+            (ALICE, 100),
+            (BOB, 100),
+        ])
+        .candidates(vec![(COLLATOR_1, 20), (COLLATOR_2, 20)])
+        .build()
+}
+
+/// Rolls to the block `n`, running the staking hooks before the pool ones.
+pub fn roll_to(n: u64) {
+    while System::block_number() < n {
+        LiquidStaking::on_finalize(System::block_number());
+        ParachainStaking::on_finalize(System::block_number());
+        System::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+        System::on_initialize(System::block_number());
+        ParachainStaking::on_initialize(System::block_number());
+        LiquidStaking::on_initialize(System::block_number());
+        Timestamp::set_timestamp(Timestamp::now() + DefaultBlockTime::get());
+    }
+}
+
+/// Rolls to the first block of the staking `round`.
+pub fn roll_to_round(round: u64) {
+    roll_to((round - 1) * DefaultBlocksPerRound::get() as u64);
+}
@@ -0,0 +1,219 @@
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use orml_traits::MultiCurrency as _;
+use sp_runtime::Perbill;
+
+fn pool_delegation(candidate: AccountId) -> Option<Balance> {
+    ParachainStaking::delegator_state(LiquidStaking::account_id()).and_then(|state| {
+        state
+            .delegations
+            .0
+            .into_iter()
+            .find(|bond| bond.owner == candidate)
+            .map(|bond| bond.amount)
+    })
+}
+
+#[test]
+fn deposit_works() {
+    default_ext().execute_with(|| {
+        assert_ok!(LiquidStaking::deposit(Origin::signed(ALICE), 10));
+        System::assert_last_event(Event::LiquidStaking(crate::Event::Deposited(ALICE, 10, 10)));
+        assert_eq!(Balances::free_balance(ALICE), 90);
+        assert_eq!(Tokens::free_balance(LIQUID, &ALICE), 10);
+        assert_eq!(pool_delegation(COLLATOR_1), Some(10));
+
+        // Delegated to the candidate the pool delegates the least to.
+        assert_ok!(LiquidStaking::deposit(Origin::signed(BOB), 6));
+        assert_eq!(Tokens::free_balance(LIQUID, &BOB), 6);
+        assert_eq!(pool_delegation(COLLATOR_2), Some(6));
+        assert_ok!(LiquidStaking::deposit(Origin::signed(BOB), 5));
+        assert_eq!(pool_delegation(COLLATOR_2), Some(11));
+        assert_eq!(LiquidStaking::total_pooled(), 21);
+    });
+}
+
+#[test]
+fn deposit_fails() {
+    default_ext().execute_with(|| {
+        assert_noop!(
+            LiquidStaking::deposit(Origin::signed(ALICE), 4),
+            Error::<Runtime>::DepositTooLow
+        );
+    });
+
+    ExtBuilder::default()
+        .balances(vec![(ALICE, 100)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                LiquidStaking::deposit(Origin::signed(ALICE), 10),
+                Error::<Runtime>::NoCandidates
+            );
+        });
+}
+
+#[test]
+fn rewards_raise_exchange_rate() {
+    default_ext().execute_with(|| {
+        assert_ok!(LiquidStaking::deposit(Origin::signed(ALICE), 10));
+        // Rewards paid to the pool.
+        Balances::make_free_balance_be(&LiquidStaking::account_id(), 10);
+        assert_eq!(LiquidStaking::total_pooled(), 20);
+
+        assert_ok!(LiquidStaking::deposit(Origin::signed(BOB), 10));
+        System::assert_last_event(Event::LiquidStaking(crate::Event::Deposited(BOB, 10, 5)));
+        assert_eq!(Tokens::free_balance(LIQUID, &BOB), 5);
+        assert_eq!(LiquidStaking::native_amount(5), 10);
+        assert_eq!(LiquidStaking::native_amount(10), 20);
+    });
+}
+
+#[test]
+fn one_to_one_while_nothing_pooled_or_issued() {
+    default_ext().execute_with(|| {
+        assert_eq!(LiquidStaking::total_pooled(), 0);
+        assert_eq!(LiquidStaking::liquid_amount(10), 10);
+        assert_eq!(LiquidStaking::native_amount(10), 10);
+    });
+}
+
+#[test]
+fn nothing_minted_for_pool_without_issuance() {
+    default_ext().execute_with(|| {
+        // Funds sent to the pool before the first deposit.
+        Balances::make_free_balance_be(&LiquidStaking::account_id(), 10);
+        assert_eq!(LiquidStaking::liquid_amount(10), 0);
+        assert_noop!(
+            LiquidStaking::deposit(Origin::signed(ALICE), 10),
+            Error::<Runtime>::AmountTooLow
+        );
+    });
+}
+
+#[test]
+fn redeem_works() {
+    default_ext().execute_with(|| {
+        assert_ok!(LiquidStaking::deposit(Origin::signed(ALICE), 10));
+        assert_noop!(
+            LiquidStaking::redeem(Origin::signed(ALICE), 0),
+            Error::<Runtime>::AmountTooLow
+        );
+        assert_noop!(
+            LiquidStaking::redeem(Origin::signed(ALICE), 11),
+            orml_tokens::Error::<Runtime>::BalanceTooLow
+        );
+
+        assert_ok!(LiquidStaking::redeem(Origin::signed(ALICE), 4));
+        System::assert_last_event(Event::LiquidStaking(crate::Event::Redeemed(ALICE, 4, 4)));
+        assert_eq!(Tokens::free_balance(LIQUID, &ALICE), 6);
+        assert_eq!(
+            LiquidStaking::redemptions(ALICE),
+            vec![Redemption {
+                amount: 4,
+                position: 4
+            }]
+        );
+        assert_eq!(LiquidStaking::redemption_queue().requested, 4);
+        // Redeemed amount doesn't back the derivative anymore.
+        assert_eq!(LiquidStaking::total_pooled(), 6);
+        assert_eq!(LiquidStaking::native_amount(6), 6);
+    });
+}
+
+#[test]
+fn redemption_is_paid_by_undelegating() {
+    default_ext().execute_with(|| {
+        assert_ok!(LiquidStaking::deposit(Origin::signed(ALICE), 10));
+        assert_ok!(LiquidStaking::deposit(Origin::signed(BOB), 10));
+        assert_ok!(LiquidStaking::redeem(Origin::signed(ALICE), 4));
+
+        // Decrease is scheduled on the first rebalance, in round 1.
+        roll_to(2);
+        assert!(System::events().iter().any(|record| record.event
+            == Event::LiquidStaking(crate::Event::UndelegationScheduled(COLLATOR_1, 4))));
+        assert_eq!(LiquidStaking::redemption_queue().scheduled, 4);
+        assert_noop!(
+            LiquidStaking::claim(Origin::signed(ALICE)),
+            Error::<Runtime>::NothingToClaim
+        );
+
+        roll_to_round(2);
+        assert_noop!(
+            LiquidStaking::claim(Origin::signed(ALICE)),
+            Error::<Runtime>::NothingToClaim
+        );
+
+        // Executable `DelegationBondLessDelay` rounds later.
+        roll_to_round(3);
+        assert_eq!(LiquidStaking::redemption_queue().paid, 4);
+        assert_eq!(pool_delegation(COLLATOR_1), Some(6));
+        assert_ok!(LiquidStaking::claim(Origin::signed(ALICE)));
+        System::assert_last_event(Event::LiquidStaking(crate::Event::Claimed(ALICE, 4)));
+        assert_eq!(Balances::free_balance(ALICE), 94);
+        assert!(LiquidStaking::redemptions(ALICE).is_empty());
+        assert_eq!(LiquidStaking::total_pooled(), 16);
+    });
+}
+
+#[test]
+fn decrease_cancelled_by_slash_is_scheduled_again() {
+    default_ext().execute_with(|| {
+        // Collators don't author, they are taken offline at the end of round 1 and slashed
+        // at the start of round 3, when the decrease becomes executable.
+        MaxMissedRounds::set(1);
+        SlashDeferDuration::set(1);
+        DelegatorDowntimeSlashFraction::set(Perbill::from_percent(50));
+        assert_ok!(LiquidStaking::deposit(Origin::signed(ALICE), 10));
+        assert_ok!(LiquidStaking::deposit(Origin::signed(BOB), 10));
+        assert_ok!(LiquidStaking::redeem(Origin::signed(ALICE), 7));
+
+        roll_to(2);
+        assert_eq!(LiquidStaking::undelegating(COLLATOR_1), 7);
+        assert_eq!(LiquidStaking::redemption_queue().scheduled, 7);
+
+        // The slash leaves less than the decrease, so staking cancels it.
+        roll_to_round(3);
+        assert_eq!(pool_delegation(COLLATOR_1), Some(5));
+        assert_eq!(pool_delegation(COLLATOR_2), Some(5));
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            Event::ParachainStaking(parachain_staking::Event::CancelledDelegationRequest(..))
+        )));
+
+        // The cancelled amount is outstanding again and scheduled from what is left.
+        assert_eq!(LiquidStaking::undelegating(COLLATOR_1), 2);
+        assert_eq!(LiquidStaking::undelegating(COLLATOR_2), 2);
+        let queue = LiquidStaking::redemption_queue();
+        assert_eq!(queue.scheduled, 4);
+        assert_eq!(queue.outstanding(), 3);
+        assert_eq!(queue.paid, 0);
+    });
+}
+
+#[test]
+fn redemption_is_paid_from_idle_funds() {
+    default_ext().execute_with(|| {
+        assert_ok!(LiquidStaking::deposit(Origin::signed(ALICE), 10));
+        // Rewards paid to the pool.
+        Balances::make_free_balance_be(&LiquidStaking::account_id(), 11);
+        assert_ok!(LiquidStaking::redeem(Origin::signed(ALICE), 2));
+        System::assert_last_event(Event::LiquidStaking(crate::Event::Redeemed(ALICE, 2, 4)));
+
+        // Paid from idle funds, the rest of them is delegated.
+        roll_to(2);
+        assert_eq!(LiquidStaking::redemption_queue().paid, 4);
+        assert_eq!(pool_delegation(COLLATOR_1), Some(10));
+        assert_eq!(pool_delegation(COLLATOR_2), Some(6));
+
+        assert_ok!(LiquidStaking::claim(Origin::signed(ALICE)));
+        assert_eq!(Balances::free_balance(ALICE), 94);
+        assert_noop!(
+            LiquidStaking::claim(Origin::signed(ALICE)),
+            Error::<Runtime>::NothingToClaim
+        );
+    });
+}
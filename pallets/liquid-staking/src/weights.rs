@@ -0,0 +1,81 @@
+// Copyright 2020-2022 Pontem Foundation LTD.
+// This file is part of Pontem Network.
+// Apache 2.0
+
+//! Weights for liquid_staking
+//!
+//! NOT BENCHMARK OUTPUT: the weights are estimated. Regenerate the file from `benchmarking.rs`
+//! on reference hardware before relying on them:
+//!
+//! make bench-liquid-staking
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for liquid_staking.
+pub trait WeightInfo {
+    fn deposit() -> Weight;
+    fn redeem() -> Weight;
+    fn claim() -> Weight;
+    fn rebalance(x: u32) -> Weight;
+}
+
+/// Weights for liquid_staking using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn deposit() -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(14 as Weight))
+            .saturating_add(T::DbWeight::get().writes(10 as Weight))
+    }
+    fn redeem() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn claim() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn rebalance(x: u32) -> Weight {
+        (130_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(x as Weight))
+            .saturating_add(T::DbWeight::get().reads(16 as Weight))
+            .saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(x as Weight)))
+            .saturating_add(T::DbWeight::get().writes(11 as Weight))
+            .saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(x as Weight)))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn deposit() -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(14 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(10 as Weight))
+    }
+    fn redeem() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn claim() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn rebalance(x: u32) -> Weight {
+        (130_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(x as Weight))
+            .saturating_add(RocksDbWeight::get().reads(16 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(x as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(11 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(x as Weight)))
+    }
+}
@@ -0,0 +1,34 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+// clippy doesn't likes sp- macros
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use sp_std::prelude::*;
+pub use parachain_staking::{
+    AuthoringStats, CollatorLiveness, DelegationChange, DelegationOverview, DelegationRequest,
+    DelegatorOverview, DelegatorStatus, RewardDestination, StakingApr,
+};
+
+// Describe Runtime API for Parachain Staking pallet.
+sp_api::decl_runtime_apis! {
+    #[api_version(3)]
+    pub trait ParachainStakingApiRuntime<AccountId, Balance> where
+        AccountId: codec::Codec,
+        Balance: codec::Codec,
+    {
+        // Get rewards of the account per round for the rounds waiting for payout.
+        fn pending_rewards(who: AccountId) -> Vec<(u32, Balance)>;
+
+        // Get reward the account is expected to receive for the round waiting for payout.
+        fn expected_reward(who: AccountId, round: u32) -> Balance;
+
+        // Get estimated annual return of staking with the candidate.
+        fn candidate_apr(candidate: AccountId) -> Option<StakingApr>;
+
+        // Get delegations of the delegator with their pending requests.
+        fn delegator_overview(delegator: AccountId) -> Option<DelegatorOverview<AccountId, Balance>>;
+
+        // Get authoring stats of the collator for the last rounds.
+        fn collator_liveness(collator: AccountId) -> CollatorLiveness;
+    }
+}
@@ -0,0 +1,354 @@
+use std::{fmt::Display, str::FromStr, sync::Arc};
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT},
+    FixedPointNumber, PerThing,
+};
+use sp_api::ProvideRuntimeApi;
+use parachain_staking_rpc_runtime::ParachainStakingApiRuntime;
+use serde::{Serialize, Deserialize};
+
+// Reward for a round struct with serde, balances are strings as they don't fit JSON numbers.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(bound(serialize = "Balance: Display", deserialize = "Balance: FromStr"))]
+pub struct RoundReward<Balance> {
+    pub round: u32,
+    #[serde(with = "as_string")]
+    pub amount: Balance,
+}
+
+// Estimated annual return struct with serde, rates are fixed point numbers with 18 decimals
+// (10^18 is 100%) as strings as they don't fit JSON numbers.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakingApr {
+    #[serde(with = "as_string")]
+    pub collator: u128,
+    #[serde(with = "as_string")]
+    pub delegator: u128,
+}
+
+impl From<parachain_staking_rpc_runtime::StakingApr> for StakingApr {
+    fn from(apr: parachain_staking_rpc_runtime::StakingApr) -> Self {
+        Self {
+            collator: apr.collator.into_inner(),
+            delegator: apr.delegator.into_inner(),
+        }
+    }
+}
+
+// Reward destination enum with serde.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RewardDestination<AccountId> {
+    Free,
+    Account(AccountId),
+    Stake,
+    Treasury,
+}
+
+impl<AccountId> From<parachain_staking_rpc_runtime::RewardDestination<AccountId>>
+    for RewardDestination<AccountId>
+{
+    fn from(destination: parachain_staking_rpc_runtime::RewardDestination<AccountId>) -> Self {
+        use parachain_staking_rpc_runtime::RewardDestination as Destination;
+        match destination {
+            Destination::Free => Self::Free,
+            Destination::Account(account) => Self::Account(account),
+            Destination::Stake => Self::Stake,
+            Destination::Treasury => Self::Treasury,
+        }
+    }
+}
+
+// Pending delegation request struct with serde.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(bound(serialize = "Balance: Display", deserialize = "Balance: FromStr"))]
+pub struct DelegationRequest<Balance> {
+    pub revoke: bool,
+    #[serde(with = "as_string")]
+    pub amount: Balance,
+    pub when_executable: u32,
+}
+
+// Delegation struct with serde, auto-compounding rate is in percent.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(bound(
+    serialize = "AccountId: Serialize, Balance: Display",
+    deserialize = "AccountId: Deserialize<'de>, Balance: FromStr"
+))]
+pub struct Delegation<AccountId, Balance> {
+    pub candidate: AccountId,
+    #[serde(with = "as_string")]
+    pub amount: Balance,
+    pub auto_compound: u8,
+    pub request: Option<DelegationRequest<Balance>>,
+}
+
+// Delegator overview struct with serde, `leaving` is the round the exit is executable from.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(bound(
+    serialize = "AccountId: Serialize, Balance: Display",
+    deserialize = "AccountId: Deserialize<'de>, Balance: FromStr"
+))]
+pub struct DelegatorOverview<AccountId, Balance> {
+    #[serde(with = "as_string")]
+    pub total: Balance,
+    #[serde(with = "as_string")]
+    pub less_total: Balance,
+    pub leaving: Option<u32>,
+    pub reward_destination: RewardDestination<AccountId>,
+    pub delegations: Vec<Delegation<AccountId, Balance>>,
+}
+
+impl<AccountId, Balance>
+    From<parachain_staking_rpc_runtime::DelegatorOverview<AccountId, Balance>>
+    for DelegatorOverview<AccountId, Balance>
+{
+    fn from(
+        overview: parachain_staking_rpc_runtime::DelegatorOverview<AccountId, Balance>,
+    ) -> Self {
+        use parachain_staking_rpc_runtime::{DelegationChange, DelegatorStatus};
+
+        Self {
+            total: overview.total,
+            less_total: overview.less_total,
+            leaving: match overview.status {
+                DelegatorStatus::Active => None,
+                DelegatorStatus::Leaving(round) => Some(round),
+            },
+            reward_destination: overview.reward_destination.into(),
+            delegations: overview
+                .delegations
+                .into_iter()
+                .map(|delegation| Delegation {
+                    candidate: delegation.candidate,
+                    amount: delegation.amount,
+                    auto_compound: delegation.auto_compound.deconstruct(),
+                    request: delegation.request.map(|request| DelegationRequest {
+                        revoke: request.action == DelegationChange::Revoke,
+                        amount: request.amount,
+                        when_executable: request.when_executable,
+                    }),
+                })
+                .collect(),
+        }
+    }
+}
+
+// Authoring stats of a collator in a round struct with serde.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundAuthoringStats {
+    pub round: u32,
+    pub authored: u32,
+    pub expected: u32,
+}
+
+// Collator liveness struct with serde.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollatorLiveness {
+    pub missed_rounds: u32,
+    pub rounds: Vec<RoundAuthoringStats>,
+}
+
+impl From<parachain_staking_rpc_runtime::CollatorLiveness> for CollatorLiveness {
+    fn from(liveness: parachain_staking_rpc_runtime::CollatorLiveness) -> Self {
+        Self {
+            missed_rounds: liveness.missed_rounds,
+            rounds: liveness
+                .rounds
+                .into_iter()
+                .map(|(round, stats)| RoundAuthoringStats {
+                    round,
+                    authored: stats.authored,
+                    expected: stats.expected,
+                })
+                .collect(),
+        }
+    }
+}
+
+mod as_string {
+    use super::*;
+    use serde::{de::Error, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, T: Display>(
+        value: &T,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, T: FromStr>(
+        deserializer: D,
+    ) -> std::result::Result<T, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value
+            .parse()
+            .map_err(|_| D::Error::custom("can't parse number"))
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(500),
+        message: "Error during requesting Runtime API".into(),
+        data: Some(format!("{:?}", e).into()),
+    }
+}
+
+// RPC calls.
+#[rpc]
+pub trait ParachainStakingApiRpc<BlockHash, AccountId, Balance> {
+    #[rpc(name = "parachainStaking_pendingRewards")]
+    fn pending_rewards(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<RoundReward<Balance>>>;
+
+    #[rpc(name = "parachainStaking_expectedReward")]
+    fn expected_reward(
+        &self,
+        who: AccountId,
+        round: u32,
+        at: Option<BlockHash>,
+    ) -> Result<RoundReward<Balance>>;
+
+    #[rpc(name = "parachainStaking_candidateApr")]
+    fn candidate_apr(
+        &self,
+        candidate: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<StakingApr>>;
+
+    #[rpc(name = "parachainStaking_delegatorOverview")]
+    fn delegator_overview(
+        &self,
+        delegator: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<DelegatorOverview<AccountId, Balance>>>;
+
+    #[rpc(name = "parachainStaking_collatorLiveness")]
+    fn collator_liveness(
+        &self,
+        collator: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<CollatorLiveness>;
+}
+
+pub struct ParachainStakingApi<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> ParachainStakingApi<C, P> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block> ParachainStakingApi<C, Block>
+where
+    Block: BlockT,
+    C: HeaderBackend<Block>,
+{
+    fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash))
+    }
+}
+
+impl<C, Block, AccountId, Balance>
+    ParachainStakingApiRpc<<Block as BlockT>::Hash, AccountId, Balance>
+    for ParachainStakingApi<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec + Serialize + Send + Sync + 'static,
+    Balance: Codec + Display + FromStr + Send + Sync + 'static,
+    C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: ParachainStakingApiRuntime<Block, AccountId, Balance>,
+{
+    fn pending_rewards(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<RoundReward<Balance>>> {
+        let api = self.client.runtime_api();
+        let res = api
+            .pending_rewards(&self.block_id(at), who)
+            .map_err(runtime_error)?;
+
+        Ok(res
+            .into_iter()
+            .map(|(round, amount)| RoundReward { round, amount })
+            .collect())
+    }
+
+    fn expected_reward(
+        &self,
+        who: AccountId,
+        round: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<RoundReward<Balance>> {
+        let api = self.client.runtime_api();
+        let amount = api
+            .expected_reward(&self.block_id(at), who, round)
+            .map_err(runtime_error)?;
+
+        Ok(RoundReward { round, amount })
+    }
+
+    fn candidate_apr(
+        &self,
+        candidate: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<StakingApr>> {
+        let api = self.client.runtime_api();
+        let res = api
+            .candidate_apr(&self.block_id(at), candidate)
+            .map_err(runtime_error)?;
+
+        Ok(res.map(StakingApr::from))
+    }
+
+    fn delegator_overview(
+        &self,
+        delegator: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<DelegatorOverview<AccountId, Balance>>> {
+        let api = self.client.runtime_api();
+        let res = api
+            .delegator_overview(&self.block_id(at), delegator)
+            .map_err(runtime_error)?;
+
+        Ok(res.map(DelegatorOverview::from))
+    }
+
+    fn collator_liveness(
+        &self,
+        collator: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<CollatorLiveness> {
+        let api = self.client.runtime_api();
+        let res = api
+            .collator_liveness(&self.block_id(at), collator)
+            .map_err(runtime_error)?;
+
+        Ok(res.into())
+    }
+}
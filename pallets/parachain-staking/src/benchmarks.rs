@@ -18,8 +18,9 @@
 
 //! Benchmarking
 use crate::{
-    BalanceOf, Call, CandidateBondLessRequest, Config, DelegationChange, DelegationRequest,
-    Pallet, Range,
+    BalanceOf, Call, CandidateBondLessRequest, CommissionBounds, Config, DelegationChange,
+    DelegationRequest, Pallet, PendingSlashes, Range, RewardDestination, UnappliedSlash,
+    UnappliedSlashes,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize, ReservableCurrency};
@@ -123,51 +124,6 @@ fn roll_to_and_author<T: Config>(round_delay: u32, author: T::AccountId) {
 const USER_SEED: u32 = 999666;
 
 benchmarks! {
-    // HOTFIX BENCHMARK
-    hotfix_remove_delegation_requests {
-        let x in 2..<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
-        + <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get();
-        let mut delegators: Vec<T::AccountId> = Vec::new();
-        let collator = create_funded_collator::<T>(
-            "candidate",
-            100,
-            0u32.into(),
-            true,
-            1u32
-        )?;
-        let mut col_del_count = 0u32;
-        for i in 1..x {
-            let seed = USER_SEED + i;
-            let delegator = create_funded_delegator::<T>(
-                "delegator",
-                seed,
-                0u32.into(),
-                collator.clone(),
-                true,
-                col_del_count,
-            )?;
-            delegators.push(delegator);
-            col_del_count += 1u32;
-        }
-    }: _(RawOrigin::Root, delegators)
-    verify { }
-
-    hotfix_update_candidate_pool_value {
-        let x in 5..200;
-        let mut candidates: Vec<T::AccountId> = Vec::new();
-        for i in 1..x {
-            let account = create_funded_collator::<T>(
-                "candidate",
-                i + 100,
-                0u32.into(),
-                true,
-                i
-            )?;
-            candidates.push(account);
-        }
-    }: _(RawOrigin::Root, candidates)
-    verify { }
-
     // MONETARY ORIGIN DISPATCHABLES
 
     set_staking_expectations {
@@ -193,6 +149,13 @@ benchmarks! {
         assert_eq!(Pallet::<T>::inflation_config().annual, inflation_range);
     }
 
+    set_inflation_decay {
+        let rate = Perbill::from_percent(10);
+    }: _(RawOrigin::Root, rate, Perbill::from_percent(2))
+    verify {
+        assert_eq!(Pallet::<T>::inflation_decay().map(|decay| decay.rate), Some(rate));
+    }
+
     set_parachain_bond_account {
         let parachain_bond_account: T::AccountId = account("TEST", 0u32, USER_SEED);
     }: _(RawOrigin::Root, parachain_bond_account.clone())
@@ -201,6 +164,14 @@ benchmarks! {
     }
 
     set_parachain_bond_reserve_percent {
+        // commissions must fit in what is left after the reserve
+        Pallet::<T>::set_commission_bounds(
+            RawOrigin::Root.into(),
+            CommissionBounds {
+                min: Perbill::zero(),
+                max: Perbill::from_percent(67),
+            },
+        )?;
     }: _(RawOrigin::Root, Percent::from_percent(33))
     verify {
         assert_eq!(Pallet::<T>::parachain_bond_info().percent, Percent::from_percent(33));
@@ -220,6 +191,16 @@ benchmarks! {
         assert_eq!(Pallet::<T>::collator_commission(), Perbill::from_percent(33));
     }
 
+    set_commission_bounds {
+        let bounds = CommissionBounds {
+            min: Perbill::from_percent(5),
+            max: Perbill::from_percent(50),
+        };
+    }: _(RawOrigin::Root, bounds)
+    verify {
+        assert_eq!(Pallet::<T>::candidate_commission_bounds(), bounds);
+    }
+
     set_blocks_per_round {}: _(RawOrigin::Root, 1200u32)
     verify {
         assert_eq!(Pallet::<T>::round().length, 1200u32);
@@ -479,6 +460,22 @@ benchmarks! {
         );
     }
 
+    schedule_candidate_commission_change {
+        let caller: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32,
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), Perbill::from_percent(10))
+    verify {
+        assert_eq!(
+            Pallet::<T>::candidate_commission_request(&caller).unwrap().commission,
+            Perbill::from_percent(10)
+        );
+    }
+
     delegate {
         let x in 3..<<T as Config>::MaxDelegationsPerDelegator as Get<u32>>::get();
         let y in 2..<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get();
@@ -854,6 +851,124 @@ benchmarks! {
         );
     }
 
+    set_auto_compound {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+        let bond = <<T as Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
+        Pallet::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            collator.clone(),
+            bond,
+            0u32,
+            0u32
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), collator.clone(), Percent::from_percent(50))
+    verify {
+        assert_eq!(
+            Pallet::<T>::auto_compound(&caller, &collator),
+            Percent::from_percent(50)
+        );
+    }
+
+    set_reward_destination {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let (caller, _) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+        let bond = <<T as Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
+        Pallet::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            collator.clone(),
+            bond,
+            0u32,
+            0u32
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), RewardDestination::Account(collator.clone()))
+    verify {
+        assert_eq!(
+            Pallet::<T>::reward_destination(&caller),
+            RewardDestination::Account(collator)
+        );
+    }
+
+    cancel_deferred_slash {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        // worst case slashes all top delegations
+        let others: Vec<(T::AccountId, BalanceOf<T>)> =
+            (0..<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get())
+                .map(|i| (account("delegator", i, USER_SEED), 1u32.into()))
+                .collect();
+        let slash = UnappliedSlash {
+            collator: collator.clone(),
+            own: min_candidate_stk::<T>(),
+            others,
+        };
+        for account in slash.accounts() {
+            <PendingSlashes<T>>::insert(account, 1u32);
+        }
+        <UnappliedSlashes<T>>::append(10u32, slash);
+    }: _(RawOrigin::Root, 10u32, collator.clone())
+    verify {
+        assert!(Pallet::<T>::unapplied_slashes(10u32).is_empty());
+        assert_eq!(Pallet::<T>::pending_slashes(&collator), 0);
+    }
+
+    apply_slash {
+        // worst case slashes all top delegations
+        let d in 0..<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get();
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let mut others: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+        for i in 0..d {
+            let delegator = create_funded_delegator::<T>(
+                "delegator",
+                i,
+                0u32.into(),
+                collator.clone(),
+                true,
+                i
+            )?;
+            // slashing the whole delegation kicks it
+            others.push((delegator, min_delegator_stk::<T>()));
+        }
+        let slash = UnappliedSlash {
+            collator: collator.clone(),
+            own: min_candidate_stk::<T>(),
+            others,
+        };
+        for account in slash.accounts() {
+            <PendingSlashes<T>>::insert(account, 1u32);
+        }
+    }: { Pallet::<T>::apply_slash(&slash); }
+    verify {
+        assert_eq!(Pallet::<T>::pending_slashes(&collator), 0);
+        assert_eq!(
+            Pallet::<T>::candidate_info(&collator).map(|info| info.delegation_count),
+            Some(0)
+        );
+    }
+
     // ON_INITIALIZE
 
     round_transition_on_initialize {
@@ -1042,6 +1157,12 @@ benchmarks! {
                 true,
                 delegators.len() as u32,
             )?;
+            // auto-compounding is the most expensive payout path
+            Pallet::<T>::set_auto_compound(
+                RawOrigin::Signed(delegator.clone()).into(),
+                sole_collator.clone(),
+                Percent::from_percent(50),
+            )?;
             delegators.push(delegator);
             total_staked += initial_stake_amount;
         }
@@ -1069,6 +1190,7 @@ benchmarks! {
             bond: 1_000u32.into(),
             delegations,
             total: 1_000_000u32.into(),
+            commission: Perbill::from_rational(1u32, 100u32),
         });
 
         <Points<T>>::insert(round_for_payout, 100);
@@ -1134,30 +1256,23 @@ mod tests {
     }
 
     #[test]
-    fn bench_hotfix_remove_delegation_requests() {
-        new_test_ext().execute_with(|| {
-            assert_ok!(Pallet::<Test>::test_benchmark_hotfix_remove_delegation_requests());
-        });
-    }
-
-    #[test]
-    fn bench_hotfix_update_candidate_pool_value() {
+    fn bench_set_staking_expectations() {
         new_test_ext().execute_with(|| {
-            assert_ok!(Pallet::<Test>::test_benchmark_hotfix_update_candidate_pool_value());
+            assert_ok!(Pallet::<Test>::test_benchmark_set_staking_expectations());
         });
     }
 
     #[test]
-    fn bench_set_staking_expectations() {
+    fn bench_set_inflation() {
         new_test_ext().execute_with(|| {
-            assert_ok!(Pallet::<Test>::test_benchmark_set_staking_expectations());
+            assert_ok!(Pallet::<Test>::test_benchmark_set_inflation());
         });
     }
 
     #[test]
-    fn bench_set_inflation() {
+    fn bench_set_inflation_decay() {
         new_test_ext().execute_with(|| {
-            assert_ok!(Pallet::<Test>::test_benchmark_set_inflation());
+            assert_ok!(Pallet::<Test>::test_benchmark_set_inflation_decay());
         });
     }
 
@@ -1189,6 +1304,13 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_set_commission_bounds() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_commission_bounds());
+        });
+    }
+
     #[test]
     fn bench_set_blocks_per_round() {
         new_test_ext().execute_with(|| {
@@ -1266,6 +1388,13 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_schedule_candidate_commission_change() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_schedule_candidate_commission_change());
+        });
+    }
+
     #[test]
     fn bench_delegate() {
         new_test_ext().execute_with(|| {
@@ -1343,6 +1472,34 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_set_auto_compound() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_auto_compound());
+        });
+    }
+
+    #[test]
+    fn bench_set_reward_destination() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_reward_destination());
+        });
+    }
+
+    #[test]
+    fn bench_cancel_deferred_slash() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_cancel_deferred_slash());
+        });
+    }
+
+    #[test]
+    fn bench_apply_slash() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_apply_slash());
+        });
+    }
+
     #[test]
     fn bench_round_transition_on_initialize() {
         new_test_ext().execute_with(|| {
@@ -21,18 +21,25 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_runtime::traits::Saturating;
 use sp_runtime::PerThing;
 use sp_runtime::{Perbill, RuntimeDebug};
 use substrate_fixed::transcendental::pow as floatpow;
 use substrate_fixed::types::{I32F32, I64F64};
 
-const SECONDS_PER_YEAR: u32 = 31557600;
-const SECONDS_PER_BLOCK: u32 = 12;
-pub const BLOCKS_PER_YEAR: u32 = SECONDS_PER_YEAR / SECONDS_PER_BLOCK;
+const MILLISECS_PER_YEAR: u64 = 31557600 * 1000;
+/// Relative change of the observed block time below which per-round inflation isn't recomputed
+pub const BLOCK_TIME_TOLERANCE: Perbill = Perbill::from_percent(1);
 
-fn rounds_per_year<T: Config>() -> u32 {
+/// Number of blocks per year at the current block time
+pub fn blocks_per_year<T: Config>() -> u32 {
+    let block_time = <Pallet<T>>::block_time().max(1);
+    (MILLISECS_PER_YEAR / block_time).min(u32::MAX.into()) as u32
+}
+
+pub(crate) fn rounds_per_year<T: Config>() -> u32 {
     let blocks_per_round = <Pallet<T>>::round().length;
-    BLOCKS_PER_YEAR / blocks_per_round
+    (blocks_per_year::<T>() / blocks_per_round).max(1)
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -126,8 +133,8 @@ impl<Balance> InflationInfo<Balance> {
         self.round = annual_to_round::<T>(new);
     }
     /// Reset round inflation rate based on changes to round length
-    pub fn reset_round(&mut self, new_length: u32) {
-        let periods = BLOCKS_PER_YEAR / new_length;
+    pub fn reset_round<T: Config>(&mut self, new_length: u32) {
+        let periods = (blocks_per_year::<T>() / new_length).max(1);
         self.round = perbill_annual_to_perbill_round(self.annual, periods);
     }
     /// Set staking expectations
@@ -136,6 +143,33 @@ impl<Balance> InflationInfo<Balance> {
     }
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Schedule decaying the annual inflation rates every year
+pub struct InflationDecay {
+    /// Relative decrease of the annual inflation rates applied every year
+    pub rate: Perbill,
+    /// Annual inflation rates don't decay below this floor
+    pub floor: Perbill,
+    /// Round at which the next decay is applied
+    pub next_round: u32,
+}
+
+impl InflationDecay {
+    /// Decay the annual inflation rates once, rates already below the floor are kept
+    pub fn decay(&self, annual: Range<Perbill>) -> Range<Perbill> {
+        let decay = |rate: Perbill| -> Perbill {
+            rate.saturating_sub(self.rate * rate)
+                .max(self.floor.min(rate))
+        };
+        Range {
+            min: decay(annual.min),
+            ideal: decay(annual.ideal),
+            max: decay(annual.max),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -195,6 +229,35 @@ mod tests {
         );
     }
     #[test]
+    fn inflation_decay_stops_at_floor() {
+        let decay = InflationDecay {
+            rate: Perbill::from_percent(10),
+            floor: Perbill::from_percent(4),
+            next_round: 0,
+        };
+        let schedule = Range {
+            min: Perbill::from_percent(3),
+            ideal: Perbill::from_percent(4),
+            max: Perbill::from_percent(5),
+        };
+        assert_eq!(
+            decay.decay(schedule),
+            Range {
+                min: Perbill::from_percent(3),
+                ideal: Perbill::from_percent(4),
+                max: Perbill::from_rational(45u32, 1000u32),
+            }
+        );
+        assert_eq!(
+            decay.decay(decay.decay(schedule)),
+            Range {
+                min: Perbill::from_percent(3),
+                ideal: Perbill::from_percent(4),
+                max: Perbill::from_percent(4),
+            }
+        );
+    }
+    #[test]
     fn expected_parameterization() {
         let expected_round_schedule: Range<u128> = Range {
             min: 45,
@@ -45,6 +45,22 @@
 //!
 //! To revoke a delegation, call `revoke_delegation` with the collator candidate's account.
 //! To leave the set of delegators and revoke all delegations, call `leave_delegators`.
+//!
+//! To bond a share of each delegation reward back into the same delegation, call
+//! `set_auto_compound` with the collator candidate's account and the percent to compound.
+//!
+//! Candidates set their own commission within the bounds set by governance by calling
+//! `schedule_candidate_commission_change`. The change is applied at the start of the round
+//! `T::CandidateCommissionDelay` rounds later and the commission is snapshotted with the stake
+//! of the round, so rewards are always paid with the commission active during the round.
+//!
+//! Candidates and delegators choose where their rewards go by calling `set_reward_destination`:
+//! the free balance (default), another account, their own stake or the treasury.
+//!
+//! Candidates taken offline for missing their slots for `T::MaxMissedRounds` consecutive rounds
+//! are slashed `T::DowntimeSlashFraction` of their self bond and `T::DelegatorDowntimeSlashFraction`
+//! of each top delegation. The slash is applied to the treasury `T::SlashDeferDuration` rounds
+//! later, capped by the bonds at that time, unless cancelled with `cancel_deferred_slash`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -55,32 +71,42 @@ pub mod migrations;
 #[cfg(test)]
 mod mock;
 mod set;
+pub mod slashing;
 #[cfg(test)]
 mod tests;
 pub mod weights;
 
 use frame_support::pallet;
-pub use inflation::{InflationInfo, Range};
+pub use inflation::{InflationDecay, InflationInfo, Range};
+pub use slashing::UnappliedSlash;
 use weights::WeightInfo;
 
 pub use pallet::*;
 
 #[pallet]
 pub mod pallet {
-    use crate::{set::OrderedSet, InflationInfo, Range, WeightInfo};
+    use crate::{set::OrderedSet, InflationDecay, InflationInfo, Range, UnappliedSlash, WeightInfo};
     use frame_support::pallet_prelude::*;
-    use frame_support::traits::{Currency, Get, Imbalance, ReservableCurrency};
+    use frame_support::sp_io::KillStorageResult;
+    use frame_support::storage::{with_transaction, TransactionOutcome};
+    use frame_support::traits::{
+        BalanceStatus, Currency, Get, Imbalance, ReservableCurrency, UnixTime,
+    };
     use frame_system::pallet_prelude::*;
     use parity_scale_codec::{Decode, Encode};
     use scale_info::TypeInfo;
     use sp_runtime::{
-        traits::{AtLeast32BitUnsigned, Saturating, Zero},
-        Perbill, Percent, RuntimeDebug,
+        traits::{SaturatedConversion, Saturating, Zero},
+        FixedPointNumber, FixedU128, Perbill, Percent, RuntimeDebug,
     };
     use sp_std::{cmp::Ordering, collections::btree_map::BTreeMap, prelude::*};
 
+    /// The current storage version.
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     /// Pallet for parachain staking
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(PhantomData<T>);
 
@@ -152,6 +178,8 @@ pub mod pallet {
         pub bond: Balance,
         pub delegations: Vec<Bond<AccountId, Balance>>,
         pub total: Balance,
+        /// Commission rate of the collator for the round
+        pub commission: Perbill,
     }
 
     impl<A, B: Default> Default for CollatorSnapshot<A, B> {
@@ -160,6 +188,7 @@ pub mod pallet {
                 bond: B::default(),
                 delegations: Vec::new(),
                 total: B::default(),
+                commission: Perbill::zero(),
             }
         }
     }
@@ -171,48 +200,11 @@ pub mod pallet {
         pub round_issuance: Balance,
         /// The total inflation paid this round to stakers (e.g. less parachain bond fund)
         pub total_staking_reward: Balance,
-        /// Snapshot of collator commission rate at the end of the round
+        /// Snapshot of the default collator commission rate at the end of the round,
+        /// the rate paid out is snapshotted per collator in `AtStake`
         pub collator_commission: Perbill,
     }
 
-    #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
-    /// DEPRECATED
-    /// Collator state with commission fee, bonded stake, and delegations
-    pub struct Collator2<AccountId, Balance> {
-        /// The account of this collator
-        pub id: AccountId,
-        /// This collator's self stake.
-        pub bond: Balance,
-        /// Set of all nominator AccountIds (to prevent >1 nomination per AccountId)
-        pub nominators: OrderedSet<AccountId>,
-        /// Top T::MaxDelegatorsPerCollator::get() nominators, ordered greatest to least
-        pub top_nominators: Vec<Bond<AccountId, Balance>>,
-        /// Bottom nominators (unbounded), ordered least to greatest
-        pub bottom_nominators: Vec<Bond<AccountId, Balance>>,
-        /// Sum of top delegations + self.bond
-        pub total_counted: Balance,
-        /// Sum of all delegations + self.bond = (total_counted + uncounted)
-        pub total_backing: Balance,
-        /// Current status of the collator
-        pub state: CollatorStatus,
-    }
-
-    impl<A, B> From<Collator2<A, B>> for CollatorCandidate<A, B> {
-        fn from(other: Collator2<A, B>) -> CollatorCandidate<A, B> {
-            CollatorCandidate {
-                id: other.id,
-                bond: other.bond,
-                delegators: other.nominators,
-                top_delegations: other.top_nominators,
-                bottom_delegations: other.bottom_nominators,
-                total_counted: other.total_counted,
-                total_backing: other.total_backing,
-                request: None,
-                state: other.state,
-            }
-        }
-    }
-
     #[derive(PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
     /// Request scheduled to change the collator candidate self-bond
     pub struct CandidateBondLessRequest<Balance> {
@@ -220,28 +212,11 @@ pub mod pallet {
         pub when_executable: RoundIndex,
     }
 
-    #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
-    /// DEPRECATED, replaced by `CandidateMetadata` and two storage instances of `Delegations`
-    /// Collator candidate state with self bond + delegations
-    pub struct CollatorCandidate<AccountId, Balance> {
-        /// The account of this collator
-        pub id: AccountId,
-        /// This collator's self stake.
-        pub bond: Balance,
-        /// Set of all delegator AccountIds (to prevent >1 delegation per AccountId)
-        pub delegators: OrderedSet<AccountId>,
-        /// Top T::MaxDelegatorsPerCollator::get() delegations, ordered greatest to least
-        pub top_delegations: Vec<Bond<AccountId, Balance>>,
-        /// Bottom delegations (unbounded), ordered least to greatest
-        pub bottom_delegations: Vec<Bond<AccountId, Balance>>,
-        /// Sum of top delegations + self.bond
-        pub total_counted: Balance,
-        /// Sum of all delegations + self.bond = (total_counted + uncounted)
-        pub total_backing: Balance,
-        /// Maximum 1 pending request to decrease candidate self bond at any given time
-        pub request: Option<CandidateBondLessRequest<Balance>>,
-        /// Current status of the collator
-        pub state: CollatorStatus,
+    #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    /// Request to change the commission of a candidate
+    pub struct CandidateCommissionRequest {
+        pub commission: Perbill,
+        pub when_executable: RoundIndex,
     }
 
     #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -675,6 +650,10 @@ pub mod pallet {
                         .expect("Delegation existence => DelegatorState existence");
                 let leaving = delegator_state.delegations.0.len() == 1usize;
                 delegator_state.rm_delegation(candidate);
+                <AutoCompoundingDelegations<T>>::remove(
+                    &lowest_bottom_to_be_kicked.owner,
+                    candidate,
+                );
                 Pallet::<T>::deposit_event(Event::DelegationKicked(
                     lowest_bottom_to_be_kicked.owner.clone(),
                     candidate.clone(),
@@ -682,6 +661,7 @@ pub mod pallet {
                 ));
                 if leaving {
                     <DelegatorState<T>>::remove(&lowest_bottom_to_be_kicked.owner);
+                    <RewardDestinations<T>>::remove(&lowest_bottom_to_be_kicked.owner);
                     Pallet::<T>::deposit_event(Event::DelegatorLeft(
                         lowest_bottom_to_be_kicked.owner,
                         lowest_bottom_to_be_kicked.amount,
@@ -1129,63 +1109,6 @@ pub mod pallet {
         }
     }
 
-    // Temporary manual implementation for migration testing purposes
-    impl<A: PartialEq, B: PartialEq> PartialEq for CollatorCandidate<A, B> {
-        fn eq(&self, other: &Self) -> bool {
-            let must_be_true = self.id == other.id
-                && self.bond == other.bond
-                && self.total_counted == other.total_counted
-                && self.total_backing == other.total_backing
-                && self.request == other.request
-                && self.state == other.state;
-            if !must_be_true {
-                return false;
-            }
-            for (x, y) in self.delegators.0.iter().zip(other.delegators.0.iter()) {
-                if x != y {
-                    return false;
-                }
-            }
-            for (
-                Bond {
-                    owner: o1,
-                    amount: a1,
-                },
-                Bond {
-                    owner: o2,
-                    amount: a2,
-                },
-            ) in self
-                .top_delegations
-                .iter()
-                .zip(other.top_delegations.iter())
-            {
-                if o1 != o2 || a1 != a2 {
-                    return false;
-                }
-            }
-            for (
-                Bond {
-                    owner: o1,
-                    amount: a1,
-                },
-                Bond {
-                    owner: o2,
-                    amount: a2,
-                },
-            ) in self
-                .bottom_delegations
-                .iter()
-                .zip(other.bottom_delegations.iter())
-            {
-                if o1 != o2 || a1 != a2 {
-                    return false;
-                }
-            }
-            true
-        }
-    }
-
     /// Convey relevant information describing if a delegator was added to the top or bottom
     /// Delegations added to the top yield a new total
     #[derive(Clone, Copy, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -1194,31 +1117,6 @@ pub mod pallet {
         AddedToBottom,
     }
 
-    impl<
-            A: Ord + Clone + sp_std::fmt::Debug,
-            B: AtLeast32BitUnsigned
-                + Ord
-                + Copy
-                + sp_std::ops::AddAssign
-                + sp_std::ops::SubAssign
-                + sp_std::fmt::Debug,
-        > CollatorCandidate<A, B>
-    {
-        pub fn is_active(&self) -> bool {
-            self.state == CollatorStatus::Active
-        }
-    }
-
-    impl<A: Clone, B: Copy> From<CollatorCandidate<A, B>> for CollatorSnapshot<A, B> {
-        fn from(other: CollatorCandidate<A, B>) -> CollatorSnapshot<A, B> {
-            CollatorSnapshot {
-                bond: other.bond,
-                delegations: other.top_delegations,
-                total: other.total_counted,
-            }
-        }
-    }
-
     #[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
     pub enum DelegatorStatus {
         /// Active with no scheduled exit
@@ -1555,6 +1453,7 @@ pub mod pallet {
                     ));
                     if leaving {
                         <DelegatorState<T>>::remove(&delegator_id);
+                        <RewardDestinations<T>>::remove(&delegator_id);
                         Pallet::<T>::deposit_event(Event::DelegatorLeft(
                             delegator_id,
                             balance_amt,
@@ -1752,38 +1651,6 @@ pub mod pallet {
         }
     }
 
-    #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
-    /// DEPRECATED in favor of Delegator
-    /// Nominator state
-    pub struct Nominator2<AccountId, Balance> {
-        /// All current delegations
-        pub delegations: OrderedSet<Bond<AccountId, Balance>>,
-        /// Delegations scheduled to be revoked
-        pub revocations: OrderedSet<AccountId>,
-        /// Total balance locked for this nominator
-        pub total: Balance,
-        /// Total number of revocations scheduled to be executed
-        pub scheduled_revocations_count: u32,
-        /// Total amount to be unbonded once revocations are executed
-        pub scheduled_revocations_total: Balance,
-        /// Status for this nominator
-        pub status: DelegatorStatus,
-    }
-
-    // /// Temporary function to migrate state
-    // pub(crate) fn migrate_nominator_to_delegator_state<T: Config>(
-    // 	id: T::AccountId,
-    // 	nominator: Nominator2<T::AccountId, BalanceOf<T>>,
-    // ) -> Delegator<T::AccountId, BalanceOf<T>> {
-    // 	Delegator {
-    // 		id,
-    // 		delegations: nominator.delegations,
-    // 		total: nominator.total,
-    // 		requests: PendingDelegationRequests::new(),
-    // 		status: nominator.status,
-    // 	}
-    // }
-
     #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
     /// The current round index and transition information
     pub struct RoundInfo<BlockNumber> {
@@ -1850,7 +1717,93 @@ pub mod pallet {
         }
     }
 
-    pub(crate) type RoundIndex = u32;
+    #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    /// Bounds on the commission candidates are allowed to set
+    pub struct CommissionBounds {
+        pub min: Perbill,
+        pub max: Perbill,
+    }
+
+    impl CommissionBounds {
+        /// Greatest commission payable out of the staking reward left after the parachain
+        /// bond `reserve`, as commissions are a share of the issuance before the reserve
+        pub fn max_for_reserve(reserve: Percent) -> Perbill {
+            Perbill::from_percent(100u32.saturating_sub(reserve.deconstruct().into()))
+        }
+        pub fn contains(&self, commission: Perbill) -> bool {
+            self.min <= commission && commission <= self.max
+        }
+        pub fn clamp(&self, commission: Perbill) -> Perbill {
+            commission.max(self.min).min(self.max)
+        }
+    }
+
+    #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    /// Where the staking rewards of an account are paid
+    pub enum RewardDestination<AccountId> {
+        /// Free balance of the rewarded account
+        Free,
+        /// Free balance of another account
+        Account(AccountId),
+        /// Bonded into the stake the reward is paid for
+        Stake,
+        /// Donated to the treasury
+        Treasury,
+    }
+
+    impl<A> Default for RewardDestination<A> {
+        fn default() -> RewardDestination<A> {
+            RewardDestination::Free
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    /// Estimated annual return of staking with a candidate, may exceed 100%
+    pub struct StakingApr {
+        /// Annual return on the self-bond of the candidate, commission included
+        pub collator: FixedU128,
+        /// Annual return on a delegation to the candidate
+        pub delegator: FixedU128,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+    /// Blocks authored by a selected collator in a round against its expected number of slots
+    pub struct AuthoringStats {
+        pub authored: u32,
+        pub expected: u32,
+    }
+
+    #[derive(Clone, PartialEq, Eq, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+    /// Liveness of a collator over the last rounds
+    pub struct CollatorLiveness {
+        /// Consecutive rounds in which the collator missed too many of its slots
+        pub missed_rounds: u32,
+        /// Authoring stats of the last rounds the collator was selected for
+        pub rounds: Vec<(RoundIndex, AuthoringStats)>,
+    }
+
+    #[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    /// Delegation with its auto-compounding rate and pending request
+    pub struct DelegationOverview<AccountId, Balance> {
+        pub candidate: AccountId,
+        pub amount: Balance,
+        pub auto_compound: Percent,
+        pub request: Option<DelegationRequest<AccountId, Balance>>,
+    }
+
+    #[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+    /// Overview of a delegator and all of its delegations
+    pub struct DelegatorOverview<AccountId, Balance> {
+        /// Total balance locked for this delegator
+        pub total: Balance,
+        /// Sum of pending revocation amounts + bond less amounts
+        pub less_total: Balance,
+        pub status: DelegatorStatus,
+        pub reward_destination: RewardDestination<AccountId>,
+        pub delegations: Vec<DelegationOverview<AccountId, Balance>>,
+    }
+
+    pub type RoundIndex = u32;
     type RewardPoint = u32;
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -1870,12 +1823,20 @@ pub mod pallet {
         /// Default number of blocks per round at genesis
         #[pallet::constant]
         type DefaultBlocksPerRound: Get<u32>;
+        /// Expected block time in milliseconds, used until the block time is observed over a round
+        #[pallet::constant]
+        type DefaultBlockTime: Get<u64>;
+        /// Source of block timestamps to observe the block time over each round
+        type UnixTime: UnixTime;
         /// Number of rounds that candidates remain bonded before exit request is executable
         #[pallet::constant]
         type LeaveCandidatesDelay: Get<RoundIndex>;
         /// Number of rounds candidate requests to decrease self-bond must wait to be executable
         #[pallet::constant]
         type CandidateBondLessDelay: Get<RoundIndex>;
+        /// Number of rounds candidate commission changes must wait before they are applied
+        #[pallet::constant]
+        type CandidateCommissionDelay: Get<RoundIndex>;
         /// Number of rounds that delegators remain bonded before exit request is executable
         #[pallet::constant]
         type LeaveDelegatorsDelay: Get<RoundIndex>;
@@ -1888,6 +1849,23 @@ pub mod pallet {
         /// Number of rounds after which block authors are rewarded
         #[pallet::constant]
         type RewardPaymentDelay: Get<RoundIndex>;
+        /// Share of its expected slots a selected collator may miss in a round without the round
+        /// counting as missed
+        #[pallet::constant]
+        type MissedSlotsThreshold: Get<Percent>;
+        /// Number of consecutive missed rounds after which a candidate is taken offline,
+        /// zero disables it
+        #[pallet::constant]
+        type MaxMissedRounds: Get<u32>;
+        /// Share of its self bond a candidate is slashed when taken offline for missed rounds
+        #[pallet::constant]
+        type DowntimeSlashFraction: Get<Perbill>;
+        /// Share of each top delegation slashed along with the candidate, zero spares delegators
+        #[pallet::constant]
+        type DelegatorDowntimeSlashFraction: Get<Perbill>;
+        /// Number of rounds a slash is deferred for, during which it can be cancelled
+        #[pallet::constant]
+        type SlashDeferDuration: Get<RoundIndex>;
         /// Minimum number of selected candidates every round
         #[pallet::constant]
         type MinSelectedCandidates: Get<u32>;
@@ -1918,6 +1896,8 @@ pub mod pallet {
         /// Minimum stake for any registered on-chain account to be a delegator
         #[pallet::constant]
         type MinDelegatorStk: Get<BalanceOf<Self>>;
+        /// Account receiving the rewards donated to the treasury
+        type TreasuryAccount: Get<Self::AccountId>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -1965,6 +1945,11 @@ pub mod pallet {
         PendingDelegationRequestAlreadyExists,
         PendingDelegationRequestNotDueYet,
         CannotDelegateLessThanLowestBottomWhenBottomIsFull,
+        InvalidCommissionBounds,
+        CommissionOutOfBounds,
+        NotCandidateNorDelegator,
+        DeferredSlashDNE,
+        DeferredSlashPending,
     }
 
     #[pallet::event]
@@ -1984,6 +1969,14 @@ pub mod pallet {
         CandidateBondedLess(T::AccountId, BalanceOf<T>, BalanceOf<T>),
         /// Candidate
         CandidateWentOffline(T::AccountId),
+        /// Candidate, Consecutive Missed Rounds
+        CandidateWentOfflineForMissedSlots(T::AccountId, u32),
+        /// Candidate, Round at which the slash is applied, Total Amount To Slash
+        SlashDeferred(T::AccountId, RoundIndex, BalanceOf<T>),
+        /// Round at which the slash would have been applied, Candidate
+        DeferredSlashCancelled(RoundIndex, T::AccountId),
+        /// Slashed Account, Candidate, Amount moved to the treasury
+        Slashed(T::AccountId, T::AccountId, BalanceOf<T>),
         /// Candidate
         CandidateBackOnline(T::AccountId),
         /// Round At Which Exit Is Allowed, Candidate, Scheduled Exit
@@ -2025,6 +2018,14 @@ pub mod pallet {
         DelegatorLeftCandidate(T::AccountId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
         /// Paid the account (delegator or collator) the balance as liquid rewards
         Rewarded(T::AccountId, BalanceOf<T>),
+        /// Delegator, Candidate, Percent of rewards auto-compounded into the delegation
+        AutoCompoundSet(T::AccountId, T::AccountId, Percent),
+        /// Delegator, Candidate, Amount of rewards bonded back into the delegation
+        AutoCompounded(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// Account, Destination of its rewards
+        RewardDestinationSet(T::AccountId, RewardDestination<T::AccountId>),
+        /// Rewarded Account, Destination, Amount paid to the destination
+        RewardedToDestination(T::AccountId, RewardDestination<T::AccountId>, BalanceOf<T>),
         /// Transferred to account which holds funds reserved for parachain bond
         ReservedForParachainBond(T::AccountId, BalanceOf<T>),
         /// Account (re)set for parachain bond treasury [old, new]
@@ -2033,12 +2034,23 @@ pub mod pallet {
         ParachainBondReservePercentSet(Percent, Percent),
         /// Annual inflation input (first 3) was used to derive new per-round inflation (last 3)
         InflationSet(Perbill, Perbill, Perbill, Perbill, Perbill, Perbill),
+        /// Inflation decay schedule (re)set, none if removed
+        InflationDecaySet(Option<InflationDecay>),
+        /// Annual inflation decayed by the schedule (first 3) was used to derive new per-round
+        /// inflation (last 3)
+        InflationDecayed(Perbill, Perbill, Perbill, Perbill, Perbill, Perbill),
         /// Staking expectations set
         StakeExpectationsSet(BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
         /// Set total selected candidates to this value [old, new]
         TotalSelectedSet(u32, u32),
         /// Set collator commission to this value [old, new]
         CollatorCommissionSet(Perbill, Perbill),
+        /// Set bounds on candidate commissions [old, new]
+        CommissionBoundsSet(CommissionBounds, CommissionBounds),
+        /// Candidate, New Commission, Round at which the change is applied
+        CandidateCommissionChangeScheduled(T::AccountId, Perbill, RoundIndex),
+        /// Candidate, Old Commission, New Commission
+        CandidateCommissionSet(T::AccountId, Perbill, Perbill),
         /// Set blocks per round [current_round, first_block, old, new, new_per_round_inflation]
         BlocksPerRoundSet(
             RoundIndex,
@@ -2049,6 +2061,8 @@ pub mod pallet {
             Perbill,
             Perbill,
         ),
+        /// Block time observed over the last round moved [old, new, new_per_round_inflation]
+        BlockTimeSet(u64, u64, Perbill, Perbill, Perbill),
     }
 
     #[pallet::hooks]
@@ -2058,10 +2072,21 @@ pub mod pallet {
 
             let mut round = <Round<T>>::get();
             if round.should_update(n) {
+                let blocks: u32 = (n - round.first).saturated_into();
+                // observe block time over the round which ends before starting the next one
+                weight += Self::update_block_time(blocks);
+                // take offline non-producing collators before selecting the next ones
+                weight += Self::track_liveness(round.current, blocks);
                 // mutate round
                 round.update(n);
                 // pay all stakers for T::RewardPaymentDelay rounds ago
                 Self::prepare_staking_payouts(round.current);
+                // decay annual inflation at the end of the year
+                weight += Self::apply_inflation_decay(round.current);
+                // apply due commission changes before they are snapshotted
+                weight += Self::apply_candidate_commission_changes(round.current);
+                // apply due slashes before the candidate pool is used for selection
+                weight += Self::apply_slashes(round.current);
                 // select top collator candidates for next round
                 let (collator_count, delegation_count, total_staked) =
                     Self::select_top_candidates(round.current);
@@ -2092,6 +2117,39 @@ pub mod pallet {
     /// Commission percent taken off of rewards for all collators
     type CollatorCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
+    #[pallet::type_value]
+    /// Commissions up to what the default parachain bond reserve leaves
+    pub(crate) fn DefaultCommissionBounds<T: Config>() -> CommissionBounds {
+        CommissionBounds {
+            min: Perbill::zero(),
+            max: CommissionBounds::max_for_reserve(T::DefaultParachainBondReservePercent::get()),
+        }
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_commission_bounds)]
+    /// Bounds on the commission candidates are allowed to set
+    pub(crate) type CandidateCommissionBounds<T: Config> =
+        StorageValue<_, CommissionBounds, ValueQuery, DefaultCommissionBounds<T>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_commission)]
+    /// Commission set by the candidate, `CollatorCommission` is used if none
+    pub(crate) type CandidateCommission<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, Perbill, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_commission_request)]
+    /// Scheduled commission change of the candidate
+    pub(crate) type CandidateCommissionRequests<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, CandidateCommissionRequest, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_commission_changes)]
+    /// Candidates with a commission change executable at the start of the round
+    pub(crate) type CandidateCommissionChanges<T: Config> =
+        StorageMap<_, Twox64Concat, RoundIndex, Vec<T::AccountId>, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn total_selected)]
     /// The total candidates selected every round
@@ -2108,18 +2166,6 @@ pub mod pallet {
     /// Current round index and next round scheduled transition
     pub(crate) type Round<T: Config> = StorageValue<_, RoundInfo<T::BlockNumber>, ValueQuery>;
 
-    #[pallet::storage]
-    #[pallet::getter(fn nominator_state2)]
-    /// DEPRECATED in favor of DelegatorState
-    /// Get nominator state associated with an account if account is nominating else None
-    pub(crate) type NominatorState2<T: Config> = StorageMap<
-        _,
-        Twox64Concat,
-        T::AccountId,
-        Nominator2<T::AccountId, BalanceOf<T>>,
-        OptionQuery,
-    >;
-
     #[pallet::storage]
     #[pallet::getter(fn delegator_state)]
     /// Get delegator state associated with an account if account is delegating else None
@@ -2132,17 +2178,24 @@ pub mod pallet {
     >;
 
     #[pallet::storage]
-    #[pallet::getter(fn candidate_state)]
-    /// DEPRECATED
-    /// Get collator candidate state associated with an account if account is a candidate else None
-    pub(crate) type CandidateState<T: Config> = StorageMap<
+    #[pallet::getter(fn auto_compound)]
+    /// Percent of the rewards of a delegation (delegator, candidate) bonded back into it
+    pub(crate) type AutoCompoundingDelegations<T: Config> = StorageDoubleMap<
         _,
         Twox64Concat,
         T::AccountId,
-        CollatorCandidate<T::AccountId, BalanceOf<T>>,
-        OptionQuery,
+        Twox64Concat,
+        T::AccountId,
+        Percent,
+        ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn reward_destination)]
+    /// Where the staking rewards of a candidate or delegator are paid
+    pub(crate) type RewardDestinations<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, RewardDestination<T::AccountId>, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn candidate_info)]
     /// Get collator candidate info associated with an account if account is candidate else None
@@ -2171,18 +2224,6 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    #[pallet::storage]
-    #[pallet::getter(fn collator_state2)]
-    /// DEPRECATED in favor of CandidateState
-    /// Get collator state associated with an account if account is collating else None
-    pub(crate) type CollatorState2<T: Config> = StorageMap<
-        _,
-        Twox64Concat,
-        T::AccountId,
-        Collator2<T::AccountId, BalanceOf<T>>,
-        OptionQuery,
-    >;
-
     #[pallet::storage]
     #[pallet::getter(fn selected_candidates)]
     /// The collator candidates selected for the current round
@@ -2224,6 +2265,20 @@ pub mod pallet {
     pub type Staked<T: Config> =
         StorageMap<_, Twox64Concat, RoundIndex, BalanceOf<T>, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn inflation_decay)]
+    /// Schedule decaying the annual inflation every year, if any
+    pub type InflationDecaySchedule<T: Config> = StorageValue<_, InflationDecay, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn block_time)]
+    /// Block time in milliseconds used to derive per-round inflation
+    pub type BlockTime<T: Config> = StorageValue<_, u64, ValueQuery, T::DefaultBlockTime>;
+
+    #[pallet::storage]
+    /// Timestamp of the start of the current round in milliseconds, zero if unknown
+    pub(crate) type RoundStartTime<T: Config> = StorageValue<_, u64, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn inflation_config)]
     /// Inflation configuration
@@ -2248,6 +2303,42 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn authoring_stats)]
+    /// Authoring stats of the selected collators per round, kept for `RewardPaymentDelay` rounds.
+    /// Authored blocks are counted as they are noted, expected ones at the end of the round.
+    pub type RoundAuthoringStats<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        RoundIndex,
+        Twox64Concat,
+        T::AccountId,
+        AuthoringStats,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn missed_rounds)]
+    /// Consecutive rounds in which the candidate missed too many of its slots
+    pub type MissedRounds<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn unapplied_slashes)]
+    /// Slashes deferred until the round they are applied in
+    pub type UnappliedSlashes<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        RoundIndex,
+        Vec<UnappliedSlash<T::AccountId, BalanceOf<T>>>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_slashes)]
+    /// Number of deferred slashes of the account not applied yet, it can't unbond meanwhile
+    pub type PendingSlashes<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub candidates: Vec<(T::AccountId, BalanceOf<T>)>,
@@ -2360,56 +2451,6 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(
-			<T as Config>::WeightInfo::hotfix_remove_delegation_requests(delegators.len() as u32)
-		)]
-        /// Hotfix patch to remove all delegation requests not removed during a candidate exit
-        pub fn hotfix_remove_delegation_requests(
-            origin: OriginFor<T>,
-            delegators: Vec<T::AccountId>,
-        ) -> DispatchResultWithPostInfo {
-            frame_system::ensure_root(origin)?;
-            for delegator in delegators {
-                if let Some(mut state) = <DelegatorState<T>>::get(&delegator) {
-                    // go through all requests and remove ones without corresponding delegation
-                    for (candidate, request) in state.requests.requests.clone().into_iter() {
-                        if state
-                            .delegations
-                            .0
-                            .iter()
-                            .find(|x| x.owner == candidate)
-                            .is_none()
-                        {
-                            state.requests.requests.remove(&candidate);
-                            state.requests.less_total =
-                                state.requests.less_total.saturating_sub(request.amount);
-                            if matches!(request.action, DelegationChange::Revoke) {
-                                state.requests.revocations_count -= 1u32;
-                            }
-                        }
-                    }
-                    <DelegatorState<T>>::insert(&delegator, state);
-                } // else delegator is not a delegator so no update needed
-            }
-            Ok(().into())
-        }
-        #[pallet::weight(
-			<T as Config>::WeightInfo::hotfix_update_candidate_pool_value(candidates.len() as u32)
-		)]
-        /// Hotfix patch to correct and update CandidatePool value for candidates that have
-        /// called candidate_bond_more when it did not update the CandidatePool value
-        pub fn hotfix_update_candidate_pool_value(
-            origin: OriginFor<T>,
-            candidates: Vec<T::AccountId>,
-        ) -> DispatchResultWithPostInfo {
-            frame_system::ensure_root(origin)?;
-            for candidate in candidates {
-                if let Some(state) = <CandidateInfo<T>>::get(&candidate) {
-                    Self::update_active(candidate, state.total_counted);
-                } // else candidate is not a candidate so no update needed
-            }
-            Ok(().into())
-        }
         #[pallet::weight(<T as Config>::WeightInfo::set_staking_expectations())]
         /// Set the expectations for total staked. These expectations determine the issuance for
         /// the round according to logic in `fn compute_issuance`
@@ -2456,6 +2497,37 @@ pub mod pallet {
             <InflationConfig<T>>::put(config);
             Ok(().into())
         }
+        #[pallet::weight(<T as Config>::WeightInfo::set_inflation_decay())]
+        /// Set the relative decrease of the annual inflation applied every year, annual rates
+        /// don't decay below `floor`. Zero `rate` removes the schedule.
+        /// - the first decay is applied a year after the schedule is first set
+        /// - `set_inflation` still overrides the annual inflation, decay continues from there
+        pub fn set_inflation_decay(
+            origin: OriginFor<T>,
+            rate: Perbill,
+            floor: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            let old = <InflationDecaySchedule<T>>::get();
+            let new = if rate.is_zero() {
+                None
+            } else {
+                let next_round = old.map(|decay| decay.next_round).unwrap_or_else(|| {
+                    <Round<T>>::get()
+                        .current
+                        .saturating_add(crate::inflation::rounds_per_year::<T>())
+                });
+                Some(InflationDecay {
+                    rate,
+                    floor,
+                    next_round,
+                })
+            };
+            ensure!(old != new, Error::<T>::NoWritingSameValue);
+            <InflationDecaySchedule<T>>::set(new);
+            Self::deposit_event(Event::InflationDecaySet(new));
+            Ok(().into())
+        }
         #[pallet::weight(<T as Config>::WeightInfo::set_parachain_bond_account())]
         /// Set the account that will hold funds set aside for parachain bond
         pub fn set_parachain_bond_account(
@@ -2477,6 +2549,7 @@ pub mod pallet {
         }
         #[pallet::weight(<T as Config>::WeightInfo::set_parachain_bond_reserve_percent())]
         /// Set the percent of inflation set aside for parachain bond
+        /// - the maximum candidate commission must fit in what is left of the inflation
         pub fn set_parachain_bond_reserve_percent(
             origin: OriginFor<T>,
             new: Percent,
@@ -2487,6 +2560,11 @@ pub mod pallet {
                 percent: old,
             } = <ParachainBondInfo<T>>::get();
             ensure!(old != new, Error::<T>::NoWritingSameValue);
+            ensure!(
+                <CandidateCommissionBounds<T>>::get().max
+                    <= CommissionBounds::max_for_reserve(new),
+                Error::<T>::InvalidCommissionBounds
+            );
             <ParachainBondInfo<T>>::put(ParachainBondConfig {
                 account,
                 percent: new,
@@ -2526,6 +2604,27 @@ pub mod pallet {
             Self::deposit_event(Event::CollatorCommissionSet(old, new));
             Ok(().into())
         }
+        #[pallet::weight(<T as Config>::WeightInfo::set_commission_bounds())]
+        /// Set the bounds on the commission candidates are allowed to set.
+        /// Commissions outside of the new bounds are clamped when snapshotted.
+        /// - the maximum must fit in the inflation left after the parachain bond reserve
+        pub fn set_commission_bounds(
+            origin: OriginFor<T>,
+            new: CommissionBounds,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            ensure!(new.min <= new.max, Error::<T>::InvalidCommissionBounds);
+            ensure!(
+                new.max
+                    <= CommissionBounds::max_for_reserve(<ParachainBondInfo<T>>::get().percent),
+                Error::<T>::InvalidCommissionBounds
+            );
+            let old = <CandidateCommissionBounds<T>>::get();
+            ensure!(old != new, Error::<T>::NoWritingSameValue);
+            <CandidateCommissionBounds<T>>::put(new);
+            Self::deposit_event(Event::CommissionBoundsSet(old, new));
+            Ok(().into())
+        }
         #[pallet::weight(<T as Config>::WeightInfo::set_blocks_per_round())]
         /// Set blocks per round
         /// - if called with `new` less than length of current round, will transition immediately
@@ -2550,7 +2649,7 @@ pub mod pallet {
             round.length = new;
             // update per-round inflation given new rounds per year
             let mut inflation_config = <InflationConfig<T>>::get();
-            inflation_config.reset_round(new);
+            inflation_config.reset_round::<T>(new);
             <Round<T>>::put(round);
             Self::deposit_event(Event::BlocksPerRoundSet(
                 now,
@@ -2631,6 +2730,7 @@ pub mod pallet {
 			<T as Config>::WeightInfo::execute_leave_candidates(*candidate_delegation_count)
 		)]
         /// Execute leave candidates request
+        /// - fails while a deferred slash of the candidate is pending
         pub fn execute_leave_candidates(
             origin: OriginFor<T>,
             candidate: T::AccountId,
@@ -2638,6 +2738,7 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             ensure_signed(origin)?;
             let state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+            Self::ensure_no_pending_slash(&candidate)?;
             ensure!(
                 state.delegation_count <= candidate_delegation_count,
                 Error::<T>::TooLowCandidateDelegationCountToLeaveCandidates
@@ -2645,6 +2746,7 @@ pub mod pallet {
             state.can_leave::<T>()?;
             let return_stake = |bond: Bond<T::AccountId, BalanceOf<T>>| {
                 T::Currency::unreserve(&bond.owner, bond.amount);
+                <AutoCompoundingDelegations<T>>::remove(&bond.owner, &candidate);
                 // remove delegation from delegator state
                 let mut delegator = DelegatorState::<T>::get(&bond.owner).expect(
                     "Collator state and delegator state are consistent. 
@@ -2654,6 +2756,7 @@ pub mod pallet {
                 if let Some(remaining) = delegator.rm_delegation(&candidate) {
                     if remaining.is_zero() {
                         <DelegatorState<T>>::remove(&bond.owner);
+                        <RewardDestinations<T>>::remove(&bond.owner);
                     } else {
                         if let Some(request) = delegator.requests.requests.remove(&candidate) {
                             delegator.requests.less_total =
@@ -2685,8 +2788,12 @@ pub mod pallet {
             // return stake to collator
             T::Currency::unreserve(&candidate, state.bond);
             <CandidateInfo<T>>::remove(&candidate);
+            <RewardDestinations<T>>::remove(&candidate);
             <TopDelegations<T>>::remove(&candidate);
             <BottomDelegations<T>>::remove(&candidate);
+            <CandidateCommission<T>>::remove(&candidate);
+            <CandidateCommissionRequests<T>>::remove(&candidate);
+            <MissedRounds<T>>::remove(&candidate);
             let new_total_staked = <Total<T>>::get().saturating_sub(total_backing);
             <Total<T>>::put(new_total_staked);
             Self::deposit_event(Event::CandidateLeft(
@@ -2707,6 +2814,10 @@ pub mod pallet {
             let collator = ensure_signed(origin)?;
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             ensure!(state.is_leaving(), Error::<T>::CandidateNotLeaving);
+            ensure!(
+                state.bond >= T::MinCandidateStk::get(),
+                Error::<T>::CandidateBondBelowMin
+            );
             state.go_online();
             let mut candidates = <CandidatePool<T>>::get();
             ensure!(
@@ -2729,14 +2840,9 @@ pub mod pallet {
         /// Temporarily leave the set of collator candidates without unbonding
         pub fn go_offline(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let collator = ensure_signed(origin)?;
-            let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
+            let state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             ensure!(state.is_active(), Error::<T>::AlreadyOffline);
-            state.go_offline();
-            let mut candidates = <CandidatePool<T>>::get();
-            if candidates.remove(&Bond::from_owner(collator.clone())) {
-                <CandidatePool<T>>::put(candidates);
-            }
-            <CandidateInfo<T>>::insert(&collator, state);
+            Self::candidate_go_offline(&collator, state);
             Self::deposit_event(Event::CandidateWentOffline(collator));
             Ok(().into())
         }
@@ -2747,6 +2853,11 @@ pub mod pallet {
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             ensure!(!state.is_active(), Error::<T>::AlreadyActive);
             ensure!(!state.is_leaving(), Error::<T>::CannotGoOnlineIfLeaving);
+            // the self bond may have been slashed below the minimum
+            ensure!(
+                state.bond >= T::MinCandidateStk::get(),
+                Error::<T>::CandidateBondBelowMin
+            );
             state.go_online();
             let mut candidates = <CandidatePool<T>>::get();
             ensure!(
@@ -2792,6 +2903,7 @@ pub mod pallet {
         }
         #[pallet::weight(<T as Config>::WeightInfo::execute_candidate_bond_less())]
         /// Execute pending request to adjust the collator candidate self bond
+        /// - fails while a deferred slash of the candidate is pending
         pub fn execute_candidate_bond_less(
             origin: OriginFor<T>,
             candidate: T::AccountId,
@@ -2799,10 +2911,48 @@ pub mod pallet {
             ensure_signed(origin)?; // we may want to reward this if caller != candidate
             let mut state =
                 <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+            Self::ensure_no_pending_slash(&candidate)?;
             state.execute_bond_less::<T>(candidate.clone())?;
             <CandidateInfo<T>>::insert(&candidate, state);
             Ok(().into())
         }
+        #[pallet::weight(<T as Config>::WeightInfo::schedule_candidate_commission_change())]
+        /// Request to change the commission of the candidate. The change is applied at the start
+        /// of the round `T::CandidateCommissionDelay` rounds from now and replaces any pending
+        /// request.
+        pub fn schedule_candidate_commission_change(
+            origin: OriginFor<T>,
+            commission: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            let candidate = ensure_signed(origin)?;
+            ensure!(
+                <CandidateInfo<T>>::contains_key(&candidate),
+                Error::<T>::CandidateDNE
+            );
+            ensure!(
+                <CandidateCommissionBounds<T>>::get().contains(commission),
+                Error::<T>::CommissionOutOfBounds
+            );
+            let when_executable = <Round<T>>::get().current + T::CandidateCommissionDelay::get();
+            let scheduled = <CandidateCommissionRequests<T>>::get(&candidate)
+                .map_or(false, |request| request.when_executable == when_executable);
+            if !scheduled {
+                <CandidateCommissionChanges<T>>::append(when_executable, &candidate);
+            }
+            <CandidateCommissionRequests<T>>::insert(
+                &candidate,
+                CandidateCommissionRequest {
+                    commission,
+                    when_executable,
+                },
+            );
+            Self::deposit_event(Event::CandidateCommissionChangeScheduled(
+                candidate,
+                commission,
+                when_executable,
+            ));
+            Ok(().into())
+        }
         #[pallet::weight(<T as Config>::WeightInfo::cancel_candidate_bond_less())]
         /// Cancel pending request to adjust the collator candidate self bond
         pub fn cancel_candidate_bond_less(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
@@ -2920,6 +3070,7 @@ pub mod pallet {
         }
         #[pallet::weight(<T as Config>::WeightInfo::execute_leave_delegators(*delegation_count))]
         /// Execute the right to exit the set of delegators and revoke all ongoing delegations.
+        /// - fails while a deferred slash of the delegator is pending
         pub fn execute_leave_delegators(
             origin: OriginFor<T>,
             delegator: T::AccountId,
@@ -2927,6 +3078,7 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             ensure_signed(origin)?;
             let state = <DelegatorState<T>>::get(&delegator).ok_or(Error::<T>::DelegatorDNE)?;
+            Self::ensure_no_pending_slash(&delegator)?;
             state.can_execute_leave::<T>(delegation_count)?;
             for bond in state.delegations.0 {
                 if let Err(error) = Self::delegator_leaves_candidate(
@@ -2941,6 +3093,7 @@ pub mod pallet {
                 }
             }
             <DelegatorState<T>>::remove(&delegator);
+            <RewardDestinations<T>>::remove(&delegator);
             Self::deposit_event(Event::DelegatorLeft(delegator, state.total));
             Ok(().into())
         }
@@ -3008,6 +3161,7 @@ pub mod pallet {
         }
         #[pallet::weight(<T as Config>::WeightInfo::execute_delegator_bond_less())]
         /// Execute pending request to change an existing delegation
+        /// - fails while a deferred slash of the delegator is pending
         pub fn execute_delegation_request(
             origin: OriginFor<T>,
             delegator: T::AccountId,
@@ -3016,6 +3170,7 @@ pub mod pallet {
             ensure_signed(origin)?; // we may want to reward caller if caller != delegator
             let mut state =
                 <DelegatorState<T>>::get(&delegator).ok_or(Error::<T>::DelegatorDNE)?;
+            Self::ensure_no_pending_slash(&delegator)?;
             state.execute_pending_request::<T>(candidate)?;
             Ok(().into())
         }
@@ -3033,6 +3188,77 @@ pub mod pallet {
             Self::deposit_event(Event::CancelledDelegationRequest(delegator, request));
             Ok(().into())
         }
+        #[pallet::weight(<T as Config>::WeightInfo::set_auto_compound())]
+        /// Set the percent of rewards from a delegation that is bonded back into the same
+        /// delegation on payout. Setting zero percent disables auto-compounding.
+        pub fn set_auto_compound(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            value: Percent,
+        ) -> DispatchResultWithPostInfo {
+            let delegator = ensure_signed(origin)?;
+            let state = <DelegatorState<T>>::get(&delegator).ok_or(Error::<T>::DelegatorDNE)?;
+            ensure!(
+                state.delegations.0.iter().any(|d| d.owner == candidate),
+                Error::<T>::DelegationDNE
+            );
+            ensure!(
+                <AutoCompoundingDelegations<T>>::get(&delegator, &candidate) != value,
+                Error::<T>::NoWritingSameValue
+            );
+            if value.is_zero() {
+                <AutoCompoundingDelegations<T>>::remove(&delegator, &candidate);
+            } else {
+                <AutoCompoundingDelegations<T>>::insert(&delegator, &candidate, value);
+            }
+            Self::deposit_event(Event::AutoCompoundSet(delegator, candidate, value));
+            Ok(().into())
+        }
+        #[pallet::weight(<T as Config>::WeightInfo::set_reward_destination())]
+        /// Set where the staking rewards of the caller, a candidate or delegator, are paid.
+        pub fn set_reward_destination(
+            origin: OriginFor<T>,
+            destination: RewardDestination<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::is_candidate(&who) || Self::is_delegator(&who),
+                Error::<T>::NotCandidateNorDelegator
+            );
+            ensure!(
+                <RewardDestinations<T>>::get(&who) != destination,
+                Error::<T>::NoWritingSameValue
+            );
+            if destination == RewardDestination::Free {
+                <RewardDestinations<T>>::remove(&who);
+            } else {
+                <RewardDestinations<T>>::insert(&who, destination.clone());
+            }
+            Self::deposit_event(Event::RewardDestinationSet(who, destination));
+            Ok(().into())
+        }
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_deferred_slash())]
+        /// Cancel the deferred slash of `collator` due in `round`
+        pub fn cancel_deferred_slash(
+            origin: OriginFor<T>,
+            round: RoundIndex,
+            collator: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            frame_system::ensure_root(origin)?;
+            let mut slashes = <UnappliedSlashes<T>>::get(round);
+            let index = slashes
+                .iter()
+                .position(|slash| slash.collator == collator)
+                .ok_or(Error::<T>::DeferredSlashDNE)?;
+            Self::remove_pending_slash(&slashes.remove(index));
+            if slashes.is_empty() {
+                <UnappliedSlashes<T>>::remove(round);
+            } else {
+                <UnappliedSlashes<T>>::insert(round, slashes);
+            }
+            Self::deposit_event(Event::DeferredSlashCancelled(round, collator));
+            Ok(().into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -3068,6 +3294,148 @@ pub mod pallet {
                 round_issuance.ideal
             }
         }
+        /// Part of `round_issuance` paid to stakers, less the parachain bond reserve which is
+        /// only taken if the parachain bond account exists
+        fn estimate_staking_reward(round_issuance: BalanceOf<T>) -> BalanceOf<T> {
+            let bond_config = <ParachainBondInfo<T>>::get();
+            if T::Currency::total_balance(&bond_config.account).is_zero() {
+                return round_issuance;
+            }
+            round_issuance.saturating_sub(bond_config.percent * round_issuance)
+        }
+        /// Estimate the payout info of a round which ended but isn't prepared for payout yet,
+        /// as `prepare_staking_payouts` would compute it now
+        fn estimate_payout(round: RoundIndex) -> Option<DelayedPayout<BalanceOf<T>>> {
+            let now = <Round<T>>::get().current;
+            if round >= now || round.saturating_add(T::RewardPaymentDelay::get()) <= now {
+                return None;
+            }
+            let round_issuance = Self::compute_issuance(<Staked<T>>::get(round));
+            Some(DelayedPayout {
+                round_issuance,
+                total_staking_reward: Self::estimate_staking_reward(round_issuance),
+                collator_commission: <CollatorCommission<T>>::get(),
+            })
+        }
+        /// Reward `who` is expected to receive for `round` once it is paid out, computed as in
+        /// `pay_one_collator_reward`. Zero if nothing is due, or the round was already paid.
+        pub fn expected_reward(who: &T::AccountId, round: RoundIndex) -> BalanceOf<T> {
+            let payout_info =
+                match <DelayedPayouts<T>>::get(round).or_else(|| Self::estimate_payout(round)) {
+                    Some(payout_info) => payout_info,
+                    None => return Zero::zero(),
+                };
+            let total_points = <Points<T>>::get(round);
+            if total_points.is_zero() {
+                return Zero::zero();
+            }
+            let mut reward = BalanceOf::<T>::zero();
+            for (collator, pts) in <AwardedPts<T>>::iter_prefix(round) {
+                let state = <AtStake<T>>::get(round, &collator);
+                let pct_due = Perbill::from_rational(pts, total_points);
+                let mut amt_due = pct_due * payout_info.total_staking_reward;
+                if state.delegations.is_empty() {
+                    if collator == *who {
+                        reward += amt_due;
+                    }
+                    continue;
+                }
+                let commission =
+                    (pct_due * (state.commission * payout_info.round_issuance)).min(amt_due);
+                amt_due = amt_due.saturating_sub(commission);
+                if collator == *who {
+                    reward +=
+                        Perbill::from_rational(state.bond, state.total) * amt_due + commission;
+                }
+                for Bond { owner, amount } in state.delegations {
+                    if owner == *who {
+                        reward += Perbill::from_rational(amount, state.total) * amt_due;
+                    }
+                }
+            }
+            reward
+        }
+        /// Rewards of `who` for the rounds which ended and are still waiting for payout
+        pub fn pending_rewards(who: &T::AccountId) -> Vec<(RoundIndex, BalanceOf<T>)> {
+            let now = <Round<T>>::get().current;
+            let first = now.saturating_sub(T::RewardPaymentDelay::get()).max(1);
+            (first..now)
+                .map(|round| (round, Self::expected_reward(who, round)))
+                .filter(|(_, reward)| !reward.is_zero())
+                .collect()
+        }
+        /// Estimate the annual return of staking with `candidate` from the current inflation
+        /// config, commission and total stake, assuming all selected collators author the same
+        /// number of blocks
+        pub fn candidate_apr(candidate: &T::AccountId) -> Option<StakingApr> {
+            let state = <CandidateInfo<T>>::get(candidate)?;
+            let selected =
+                (<SelectedCandidates<T>>::decode_len().unwrap_or_default() as u32).max(1u32);
+            let round_issuance =
+                Self::compute_issuance(<Staked<T>>::get(<Round<T>>::get().current));
+            let pct_due = Perbill::from_rational(1u32, selected);
+            let collator_due = pct_due * Self::estimate_staking_reward(round_issuance);
+            let commission = (pct_due
+                * (<CandidateCommissionBounds<T>>::get().clamp(
+                    <CandidateCommission<T>>::get(candidate)
+                        .unwrap_or_else(<CollatorCommission<T>>::get),
+                ) * round_issuance))
+                .min(collator_due);
+            let amt_due = collator_due.saturating_sub(commission);
+            let collator_reward =
+                Perbill::from_rational(state.bond, state.total_counted) * amt_due + commission;
+            let rounds_per_year: BalanceOf<T> = crate::inflation::rounds_per_year::<T>().into();
+            let ratio = |n: BalanceOf<T>, d: BalanceOf<T>| {
+                if d.is_zero() {
+                    return FixedU128::zero();
+                }
+                FixedU128::saturating_from_rational(
+                    n.saturated_into::<u128>(),
+                    d.saturated_into::<u128>(),
+                )
+            };
+            Some(StakingApr {
+                collator: ratio(collator_reward.saturating_mul(rounds_per_year), state.bond),
+                delegator: ratio(amt_due.saturating_mul(rounds_per_year), state.total_counted),
+            })
+        }
+        /// Authoring stats of `collator` for the last rounds and its consecutive missed rounds
+        pub fn collator_liveness(collator: &T::AccountId) -> CollatorLiveness {
+            let now = <Round<T>>::get().current;
+            let first = now.saturating_sub(T::RewardPaymentDelay::get()).max(1);
+            CollatorLiveness {
+                missed_rounds: <MissedRounds<T>>::get(collator),
+                rounds: (first..now)
+                    .filter_map(|round| {
+                        <RoundAuthoringStats<T>>::get(round, collator).map(|stats| (round, stats))
+                    })
+                    .collect(),
+            }
+        }
+        /// Overview of the delegations of `delegator` with their pending requests
+        pub fn delegator_overview(
+            delegator: &T::AccountId,
+        ) -> Option<DelegatorOverview<T::AccountId, BalanceOf<T>>> {
+            let state = <DelegatorState<T>>::get(delegator)?;
+            let delegations = state
+                .delegations
+                .0
+                .into_iter()
+                .map(|Bond { owner, amount }| DelegationOverview {
+                    auto_compound: <AutoCompoundingDelegations<T>>::get(delegator, &owner),
+                    request: state.requests.requests.get(&owner).cloned(),
+                    candidate: owner,
+                    amount,
+                })
+                .collect();
+            Some(DelegatorOverview {
+                total: state.total,
+                less_total: state.requests.less_total,
+                status: state.status,
+                reward_destination: <RewardDestinations<T>>::get(delegator),
+                delegations,
+            })
+        }
         /// Remove delegation from candidate state
         /// Amount input should be retrieved from delegator and it informs the storage lookups
         fn delegator_leaves_candidate(
@@ -3080,6 +3448,7 @@ pub mod pallet {
             let total_changed =
                 state.rm_delegation_if_exists::<T>(&candidate, delegator.clone(), amount)?;
             T::Currency::unreserve(&delegator, amount);
+            <AutoCompoundingDelegations<T>>::remove(&delegator, &candidate);
             if state.is_active() && total_changed {
                 Self::update_active(candidate.clone(), state.total_counted);
             }
@@ -3178,18 +3547,59 @@ pub mod pallet {
                 return (None, 0u64.into());
             }
 
-            let mint = |amt: BalanceOf<T>, to: T::AccountId| {
-                if let Ok(amount_transferred) = T::Currency::deposit_into_existing(&to, amt) {
-                    Self::deposit_event(Event::Rewarded(to.clone(), amount_transferred.peek()));
-                }
-            };
-
-            let collator_fee = payout_info.collator_commission;
-            let collator_issuance = collator_fee * payout_info.round_issuance;
-
             if let Some((collator, pts)) =
                 <AwardedPts<T>>::iter_prefix(paid_for_round).drain().next()
             {
+                // pays to the reward destination of the account,
+                // returns the amount paid into the free balance of the account
+                let mint = |amt: BalanceOf<T>, to: T::AccountId| -> BalanceOf<T> {
+                    match <RewardDestinations<T>>::get(&to) {
+                        RewardDestination::Free => {
+                            if let Ok(amount_transferred) =
+                                T::Currency::deposit_into_existing(&to, amt)
+                            {
+                                let paid = amount_transferred.peek();
+                                Self::deposit_event(Event::Rewarded(to, paid));
+                                return paid;
+                            }
+                        }
+                        RewardDestination::Account(account) => {
+                            let amount_transferred = T::Currency::deposit_creating(&account, amt);
+                            Self::deposit_event(Event::RewardedToDestination(
+                                to,
+                                RewardDestination::Account(account),
+                                amount_transferred.peek(),
+                            ));
+                        }
+                        RewardDestination::Stake => {
+                            if let Ok(amount_transferred) =
+                                T::Currency::deposit_into_existing(&to, amt)
+                            {
+                                let paid = amount_transferred.peek();
+                                Self::deposit_event(Event::RewardedToDestination(
+                                    to.clone(),
+                                    RewardDestination::Stake,
+                                    paid,
+                                ));
+                                if to == collator {
+                                    Self::stake_collator_reward(to, paid);
+                                } else {
+                                    Self::compound_reward(to, collator.clone(), paid);
+                                }
+                            }
+                        }
+                        RewardDestination::Treasury => {
+                            let amount_transferred =
+                                T::Currency::deposit_creating(&T::TreasuryAccount::get(), amt);
+                            Self::deposit_event(Event::RewardedToDestination(
+                                to,
+                                RewardDestination::Treasury,
+                                amount_transferred.peek(),
+                            ));
+                        }
+                    }
+                    Zero::zero()
+                };
                 let pct_due = Perbill::from_rational(pts, total_points);
                 let total_paid = pct_due * payout_info.total_staking_reward;
                 let mut amt_due = total_paid;
@@ -3202,15 +3612,21 @@ pub mod pallet {
                 } else {
                     // pay collator first; commission + due_portion
                     let collator_pct = Perbill::from_rational(state.bond, state.total);
-                    let commission = pct_due * collator_issuance;
-                    amt_due -= commission;
+                    // the reward is what's left of the issuance after the parachain bond reserve
+                    let commission = (pct_due * (state.commission * payout_info.round_issuance))
+                        .min(total_paid);
+                    amt_due = amt_due.saturating_sub(commission);
                     let collator_reward = (collator_pct * amt_due) + commission;
                     mint(collator_reward, collator.clone());
                     // pay delegators due portion
                     for Bond { owner, amount } in state.delegations {
                         let percent = Perbill::from_rational(amount, state.total);
                         let due = percent * amt_due;
-                        mint(due, owner.clone());
+                        let paid = mint(due, owner.clone());
+                        let compound = <AutoCompoundingDelegations<T>>::get(&owner, &collator);
+                        if !compound.is_zero() {
+                            Self::compound_reward(owner, collator.clone(), compound * paid);
+                        }
                     }
                 }
 
@@ -3225,6 +3641,467 @@ pub mod pallet {
             }
         }
 
+        /// Bond `amount` of a freshly paid delegator reward back into its delegation.
+        /// Leaving delegators and delegations scheduled for revocation are not compounded,
+        /// the reward simply stays free balance.
+        fn compound_reward(
+            delegator: T::AccountId,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+        ) {
+            if amount.is_zero() {
+                return;
+            }
+            let mut state = match <DelegatorState<T>>::get(&delegator) {
+                Some(state) if state.is_active() => state,
+                _ => return,
+            };
+            if matches!(
+                state.requests.requests.get(&candidate),
+                Some(DelegationRequest {
+                    action: DelegationChange::Revoke,
+                    ..
+                })
+            ) {
+                return;
+            }
+            let result = with_transaction(|| {
+                match state.increase_delegation::<T>(candidate.clone(), amount) {
+                    Ok(()) => TransactionOutcome::Commit(Ok(())),
+                    Err(error) => TransactionOutcome::Rollback(Err(error)),
+                }
+            });
+            match result {
+                Ok(()) => {
+                    Self::deposit_event(Event::AutoCompounded(delegator, candidate, amount))
+                }
+                Err(error) => log::warn!(
+                    "Auto-compounding reward of {:?} into {:?} failed with error: {:?}",
+                    delegator,
+                    candidate,
+                    error
+                ),
+            }
+        }
+
+        /// Bond a freshly paid collator reward into the self-bond of the collator
+        fn stake_collator_reward(collator: T::AccountId, amount: BalanceOf<T>) {
+            if amount.is_zero() {
+                return;
+            }
+            let mut state = match <CandidateInfo<T>>::get(&collator) {
+                Some(state) if !state.is_leaving() => state,
+                _ => return,
+            };
+            if let Err(error) = state.bond_more::<T>(collator.clone(), amount) {
+                log::warn!(
+                    "Staking reward of collator {:?} failed with error: {:?}",
+                    collator,
+                    error
+                );
+                return;
+            }
+            let (is_active, total_counted) = (state.is_active(), state.total_counted);
+            <CandidateInfo<T>>::insert(&collator, state);
+            if is_active {
+                Self::update_active(collator, total_counted);
+            }
+        }
+
+        /// Derive the block time from the timestamps of the round which lasted `blocks` blocks and
+        /// recompute per-round inflation if it moved by more than `BLOCK_TIME_TOLERANCE`
+        fn update_block_time(blocks: u32) -> Weight {
+            let now: u64 = T::UnixTime::now().as_millis().saturated_into();
+            let round_start = <RoundStartTime<T>>::get();
+            <RoundStartTime<T>>::put(now);
+            if round_start.is_zero() || blocks.is_zero() || now <= round_start {
+                return T::DbWeight::get().reads_writes(1, 1);
+            }
+            let observed = (now - round_start) / u64::from(blocks);
+            let old = <BlockTime<T>>::get();
+            let drift = if observed > old {
+                observed - old
+            } else {
+                old - observed
+            };
+            if Perbill::from_rational(drift, old) <= crate::inflation::BLOCK_TIME_TOLERANCE {
+                return T::DbWeight::get().reads_writes(2, 1);
+            }
+            <BlockTime<T>>::put(observed);
+            let mut config = <InflationConfig<T>>::get();
+            config.set_round_from_annual::<T>(config.annual);
+            Self::deposit_event(Event::BlockTimeSet(
+                old,
+                observed,
+                config.round.min,
+                config.round.ideal,
+                config.round.max,
+            ));
+            <InflationConfig<T>>::put(config);
+            T::DbWeight::get().reads_writes(4, 3)
+        }
+
+        /// Take an active candidate offline, removing it from the candidate pool
+        fn candidate_go_offline(
+            collator: &T::AccountId,
+            mut state: CandidateMetadata<BalanceOf<T>>,
+        ) {
+            state.go_offline();
+            let mut candidates = <CandidatePool<T>>::get();
+            if candidates.remove(&Bond::from_owner(collator.clone())) {
+                <CandidatePool<T>>::put(candidates);
+            }
+            <CandidateInfo<T>>::insert(collator, state);
+            <MissedRounds<T>>::remove(collator);
+        }
+
+        /// Record the authoring stats of the collators selected for `round`, which lasted
+        /// `blocks` blocks, and take offline the candidates which missed more than
+        /// `MissedSlotsThreshold` of their slots for `MaxMissedRounds` consecutive rounds, deferring
+        /// their downtime slash
+        fn track_liveness(round: RoundIndex, blocks: u32) -> Weight {
+            let selected = <SelectedCandidates<T>>::get();
+            let (mut reads, mut writes) = (1u64, 0u64);
+            // prune the stats which are no longer kept
+            if let Some(pruned) = round.checked_sub(T::RewardPaymentDelay::get()) {
+                let removed = match <RoundAuthoringStats<T>>::remove_prefix(pruned, None) {
+                    KillStorageResult::AllRemoved(removed)
+                    | KillStorageResult::SomeRemaining(removed) => removed,
+                };
+                writes += removed as u64;
+            }
+            if selected.is_empty() {
+                return T::DbWeight::get().reads_writes(reads, writes);
+            }
+            // every selected collator is expected to author the same number of blocks
+            let expected = blocks / selected.len() as u32;
+            let max_missed_rounds = T::MaxMissedRounds::get();
+            for collator in selected {
+                reads += 2;
+                writes += 2;
+                let authored = <RoundAuthoringStats<T>>::mutate(round, &collator, |stats| {
+                    let stats = stats.get_or_insert_with(Default::default);
+                    stats.expected = expected;
+                    stats.authored
+                });
+                if expected.is_zero() {
+                    continue;
+                }
+                let missed = Percent::from_rational(expected.saturating_sub(authored), expected);
+                if missed <= T::MissedSlotsThreshold::get() {
+                    <MissedRounds<T>>::remove(&collator);
+                    continue;
+                }
+                let missed_rounds = <MissedRounds<T>>::get(&collator).saturating_add(1);
+                if max_missed_rounds.is_zero() || missed_rounds < max_missed_rounds {
+                    <MissedRounds<T>>::insert(&collator, missed_rounds);
+                    continue;
+                }
+                reads += 2;
+                writes += 2;
+                match <CandidateInfo<T>>::get(&collator) {
+                    Some(state) if state.is_active() => {
+                        reads += 1;
+                        writes += 1;
+                        Self::candidate_go_offline(&collator, state);
+                        Self::deposit_event(Event::CandidateWentOfflineForMissedSlots(
+                            collator.clone(),
+                            missed_rounds,
+                        ));
+                        let slashed = Self::defer_downtime_slash(round, &collator);
+                        reads += slashed;
+                        writes += slashed;
+                    }
+                    _ => <MissedRounds<T>>::remove(&collator),
+                }
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        /// Defer the downtime slash of `collator`, taken offline at the end of `round`, until
+        /// `SlashDeferDuration` rounds after the next round
+        /// Returns the number of slashed accounts
+        fn defer_downtime_slash(round: RoundIndex, collator: &T::AccountId) -> u64 {
+            let slash = match crate::slashing::compute_downtime_slash::<T>(collator) {
+                Some(slash) => slash,
+                None => return 0,
+            };
+            let when = round
+                .saturating_add(1)
+                .saturating_add(T::SlashDeferDuration::get());
+            let total = slash.total();
+            let mut slashed = 0u64;
+            for account in slash.accounts() {
+                slashed += 1;
+                <PendingSlashes<T>>::mutate(account, |pending| *pending += 1);
+            }
+            <UnappliedSlashes<T>>::append(when, slash);
+            Self::deposit_event(Event::SlashDeferred(collator.clone(), when, total));
+            slashed
+        }
+
+        /// Apply the slashes due in round `now`
+        fn apply_slashes(now: RoundIndex) -> Weight {
+            if !<UnappliedSlashes<T>>::contains_key(now) {
+                return T::DbWeight::get().reads(1);
+            }
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+            for slash in <UnappliedSlashes<T>>::take(now) {
+                weight =
+                    weight.saturating_add(T::WeightInfo::apply_slash(slash.others.len() as u32));
+                Self::apply_slash(&slash);
+            }
+            weight
+        }
+
+        /// Slash the collator and the delegators of `slash`
+        pub(crate) fn apply_slash(slash: &UnappliedSlash<T::AccountId, BalanceOf<T>>) {
+            Self::slash_candidate(&slash.collator, slash.own);
+            for (delegator, amount) in &slash.others {
+                Self::slash_delegation(delegator, &slash.collator, *amount);
+            }
+            Self::remove_pending_slash(slash);
+        }
+
+        /// Stop counting the accounts of `slash` as pending a slash
+        fn remove_pending_slash(slash: &UnappliedSlash<T::AccountId, BalanceOf<T>>) {
+            for account in slash.accounts() {
+                <PendingSlashes<T>>::mutate_exists(account, |pending| {
+                    *pending = pending
+                        .and_then(|pending| pending.checked_sub(1))
+                        .filter(|pending| !pending.is_zero());
+                });
+            }
+        }
+
+        /// Slashed accounts can't unbond before their deferred slashes are applied
+        fn ensure_no_pending_slash(who: &T::AccountId) -> DispatchResult {
+            ensure!(
+                <PendingSlashes<T>>::get(who).is_zero(),
+                Error::<T>::DeferredSlashPending
+            );
+            Ok(())
+        }
+
+        /// Move `amount` of the reserved bond of `who` backing `candidate` to the treasury
+        fn slash_bond(who: &T::AccountId, candidate: &T::AccountId, amount: BalanceOf<T>) {
+            let unmoved = T::Currency::repatriate_reserved(
+                who,
+                &T::TreasuryAccount::get(),
+                amount,
+                BalanceStatus::Free,
+            )
+            .unwrap_or(amount);
+            let slashed = amount.saturating_sub(unmoved);
+            <Total<T>>::mutate(|total| *total = total.saturating_sub(slashed));
+            Self::deposit_event(Event::Slashed(who.clone(), candidate.clone(), slashed));
+        }
+
+        /// Slash up to `amount` of the self bond of `candidate`
+        fn slash_candidate(candidate: &T::AccountId, amount: BalanceOf<T>) {
+            let mut state = match <CandidateInfo<T>>::get(candidate) {
+                Some(state) => state,
+                None => return,
+            };
+            let amount = amount.min(state.bond);
+            if amount.is_zero() {
+                return;
+            }
+            Self::slash_bond(candidate, candidate, amount);
+            state.bond = state.bond.saturating_sub(amount);
+            state.total_counted = state.total_counted.saturating_sub(amount);
+            // a pending bond less request must leave the minimum of the slashed bond
+            if let Some(request) = state.request {
+                if request.amount.saturating_add(T::MinCandidateStk::get()) > state.bond {
+                    state.request = None;
+                    Self::deposit_event(Event::CancelledCandidateBondLess(
+                        candidate.clone(),
+                        request.amount,
+                        request.when_executable,
+                    ));
+                }
+            }
+            if state.is_active() {
+                if state.bond < T::MinCandidateStk::get() {
+                    // the candidate must bond more to go back online
+                    Self::candidate_go_offline(candidate, state);
+                    Self::deposit_event(Event::CandidateWentOffline(candidate.clone()));
+                    return;
+                }
+                Self::update_active(candidate.clone(), state.total_counted);
+            }
+            <CandidateInfo<T>>::insert(candidate, state);
+        }
+
+        /// Slash up to `amount` of the delegation of `delegator` to `candidate`,
+        /// the delegation is kicked if less than `MinDelegation` of it is left
+        fn slash_delegation(
+            delegator: &T::AccountId,
+            candidate: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) {
+            let (mut delegator_state, mut candidate_state) = match (
+                <DelegatorState<T>>::get(delegator),
+                <CandidateInfo<T>>::get(candidate),
+            ) {
+                (Some(delegator_state), Some(candidate_state)) => {
+                    (delegator_state, candidate_state)
+                }
+                _ => return,
+            };
+            let bond = match delegator_state
+                .delegations
+                .0
+                .iter_mut()
+                .find(|bond| &bond.owner == candidate)
+            {
+                Some(bond) => bond,
+                None => return,
+            };
+            let amount_before = bond.amount;
+            let amount = amount.min(amount_before);
+            if amount.is_zero() {
+                return;
+            }
+            let before = candidate_state.total_counted;
+            if candidate_state
+                .decrease_delegation::<T>(candidate, delegator.clone(), amount_before, amount)
+                .is_err()
+            {
+                return;
+            }
+            bond.amount = amount_before.saturating_sub(amount);
+            delegator_state.total = delegator_state.total.saturating_sub(amount);
+            let remaining = bond.amount;
+            // a pending revocation only unstakes what is left of the delegation, and a pending
+            // decrease which doesn't leave any of it can't be executed anymore
+            let cancel_decrease = match delegator_state.requests.requests.get_mut(candidate) {
+                Some(request) if request.action == DelegationChange::Revoke => {
+                    request.amount = request.amount.saturating_sub(amount);
+                    delegator_state.requests.less_total =
+                        delegator_state.requests.less_total.saturating_sub(amount);
+                    false
+                }
+                Some(request) => request.amount >= remaining,
+                None => false,
+            };
+            if cancel_decrease {
+                if let Some(request) = delegator_state.requests.requests.remove(candidate) {
+                    delegator_state.requests.less_total = delegator_state
+                        .requests
+                        .less_total
+                        .saturating_sub(request.amount);
+                    Self::deposit_event(Event::CancelledDelegationRequest(
+                        delegator.clone(),
+                        request,
+                    ));
+                }
+            }
+            Self::slash_bond(delegator, candidate, amount);
+            if candidate_state.is_active() && before != candidate_state.total_counted {
+                Self::update_active(candidate.clone(), candidate_state.total_counted);
+            }
+            <CandidateInfo<T>>::insert(candidate, candidate_state);
+            if remaining < T::MinDelegation::get()
+                && Self::delegator_leaves_candidate(
+                    candidate.clone(),
+                    delegator.clone(),
+                    remaining,
+                )
+                .is_ok()
+            {
+                Self::kick_slashed_delegation(delegator, candidate, delegator_state, remaining);
+            } else {
+                <DelegatorState<T>>::insert(delegator, delegator_state);
+            }
+        }
+
+        /// Remove the delegation of `delegator` to `candidate`, which `remaining` is left
+        /// below `MinDelegation` by a slash and unreserved, from the delegator state
+        fn kick_slashed_delegation(
+            delegator: &T::AccountId,
+            candidate: &T::AccountId,
+            mut state: Delegator<T::AccountId, BalanceOf<T>>,
+            remaining: BalanceOf<T>,
+        ) {
+            if let Some(request) = state.requests.requests.remove(candidate) {
+                state.requests.less_total =
+                    state.requests.less_total.saturating_sub(request.amount);
+                if request.action == DelegationChange::Revoke {
+                    state.requests.revocations_count =
+                        state.requests.revocations_count.saturating_sub(1);
+                }
+                Self::deposit_event(Event::CancelledDelegationRequest(
+                    delegator.clone(),
+                    request,
+                ));
+            }
+            state.rm_delegation(candidate);
+            Self::deposit_event(Event::DelegationKicked(
+                delegator.clone(),
+                candidate.clone(),
+                remaining,
+            ));
+            if state.delegations.0.is_empty() {
+                <DelegatorState<T>>::remove(delegator);
+                <RewardDestinations<T>>::remove(delegator);
+                Self::deposit_event(Event::DelegatorLeft(delegator.clone(), remaining));
+            } else {
+                <DelegatorState<T>>::insert(delegator, state);
+            }
+        }
+
+        /// Decay the annual inflation if the year of the decay schedule ends in round `now`
+        fn apply_inflation_decay(now: RoundIndex) -> Weight {
+            let mut decay = match <InflationDecaySchedule<T>>::get() {
+                Some(decay) if decay.next_round <= now => decay,
+                _ => return T::DbWeight::get().reads(1),
+            };
+            decay.next_round = now.saturating_add(crate::inflation::rounds_per_year::<T>());
+            <InflationDecaySchedule<T>>::put(decay);
+            let mut config = <InflationConfig<T>>::get();
+            let annual = decay.decay(config.annual);
+            if annual != config.annual {
+                config.annual = annual;
+                config.set_round_from_annual::<T>(annual);
+                Self::deposit_event(Event::InflationDecayed(
+                    config.annual.min,
+                    config.annual.ideal,
+                    config.annual.max,
+                    config.round.min,
+                    config.round.ideal,
+                    config.round.max,
+                ));
+                <InflationConfig<T>>::put(config);
+            }
+            T::DbWeight::get().reads_writes(4, 2)
+        }
+
+        /// Apply the candidate commission changes due in round `now`
+        fn apply_candidate_commission_changes(now: RoundIndex) -> Weight {
+            let (mut reads, mut writes) = (1u64, 1u64);
+            for candidate in <CandidateCommissionChanges<T>>::take(now) {
+                reads += 1;
+                // the request may have been replaced or the candidate may have left since
+                let request = match <CandidateCommissionRequests<T>>::get(&candidate) {
+                    Some(request) if request.when_executable == now => request,
+                    _ => continue,
+                };
+                reads += 1;
+                writes += 2;
+                let old = <CandidateCommission<T>>::get(&candidate)
+                    .unwrap_or_else(<CollatorCommission<T>>::get);
+                <CandidateCommission<T>>::insert(&candidate, request.commission);
+                <CandidateCommissionRequests<T>>::remove(&candidate);
+                Self::deposit_event(Event::CandidateCommissionSet(
+                    candidate,
+                    old,
+                    request.commission,
+                ));
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
         /// Compute the top `TotalSelected` candidates in the CandidatePool and return
         /// a vec of their AccountIds (in the order of selection)
         pub fn compute_top_candidates() -> Vec<T::AccountId> {
@@ -3250,6 +4127,8 @@ pub mod pallet {
                 (0u32, 0u32, BalanceOf::<T>::zero());
             // choose the top TotalSelected qualified candidates, ordered by stake
             let collators = Self::compute_top_candidates();
+            let commission_bounds = <CandidateCommissionBounds<T>>::get();
+            let default_commission = <CollatorCommission<T>>::get();
             // snapshot exposure for round for weighting reward distribution
             for account in collators.iter() {
                 let state = <CandidateInfo<T>>::get(account)
@@ -3264,6 +4143,9 @@ pub mod pallet {
                     bond: state.bond,
                     delegations: top_delegations.delegations,
                     total: state.total_counted,
+                    commission: commission_bounds.clamp(
+                        <CandidateCommission<T>>::get(account).unwrap_or(default_commission),
+                    ),
                 };
                 <AtStake<T>>::insert(now, account, snapshot);
                 Self::deposit_event(Event::CollatorChosen(now, account.clone(), snapshot_total));
@@ -3276,9 +4158,13 @@ pub mod pallet {
 
     /// Add reward points to block authors:
     /// * 20 points to the block producer for producing a block in the chain
+    /// and count the block in the authoring stats of the round
     impl<T: Config> nimbus_primitives::EventHandler<T::AccountId> for Pallet<T> {
         fn note_author(author: T::AccountId) {
             let now = <Round<T>>::get().current;
+            <RoundAuthoringStats<T>>::mutate(now, &author, |stats| {
+                stats.get_or_insert_with(Default::default).authored += 1
+            });
             let score_plus_20 = <AwardedPts<T>>::get(now, &author) + 20;
             <AwardedPts<T>>::insert(now, author, score_plus_20);
             <Points<T>>::mutate(now, |x| *x += 20);
@@ -16,434 +16,397 @@
 
 //! # Migrations
 use crate::{
-    pallet::Total, BalanceOf, Bond, BottomDelegations, CandidateInfo, CandidateMetadata,
-    CandidateState, CapacityStatus, CollatorCandidate, Config, Delegations, DelegatorState,
-    Event, Pallet, Points, Round, Staked, TopDelegations,
+    pallet::{CandidatePool, Total, STORAGE_VERSION},
+    set::OrderedSet,
+    AtStake, BalanceOf, Bond, BottomDelegations, CandidateBondLessRequest, CandidateInfo,
+    CandidateMetadata, CapacityStatus, CollatorSnapshot, CollatorStatus, Config, DelayedPayout,
+    DelayedPayouts, DelegationRequest, Delegations, Delegator, DelegatorState, DelegatorStatus,
+    Event, InflationConfig, InflationInfo, Pallet, PendingDelegationRequests, Points, Range,
+    Round, Staked, TopDelegations,
 };
 #[cfg(feature = "try-runtime")]
-use crate::{Collator2, Delegator, Nominator2};
-#[cfg(feature = "try-runtime")]
 use frame_support::traits::OnRuntimeUpgradeHelpersExt;
-use frame_support::Twox64Concat;
-extern crate alloc;
-#[cfg(feature = "try-runtime")]
-use alloc::format;
 use frame_support::{
-    migration::{remove_storage_prefix, storage_key_iter},
+    migration::{put_storage_value, remove_storage_prefix, storage_key_iter},
     pallet_prelude::PhantomData,
-    traits::{Get, OnRuntimeUpgrade, ReservableCurrency},
+    storage::unhashed,
+    traits::{
+        Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, ReservableCurrency,
+        StorageVersion,
+    },
     weights::Weight,
+    StorageHasher, Twox64Concat,
 };
-use sp_runtime::traits::Zero;
-use sp_std::{convert::TryInto, vec::Vec};
-
-/// Migration to split CandidateState and minimize unnecessary storage reads
-/// for PoV optimization
-/// This assumes Config::MaxTopDelegationsPerCandidate == OldConfig::MaxDelegatorsPerCandidate
-pub struct SplitCandidateStateToDecreasePoV<T>(PhantomData<T>);
-impl<T: Config> OnRuntimeUpgrade for SplitCandidateStateToDecreasePoV<T> {
-    fn on_runtime_upgrade() -> Weight {
-        let pallet_prefix: &[u8] = b"ParachainStaking";
-        let storage_item_prefix: &[u8] = b"CandidateState";
-        // Read all the data into memory.
-        // https://crates.parity.io/frame_support/storage/migration/fn.storage_key_iter.html
-        let stored_data: Vec<_> = storage_key_iter::<
-            T::AccountId,
-            CollatorCandidate<T::AccountId, BalanceOf<T>>,
-            Twox64Concat,
-        >(pallet_prefix, storage_item_prefix)
-        .collect();
-        let migrated_count: Weight = stored_data
-            .len()
-            .try_into()
-            .expect("There are between 0 and 2**64 mappings stored.");
-        // Now remove the old storage
-        // https://crates.parity.io/frame_support/storage/migration/fn.remove_storage_prefix.html
-        remove_storage_prefix(pallet_prefix, storage_item_prefix, &[]);
-        // Assert that old storage is empty
-        assert!(storage_key_iter::<
-            T::AccountId,
-            CollatorCandidate<T::AccountId, BalanceOf<T>>,
-            Twox64Concat,
-        >(pallet_prefix, storage_item_prefix)
-        .next()
-        .is_none());
-        for (account, state) in stored_data {
-            // all delegations are stored greatest to least post migration
-            // but bottom delegations were least to greatest pre migration
-            let new_bottom_delegations: Vec<Bond<T::AccountId, BalanceOf<T>>> =
-                if state.bottom_delegations.len()
-                    > T::MaxBottomDelegationsPerCandidate::get() as usize
-                {
-                    // if actual length > max bottom delegations, revoke the bottom actual - max
-                    let rest = state.bottom_delegations.len()
-                        - T::MaxBottomDelegationsPerCandidate::get() as usize;
-                    let mut total_less = BalanceOf::<T>::zero();
-                    state.bottom_delegations.iter().take(rest).for_each(
-                        |Bond { owner, amount }| {
-                            total_less += *amount;
-                            // update delegator state
-                            // unreserve kicked bottom
-                            T::Currency::unreserve(&owner, *amount);
-                            let mut delegator_state = <DelegatorState<T>>::get(&owner)
-                                .expect("Delegation existence => DelegatorState existence");
-                            let leaving = delegator_state.delegations.0.len() == 1usize;
-                            delegator_state.rm_delegation(&account);
-                            Pallet::<T>::deposit_event(Event::DelegationKicked(
-                                owner.clone(),
-                                account.clone(),
-                                *amount,
-                            ));
-                            if leaving {
-                                <DelegatorState<T>>::remove(&owner);
-                                Pallet::<T>::deposit_event(Event::DelegatorLeft(
-                                    owner.clone(),
-                                    *amount,
-                                ));
-                            } else {
-                                <DelegatorState<T>>::insert(&owner, delegator_state);
-                            }
-                        },
-                    );
-                    let new_total = <Total<T>>::get() - total_less;
-                    <Total<T>>::put(new_total);
-                    state
-                        .bottom_delegations
-                        .into_iter()
-                        .rev()
-                        .take(T::MaxBottomDelegationsPerCandidate::get() as usize)
-                        .collect()
-                } else {
-                    state.bottom_delegations.into_iter().rev().collect()
-                };
-            let lowest_top_delegation_amount = if state.top_delegations.is_empty() {
-                BalanceOf::<T>::zero()
-            } else {
-                state.top_delegations[state.top_delegations.len() - 1].amount
-            };
-            let highest_bottom_delegation_amount = if new_bottom_delegations.is_empty() {
-                BalanceOf::<T>::zero()
-            } else {
-                new_bottom_delegations[0].amount
-            };
-            // start here,
-            let lowest_bottom_delegation_amount = if new_bottom_delegations.is_empty() {
-                BalanceOf::<T>::zero()
-            } else {
-                new_bottom_delegations[new_bottom_delegations.len() - 1].amount
-            };
-            let top_capacity = match &state.top_delegations {
-                x if x.len() as u32 >= T::MaxTopDelegationsPerCandidate::get() => {
-                    CapacityStatus::Full
-                }
-                x if x.is_empty() => CapacityStatus::Empty,
-                _ => CapacityStatus::Partial,
-            };
-            let bottom_capacity = match &new_bottom_delegations {
-                x if x.len() as u32 >= T::MaxBottomDelegationsPerCandidate::get() => {
-                    CapacityStatus::Full
-                }
-                x if x.is_empty() => CapacityStatus::Empty,
-                _ => CapacityStatus::Partial,
-            };
-            let metadata = CandidateMetadata {
-                bond: state.bond,
-                delegation_count: state.top_delegations.len() as u32
-                    + new_bottom_delegations.len() as u32,
-                total_counted: state.total_counted,
-                lowest_top_delegation_amount,
-                highest_bottom_delegation_amount,
-                lowest_bottom_delegation_amount,
-                top_capacity,
-                bottom_capacity,
-                request: state.request,
-                status: state.state,
-            };
-            <CandidateInfo<T>>::insert(&account, metadata);
-            let top_delegations = Delegations {
-                total: state.total_counted - state.bond,
-                delegations: state.top_delegations,
-            };
-            <TopDelegations<T>>::insert(&account, top_delegations);
-            let bottom_delegations = Delegations {
-                total: new_bottom_delegations
-                    .iter()
-                    .fold(BalanceOf::<T>::zero(), |acc, b| acc + b.amount),
-                delegations: new_bottom_delegations,
-            };
-            <BottomDelegations<T>>::insert(&account, bottom_delegations);
-        }
-        let weight = T::DbWeight::get();
-        migrated_count.saturating_mul(3 * weight.write + weight.read)
-    }
-    #[cfg(feature = "try-runtime")]
-    fn pre_upgrade() -> Result<(), &'static str> {
-        // get delegation count for all candidates to check consistency
-        for (account, state) in <CandidateState<T>>::iter() {
-            // insert top + bottom into some temp map?
-            let total_delegation_count =
-                state.top_delegations.len() as u32 + state.bottom_delegations.len() as u32;
-            Self::set_temp_storage(
-                total_delegation_count,
-                &format!("Candidate{}DelegationCount", account)[..],
-            );
-        }
-        Ok(())
-    }
+use parity_scale_codec::{Decode, DecodeAll, Encode};
+use sp_runtime::{traits::Zero, RuntimeDebug};
+use sp_std::vec::Vec;
 
-    #[cfg(feature = "try-runtime")]
-    fn post_upgrade() -> Result<(), &'static str> {
-        // check that top + bottom are the same as the expected (stored in temp)
-        for (account, state) in <CandidateInfo<T>>::iter() {
-            let expected_count: u32 =
-                Self::get_temp_storage(&format!("Candidate{}DelegationCount", account)[..])
-                    .expect("qed");
-            let actual_count = state.delegation_count;
-            assert_eq!(expected_count, actual_count);
+/// Deprecated storage item of `Collator2` states, replaced by `CandidateState`
+const COLLATOR_STATE2: &[u8] = b"CollatorState2";
+/// Deprecated storage item of `CollatorCandidate` states, replaced by `CandidateInfo`,
+/// `TopDelegations` and `BottomDelegations`
+const CANDIDATE_STATE: &[u8] = b"CandidateState";
+/// Deprecated storage item of `Nominator2` states, replaced by `DelegatorState`
+const NOMINATOR_STATE2: &[u8] = b"NominatorState2";
+
+#[derive(Encode, Decode, RuntimeDebug)]
+/// Collator state stored in the deprecated `CollatorState2`
+pub struct Collator2<AccountId, Balance> {
+    /// The account of this collator
+    pub id: AccountId,
+    /// This collator's self stake.
+    pub bond: Balance,
+    /// Set of all nominator AccountIds (to prevent >1 nomination per AccountId)
+    pub nominators: OrderedSet<AccountId>,
+    /// Top T::MaxDelegatorsPerCollator::get() nominators, ordered greatest to least
+    pub top_nominators: Vec<Bond<AccountId, Balance>>,
+    /// Bottom nominators (unbounded), ordered least to greatest
+    pub bottom_nominators: Vec<Bond<AccountId, Balance>>,
+    /// Sum of top delegations + self.bond
+    pub total_counted: Balance,
+    /// Sum of all delegations + self.bond = (total_counted + uncounted)
+    pub total_backing: Balance,
+    /// Current status of the collator
+    pub state: CollatorStatus,
+}
+
+impl<A, B> From<Collator2<A, B>> for CollatorCandidate<A, B> {
+    fn from(other: Collator2<A, B>) -> CollatorCandidate<A, B> {
+        CollatorCandidate {
+            id: other.id,
+            bond: other.bond,
+            delegators: other.nominators,
+            top_delegations: other.top_nominators,
+            bottom_delegations: other.bottom_nominators,
+            total_counted: other.total_counted,
+            total_backing: other.total_backing,
+            request: None,
+            state: other.state,
         }
-        Ok(())
     }
 }
 
-/// Migration to properly increase maximum delegations per collator
-/// The logic may be used to recompute the top and bottom delegations whenever
-/// MaxTopDelegationsPerCandidate changes (works for if decreases as well)
-pub struct IncreaseMaxDelegationsPerCandidate<T>(PhantomData<T>);
-impl<T: Config> OnRuntimeUpgrade for IncreaseMaxDelegationsPerCandidate<T> {
+#[derive(Encode, Decode, RuntimeDebug)]
+/// Collator candidate state stored in the deprecated `CandidateState`
+pub struct CollatorCandidate<AccountId, Balance> {
+    /// The account of this collator
+    pub id: AccountId,
+    /// This collator's self stake.
+    pub bond: Balance,
+    /// Set of all delegator AccountIds (to prevent >1 delegation per AccountId)
+    pub delegators: OrderedSet<AccountId>,
+    /// Top T::MaxDelegatorsPerCollator::get() delegations, ordered greatest to least
+    pub top_delegations: Vec<Bond<AccountId, Balance>>,
+    /// Bottom delegations (unbounded), ordered least to greatest
+    pub bottom_delegations: Vec<Bond<AccountId, Balance>>,
+    /// Sum of top delegations + self.bond
+    pub total_counted: Balance,
+    /// Sum of all delegations + self.bond = (total_counted + uncounted)
+    pub total_backing: Balance,
+    /// Maximum 1 pending request to decrease candidate self bond at any given time
+    pub request: Option<CandidateBondLessRequest<Balance>>,
+    /// Current status of the collator
+    pub state: CollatorStatus,
+}
+
+#[derive(Encode, Decode, RuntimeDebug)]
+/// Nominator state stored in the deprecated `NominatorState2`
+pub struct Nominator2<AccountId, Balance> {
+    /// All current delegations
+    pub delegations: OrderedSet<Bond<AccountId, Balance>>,
+    /// Delegations scheduled to be revoked
+    pub revocations: OrderedSet<AccountId>,
+    /// Total balance locked for this nominator
+    pub total: Balance,
+    /// Total number of revocations scheduled to be executed
+    pub scheduled_revocations_count: u32,
+    /// Total amount to be unbonded once revocations are executed
+    pub scheduled_revocations_total: Balance,
+    /// Status for this nominator
+    pub status: DelegatorStatus,
+}
+
+/// Migration to move the states left in the deprecated `CandidateState`, `CollatorState2` and
+/// `NominatorState2` into `CandidateInfo` and `DelegatorState`, and remove the deprecated storage.
+/// States of accounts which already have a current one are dead and dropped.
+/// Runs for storage version 2, after `MigrateBalanceType` and `SnapshotCollatorCommission`.
+pub struct RemoveDeprecatedStorage<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for RemoveDeprecatedStorage<T> {
     fn on_runtime_upgrade() -> Weight {
-        let (mut reads, mut writes) = (0u64, 0u64);
-        for (account, state) in <CandidateState<T>>::iter() {
-            reads += 1u64;
-            // 1. collect all delegations into single vec and order them
-            let mut all_delegations = state.top_delegations.clone();
-            let mut starting_bottom_delegations = state.bottom_delegations.clone();
-            all_delegations.append(&mut starting_bottom_delegations);
-            // sort all delegations from greatest to least
-            all_delegations.sort_unstable_by(|a, b| b.amount.cmp(&a.amount));
-            let top_n = T::MaxTopDelegationsPerCandidate::get() as usize;
-            // 2. split them into top and bottom using the T::MaxNominatorsPerCollator
-            let top_delegations: Vec<Bond<T::AccountId, BalanceOf<T>>> =
-                all_delegations.iter().take(top_n).cloned().collect();
-            let bottom_delegations = if all_delegations.len() > top_n {
-                let rest = all_delegations.len() - top_n;
-                let bottom: Vec<Bond<T::AccountId, BalanceOf<T>>> =
-                    all_delegations.iter().rev().take(rest).cloned().collect();
-                bottom
-            } else {
-                // empty, all nominations are in top
-                Vec::new()
-            };
-            let (mut total_counted, mut total_backing): (BalanceOf<T>, BalanceOf<T>) =
-                (state.bond.into(), state.bond.into());
-            for Bond { amount, .. } in &top_delegations {
-                total_counted += *amount;
-                total_backing += *amount;
-            }
-            for Bond { amount, .. } in &bottom_delegations {
-                total_backing += *amount;
+        if Pallet::<T>::on_chain_storage_version() != StorageVersion::new(2) {
+            return T::DbWeight::get().reads(1);
+        }
+        log::info!(
+            target: "RemoveDeprecatedStorage",
+            "running migration to remove deprecated staking storage"
+        );
+        // storage version and round
+        let (mut reads, mut writes) = (2u64, 1u64);
+        // delegators first, splitting the candidate states may kick their delegations
+        let when = <Round<T>>::get().current + T::RevokeDelegationDelay::get();
+        for (delegator, state) in
+            deprecated_values::<T, Nominator2<T::AccountId, BalanceOf<T>>>(NOMINATOR_STATE2)
+        {
+            reads += 2u64;
+            if <DelegatorState<T>>::contains_key(&delegator) {
+                continue;
             }
-            // update candidate pool with new total counted if it changed
-            if state.total_counted != total_counted && state.is_active() {
-                reads += 1u64;
-                writes += 1u64;
-                <Pallet<T>>::update_active(account.clone(), total_counted);
+            let mut delegator_state = Delegator {
+                id: delegator.clone(),
+                delegations: state.delegations,
+                total: state.total,
+                requests: PendingDelegationRequests::new(),
+                status: state.status,
+            };
+            // rounds of scheduled revocations were kept by the removed exit queue
+            for candidate in state.revocations.0 {
+                delegator_state.hotfix_set_revoke::<T>(candidate, when);
             }
-            <CandidateState<T>>::insert(
-                account,
-                CollatorCandidate {
-                    top_delegations,
-                    bottom_delegations,
-                    total_counted,
-                    total_backing,
-                    ..state
-                },
-            );
+            <DelegatorState<T>>::insert(&delegator, delegator_state);
             writes += 1u64;
         }
-        let weight = T::DbWeight::get();
-        // 20% of the max block weight as safety margin for computation
-        weight.reads(reads) + weight.writes(writes) + 100_000_000_000
+        // `CandidateState` replaced `CollatorState2`, so it's migrated first
+        let mut candidates = deprecated_values::<T, CollatorCandidate<T::AccountId, BalanceOf<T>>>(
+            CANDIDATE_STATE,
+        );
+        candidates.extend(
+            deprecated_values::<T, Collator2<T::AccountId, BalanceOf<T>>>(COLLATOR_STATE2)
+                .into_iter()
+                .map(|(candidate, state)| (candidate, state.into())),
+        );
+        for (candidate, state) in candidates {
+            reads += 2u64;
+            if <CandidateInfo<T>>::contains_key(&candidate) {
+                continue;
+            }
+            writes += split_candidate_state::<T>(candidate, state);
+        }
+        for item in [NOMINATOR_STATE2, CANDIDATE_STATE, COLLATOR_STATE2] {
+            remove_storage_prefix(pallet_prefix::<T>(), item, &[]);
+        }
+
+        STORAGE_VERSION.put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(reads, writes)
     }
+
     #[cfg(feature = "try-runtime")]
     fn pre_upgrade() -> Result<(), &'static str> {
-        // get delegation count for all candidates to check consistency
-        for (account, state) in <CandidateState<T>>::iter() {
-            // insert top + bottom into some temp map?
-            let total_delegation_count =
-                state.top_delegations.len() as u32 + state.bottom_delegations.len() as u32;
-            Self::set_temp_storage(
-                total_delegation_count,
-                &format!("Candidate{:?}DelegationCount", account)[..],
-            );
+        if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+            return Ok(());
         }
+        let mut candidates: Vec<T::AccountId> = <CandidateInfo<T>>::iter_keys().collect();
+        candidates.extend(deprecated_keys::<T>(CANDIDATE_STATE));
+        candidates.extend(deprecated_keys::<T>(COLLATOR_STATE2));
+        candidates.sort();
+        candidates.dedup();
+        let mut delegators: Vec<T::AccountId> = <DelegatorState<T>>::iter_keys().collect();
+        delegators.extend(deprecated_keys::<T>(NOMINATOR_STATE2));
+        delegators.sort();
+        delegators.dedup();
+        Self::set_temp_storage(
+            (candidates.len() as u32, delegators.len() as u32),
+            "RemoveDeprecatedStorageCounts",
+        );
         Ok(())
     }
 
     #[cfg(feature = "try-runtime")]
     fn post_upgrade() -> Result<(), &'static str> {
-        // check that top + bottom are the same as the expected (stored in temp)
-        for (account, state) in <CandidateState<T>>::iter() {
-            let expected_count: u32 =
-                Self::get_temp_storage(&format!("Candidate{:?}DelegationCount", account)[..])
-                    .expect("qed");
-            let actual_count =
-                state.top_delegations.len() as u32 + state.bottom_delegations.len() as u32;
-            assert_eq!(expected_count, actual_count);
+        let (candidate_count, delegator_count): (u32, u32) =
+            match Self::get_temp_storage("RemoveDeprecatedStorageCounts") {
+                Some(counts) => counts,
+                // migration was already executed before
+                None => return Ok(()),
+            };
+        for item in [NOMINATOR_STATE2, CANDIDATE_STATE, COLLATOR_STATE2] {
+            assert!(
+                deprecated_keys::<T>(item).is_empty(),
+                "Expected deprecated storage to be removed"
+            );
         }
+        let migrated_candidate_count = <CandidateInfo<T>>::iter_values().count() as u32;
+        assert_eq!(
+            migrated_candidate_count, candidate_count,
+            "Expected {} for `CandidateInfo` count, Found: {}",
+            candidate_count, migrated_candidate_count
+        );
+        // delegators leave if their last delegation is kicked from full bottom delegations
+        let migrated_delegator_count = <DelegatorState<T>>::iter_values().count() as u32;
+        assert!(
+            migrated_delegator_count <= delegator_count,
+            "Expected at most {} for `DelegatorState` count, Found: {}",
+            delegator_count,
+            migrated_delegator_count
+        );
+        for (candidate, info) in <CandidateInfo<T>>::iter() {
+            let count = |delegations: Option<Delegations<T::AccountId, BalanceOf<T>>>| {
+                delegations.map_or(0, |delegations| delegations.delegations.len() as u32)
+            };
+            assert_eq!(
+                info.delegation_count,
+                count(<TopDelegations<T>>::get(&candidate))
+                    + count(<BottomDelegations<T>>::get(&candidate)),
+                "Expected `delegation_count` to count top and bottom delegations"
+            );
+        }
+        assert_eq!(Pallet::<T>::on_chain_storage_version(), STORAGE_VERSION);
         Ok(())
     }
 }
 
-/// Migration to replace the automatic ExitQueue with a manual exits API.
-/// This migration is idempotent so it can be run more than once without any risk.
-// pub struct RemoveExitQueue<T>(PhantomData<T>);
-// impl<T: Config> OnRuntimeUpgrade for RemoveExitQueue<T> {
-// 	fn on_runtime_upgrade() -> Weight {
-// 		log::info!(target: "RemoveExitQueue", "running migration to remove staking exit queue");
-// 		let exit_queue = <ExitQueue2<T>>::take();
-// 		let (mut reads, mut writes) = (1u64, 0u64);
-// 		let mut delegator_exits: BTreeMap<T::AccountId, RoundIndex> = BTreeMap::new();
-// 		let mut delegation_revocations: BTreeMap<T::AccountId, (T::AccountId, RoundIndex)> =
-// 			BTreeMap::new();
-// 		// Track scheduled delegator exits and revocations before migrating state
-// 		// Candidates already track exit info locally so no tracking is necessary
-// 		for (delegator, is_revocation, when) in exit_queue.nominator_schedule {
-// 			if let Some(revoking_candidate) = is_revocation {
-// 				delegation_revocations.insert(delegator, (revoking_candidate, when));
-// 			} else {
-// 				delegator_exits.insert(delegator, when);
-// 			}
-// 		}
-// 		// execute candidate migration
-// 		for (candidate_id, collator_state) in <CollatorState2<T>>::drain() {
-// 			let candidate_state: CollatorCandidate<T::AccountId, BalanceOf<T>> =
-// 				collator_state.into();
-// 			<CandidateState<T>>::insert(candidate_id, candidate_state);
-// 			reads += 1u64;
-// 			writes += 1u64;
-// 		}
-// 		// execute delegator migration
-// 		for (delegator_id, nominator_state) in <NominatorState2<T>>::drain() {
-// 			let mut delegator_state =
-// 				migrate_nominator_to_delegator_state::<T>(delegator_id.clone(), nominator_state);
-// 			// add exit if it exists
-// 			if let Some(when) = delegator_exits.get(&delegator_id) {
-// 				delegator_state.set_leaving(*when);
-// 			}
-// 			// add revocation if exists
-// 			if let Some((candidate, when)) = delegation_revocations.get(&delegator_id) {
-// 				delegator_state.hotfix_set_revoke::<T>(candidate.clone(), *when);
-// 			}
-// 			<DelegatorState<T>>::insert(delegator_id, delegator_state);
-// 			reads += 1u64;
-// 			writes += 1u64;
-// 		}
-// 		let db_weight = T::DbWeight::get();
-// 		if reads > 1u64 {
-// 			// 50% of the max block weight as safety margin for computation
-// 			db_weight.reads(reads) + db_weight.writes(writes) + 250_000_000_000
-// 		} else {
-// 			// migration was already executed before
-// 			db_weight.reads(reads)
-// 		}
-// 	}
-
-// 	#[cfg(feature = "try-runtime")]
-// 	fn pre_upgrade() -> Result<(), &'static str> {
-// 		use frame_support::storage::migration::storage_iter;
-
-// 		let pallet_prefix: &[u8] = b"ParachainStaking";
-// 		let collator_state_prefix: &[u8] = b"CollatorState2";
-// 		let nominator_state_prefix: &[u8] = b"NominatorState2";
-
-// 		// Assert new storage is empty
-// 		assert!(CandidateState::<T>::iter().next().is_none());
-// 		assert!(DelegatorState::<T>::iter().next().is_none());
-
-// 		// Check number of old collator candidates, and set it aside in temp storage
-// 		let old_collator_count = storage_iter::<Collator2<T::AccountId, BalanceOf<T>>>(
-// 			pallet_prefix,
-// 			collator_state_prefix,
-// 		)
-// 		.count() as u64;
-// 		Self::set_temp_storage(old_collator_count, "old_collator_count");
-
-// 		// Read first old candidate from old storage and set it aside in temp storage
-// 		if old_collator_count > 0 {
-// 			let example_collator = storage_key_iter::<
-// 				T::AccountId,
-// 				Collator2<T::AccountId, BalanceOf<T>>,
-// 				Twox64Concat,
-// 			>(pallet_prefix, collator_state_prefix)
-// 			.next()
-// 			.expect("We already confirmed that there was at least one item stored");
-
-// 			Self::set_temp_storage(example_collator, "example_collator");
-// 		}
-
-// 		// Check number of new delegators, and set it aside in temp storage
-// 		let old_nominator_count = storage_iter::<Nominator2<T::AccountId, BalanceOf<T>>>(
-// 			pallet_prefix,
-// 			nominator_state_prefix,
-// 		)
-// 		.count() as u64;
-// 		Self::set_temp_storage(old_nominator_count, "old_nominator_count");
-
-// 		// Read first new delegator from old storage and set it aside in temp storage
-// 		if old_nominator_count > 0 {
-// 			let example_nominator = storage_key_iter::<
-// 				T::AccountId,
-// 				Nominator2<T::AccountId, BalanceOf<T>>,
-// 				Twox64Concat,
-// 			>(pallet_prefix, nominator_state_prefix)
-// 			.next()
-// 			.expect("We already confirmed that there was at least one item stored");
-
-// 			Self::set_temp_storage(example_nominator, "example_nominator");
-// 		}
-// 		Ok(())
-// 	}
-
-// 	#[cfg(feature = "try-runtime")]
-// 	fn post_upgrade() -> Result<(), &'static str> {
-// 		// Check number of candidates matches what was set aside in pre_upgrade
-// 		let old_candidate_count: u64 = Self::get_temp_storage("old_collator_count")
-// 			.expect("We stored the old collator candidate count so it should be there");
-// 		let new_candidate_count = CandidateState::<T>::iter().count() as u64;
-// 		assert_eq!(old_candidate_count, new_candidate_count);
-
-// 		// Check that our example candidate is converted correctly
-// 		if new_candidate_count > 0 {
-// 			let (account, original_collator_state): (
-// 				T::AccountId,
-// 				Collator2<T::AccountId, BalanceOf<T>>,
-// 			) = Self::get_temp_storage("example_collator").expect("qed");
-// 			let new_candidate_state = CandidateState::<T>::get(account).expect("qed");
-// 			let old_candidate_converted: CollatorCandidate<T::AccountId, BalanceOf<T>> =
-// 				original_collator_state.into();
-// 			assert_eq!(new_candidate_state, old_candidate_converted);
-// 		}
-
-// 		// Check number of delegators matches what was set aside in pre_upgrade
-// 		let old_nominator_count: u64 = Self::get_temp_storage("old_nominator_count")
-// 			.expect("We stored the old nominator count so it should be there");
-// 		let new_delegator_count = DelegatorState::<T>::iter().count() as u64;
-// 		assert_eq!(old_nominator_count, new_delegator_count);
-
-// 		// Check that our example delegator is converted correctly
-// 		if new_delegator_count > 0 {
-// 			let (account, original_delegator_state): (
-// 				T::AccountId,
-// 				Nominator2<T::AccountId, BalanceOf<T>>,
-// 			) = Self::get_temp_storage("example_nominator").expect("qed");
-// 			let new_delegator_state = DelegatorState::<T>::get(&account).expect("qed");
-// 			let old_delegator_converted: Delegator<T::AccountId, BalanceOf<T>> =
-// 				migrate_nominator_to_delegator_state::<T>(account, original_delegator_state);
-// 			assert_eq!(old_delegator_converted, new_delegator_state);
-// 		}
-// 		Ok(())
-// 	}
-// }
+/// Split the deprecated state of `candidate` into `CandidateInfo`, `TopDelegations` and
+/// `BottomDelegations`, kicking bottom delegations above `MaxBottomDelegationsPerCandidate`.
+/// This assumes Config::MaxTopDelegationsPerCandidate == OldConfig::MaxDelegatorsPerCandidate.
+/// Returns the number of writes.
+fn split_candidate_state<T: Config>(
+    account: T::AccountId,
+    state: CollatorCandidate<T::AccountId, BalanceOf<T>>,
+) -> u64 {
+    let mut writes = 3u64;
+    // all delegations are stored greatest to least post migration
+    // but bottom delegations were least to greatest pre migration
+    let new_bottom_delegations: Vec<Bond<T::AccountId, BalanceOf<T>>> =
+        if state.bottom_delegations.len() > T::MaxBottomDelegationsPerCandidate::get() as usize {
+            // if actual length > max bottom delegations, revoke the bottom actual - max
+            let rest = state.bottom_delegations.len()
+                - T::MaxBottomDelegationsPerCandidate::get() as usize;
+            let mut total_less = BalanceOf::<T>::zero();
+            state
+                .bottom_delegations
+                .iter()
+                .take(rest)
+                .for_each(|Bond { owner, amount }| {
+                    total_less += *amount;
+                    writes += 2u64;
+                    // update delegator state
+                    // unreserve kicked bottom
+                    T::Currency::unreserve(&owner, *amount);
+                    let mut delegator_state = match <DelegatorState<T>>::get(&owner) {
+                        Some(state) => state,
+                        None => {
+                            log::error!(
+                                target: "RemoveDeprecatedStorage",
+                                "kicked bottom delegation of {:?} without delegator state",
+                                owner
+                            );
+                            return;
+                        }
+                    };
+                    let leaving = delegator_state.delegations.0.len() == 1usize;
+                    delegator_state.rm_delegation(&account);
+                    Pallet::<T>::deposit_event(Event::DelegationKicked(
+                        owner.clone(),
+                        account.clone(),
+                        *amount,
+                    ));
+                    if leaving {
+                        <DelegatorState<T>>::remove(&owner);
+                        Pallet::<T>::deposit_event(Event::DelegatorLeft(owner.clone(), *amount));
+                    } else {
+                        <DelegatorState<T>>::insert(&owner, delegator_state);
+                    }
+                });
+            let new_total = <Total<T>>::get() - total_less;
+            <Total<T>>::put(new_total);
+            state
+                .bottom_delegations
+                .into_iter()
+                .rev()
+                .take(T::MaxBottomDelegationsPerCandidate::get() as usize)
+                .collect()
+        } else {
+            state.bottom_delegations.into_iter().rev().collect()
+        };
+    let lowest_top_delegation_amount = if state.top_delegations.is_empty() {
+        BalanceOf::<T>::zero()
+    } else {
+        state.top_delegations[state.top_delegations.len() - 1].amount
+    };
+    let highest_bottom_delegation_amount = if new_bottom_delegations.is_empty() {
+        BalanceOf::<T>::zero()
+    } else {
+        new_bottom_delegations[0].amount
+    };
+    let lowest_bottom_delegation_amount = if new_bottom_delegations.is_empty() {
+        BalanceOf::<T>::zero()
+    } else {
+        new_bottom_delegations[new_bottom_delegations.len() - 1].amount
+    };
+    let top_capacity = match &state.top_delegations {
+        x if x.len() as u32 >= T::MaxTopDelegationsPerCandidate::get() => CapacityStatus::Full,
+        x if x.is_empty() => CapacityStatus::Empty,
+        _ => CapacityStatus::Partial,
+    };
+    let bottom_capacity = match &new_bottom_delegations {
+        x if x.len() as u32 >= T::MaxBottomDelegationsPerCandidate::get() => CapacityStatus::Full,
+        x if x.is_empty() => CapacityStatus::Empty,
+        _ => CapacityStatus::Partial,
+    };
+    let metadata = CandidateMetadata {
+        bond: state.bond,
+        delegation_count: state.top_delegations.len() as u32
+            + new_bottom_delegations.len() as u32,
+        total_counted: state.total_counted,
+        lowest_top_delegation_amount,
+        highest_bottom_delegation_amount,
+        lowest_bottom_delegation_amount,
+        top_capacity,
+        bottom_capacity,
+        request: state.request,
+        status: state.state,
+    };
+    <CandidateInfo<T>>::insert(&account, metadata);
+    let top_delegations = Delegations {
+        total: state.total_counted - state.bond,
+        delegations: state.top_delegations,
+    };
+    <TopDelegations<T>>::insert(&account, top_delegations);
+    let bottom_delegations = Delegations {
+        total: new_bottom_delegations
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |acc, b| acc + b.amount),
+        delegations: new_bottom_delegations,
+    };
+    <BottomDelegations<T>>::insert(&account, bottom_delegations);
+    writes
+}
+
+fn pallet_prefix<T: Config>() -> &'static [u8] {
+    <Pallet<T> as PalletInfoAccess>::name().as_bytes()
+}
+
+/// Values of a deprecated storage map of accounts, which isn't declared by the pallet anymore
+fn deprecated_values<T: Config, V: Decode>(item: &[u8]) -> Vec<(T::AccountId, V)> {
+    storage_key_iter::<T::AccountId, V, Twox64Concat>(pallet_prefix::<T>(), item).collect()
+}
+
+/// Keys of a deprecated storage map of accounts, values may not be decodable with current types
+#[cfg(feature = "try-runtime")]
+fn deprecated_keys<T: Config>(item: &[u8]) -> Vec<T::AccountId> {
+    deprecated_values::<T, ()>(item)
+        .into_iter()
+        .map(|(account, _)| account)
+        .collect()
+}
+
+/// Translate the values of a deprecated storage map of accounts, returns the number translated
+fn translate_deprecated<T: Config, O: Decode, N: Encode>(
+    item: &[u8],
+    f: impl Fn(O) -> N,
+) -> Weight {
+    let values = deprecated_values::<T, O>(item);
+    let translated = values.len() as Weight;
+    for (account, value) in values {
+        let key = Twox64Concat::hash(&account.encode());
+        put_storage_value(pallet_prefix::<T>(), item, &key, f(value));
+    }
+    translated
+}
+
+/// Whether the value at `key` is missing or can be decoded as `V`
+fn decodes<V: Decode>(key: &[u8]) -> bool {
+    unhashed::get_raw(key).map_or(true, |raw| V::decode_all(&mut &raw[..]).is_ok())
+}
 
 /// Migration to purge staking storage bloat for `Points` and `AtStake` storage items
 pub struct PurgeStaleStorage<T>(PhantomData<T>);
@@ -495,3 +458,358 @@ impl<T: Config> OnRuntimeUpgrade for PurgeStaleStorage<T> {
         Ok(())
     }
 }
+
+/// `CollatorSnapshot` as stored before the collator commission was snapshotted with it
+#[derive(Encode, Decode, RuntimeDebug)]
+pub struct OldCollatorSnapshot<AccountId, Balance> {
+    pub bond: Balance,
+    pub delegations: Vec<Bond<AccountId, Balance>>,
+    pub total: Balance,
+}
+
+/// Migration to widen all staking amounts from `OldBalance` to `BalanceOf<T>`,
+/// e.g. after the runtime `Balance` type changed from `u64` to `u128`.
+/// Storage is expected to be encoded with `OldBalance` until the storage version is bumped to 1.
+/// `AtStake` snapshots are also given the current collator commission, as done by
+/// `SnapshotCollatorCommission`.
+/// Nothing is migrated and the storage version is kept when `Total`, `CandidatePool` or
+/// `InflationConfig` can't be decoded with `OldBalance`.
+pub struct MigrateBalanceType<T, OldBalance>(PhantomData<(T, OldBalance)>);
+impl<T, OldBalance> MigrateBalanceType<T, OldBalance>
+where
+    T: Config,
+    OldBalance: Decode + Into<BalanceOf<T>>,
+{
+    /// Name of the first storage value which can't be decoded with `OldBalance`
+    fn undecodable_value() -> Option<&'static str> {
+        if !decodes::<OldBalance>(&<Total<T>>::hashed_key()) {
+            Some("Total")
+        } else if !decodes::<OrderedSet<Bond<T::AccountId, OldBalance>>>(
+            &<CandidatePool<T>>::hashed_key(),
+        ) {
+            Some("CandidatePool")
+        } else if !decodes::<InflationInfo<OldBalance>>(&<InflationConfig<T>>::hashed_key()) {
+            Some("InflationConfig")
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, OldBalance> OnRuntimeUpgrade for MigrateBalanceType<T, OldBalance>
+where
+    T: Config,
+    OldBalance: Decode + Into<BalanceOf<T>>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(1) {
+            return T::DbWeight::get().reads(1);
+        }
+        if let Some(value) = Self::undecodable_value() {
+            log::error!(
+                target: "MigrateBalanceType",
+                "failed to decode `{}`, staking balances are not migrated",
+                value
+            );
+            return T::DbWeight::get().reads(4);
+        }
+        log::info!(target: "MigrateBalanceType", "running migration to widen staking balances");
+
+        // storage version and 3 storage values, which are checked to be decodable above
+        let mut migrated: Weight = 4;
+        let _ = <Total<T>>::translate(|total: Option<OldBalance>| total.map(Into::into));
+        let _ = <CandidatePool<T>>::translate(
+            |pool: Option<OrderedSet<Bond<T::AccountId, OldBalance>>>| {
+                pool.map(|pool| OrderedSet(migrate_bonds(pool.0)))
+            },
+        );
+        let _ = <InflationConfig<T>>::translate(|config: Option<InflationInfo<OldBalance>>| {
+            config.map(migrate_inflation_info)
+        });
+
+        <Staked<T>>::translate(|_, staked: OldBalance| {
+            migrated += 1;
+            Some(staked.into())
+        });
+        <DelayedPayouts<T>>::translate(|_, payout: DelayedPayout<OldBalance>| {
+            migrated += 1;
+            Some(DelayedPayout {
+                round_issuance: payout.round_issuance.into(),
+                total_staking_reward: payout.total_staking_reward.into(),
+                collator_commission: payout.collator_commission,
+            })
+        });
+        let commission = Pallet::<T>::collator_commission();
+        <AtStake<T>>::translate(
+            |_, _, snapshot: OldCollatorSnapshot<T::AccountId, OldBalance>| {
+                migrated += 1;
+                Some(CollatorSnapshot {
+                    bond: snapshot.bond.into(),
+                    delegations: migrate_bonds(snapshot.delegations),
+                    total: snapshot.total.into(),
+                    commission,
+                })
+            },
+        );
+        <CandidateInfo<T>>::translate(|_, info: CandidateMetadata<OldBalance>| {
+            migrated += 1;
+            Some(migrate_candidate_metadata(info))
+        });
+        <TopDelegations<T>>::translate(
+            |_, delegations: Delegations<T::AccountId, OldBalance>| {
+                migrated += 1;
+                Some(migrate_delegations(delegations))
+            },
+        );
+        <BottomDelegations<T>>::translate(
+            |_, delegations: Delegations<T::AccountId, OldBalance>| {
+                migrated += 1;
+                Some(migrate_delegations(delegations))
+            },
+        );
+        <DelegatorState<T>>::translate(|_, state: Delegator<T::AccountId, OldBalance>| {
+            migrated += 1;
+            Some(migrate_delegator(state))
+        });
+        // deprecated storage items, moved to the current ones by `RemoveDeprecatedStorage`
+        migrated += translate_deprecated::<T, _, _>(
+            NOMINATOR_STATE2,
+            |state: Nominator2<T::AccountId, OldBalance>| Nominator2::<_, BalanceOf<T>> {
+                delegations: OrderedSet(migrate_bonds(state.delegations.0)),
+                revocations: state.revocations,
+                total: state.total.into(),
+                scheduled_revocations_count: state.scheduled_revocations_count,
+                scheduled_revocations_total: state.scheduled_revocations_total.into(),
+                status: state.status,
+            },
+        );
+        migrated += translate_deprecated::<T, _, _>(
+            CANDIDATE_STATE,
+            |state: CollatorCandidate<T::AccountId, OldBalance>| CollatorCandidate::<
+                _,
+                BalanceOf<T>,
+            > {
+                id: state.id,
+                bond: state.bond.into(),
+                delegators: state.delegators,
+                top_delegations: migrate_bonds(state.top_delegations),
+                bottom_delegations: migrate_bonds(state.bottom_delegations),
+                total_counted: state.total_counted.into(),
+                total_backing: state.total_backing.into(),
+                request: state.request.map(migrate_bond_less_request),
+                state: state.state,
+            },
+        );
+        migrated += translate_deprecated::<T, _, _>(
+            COLLATOR_STATE2,
+            |state: Collator2<T::AccountId, OldBalance>| Collator2::<_, BalanceOf<T>> {
+                id: state.id,
+                bond: state.bond.into(),
+                nominators: state.nominators,
+                top_nominators: migrate_bonds(state.top_nominators),
+                bottom_nominators: migrate_bonds(state.bottom_nominators),
+                total_counted: state.total_counted.into(),
+                total_backing: state.total_backing.into(),
+                state: state.state,
+            },
+        );
+
+        // collator commission is snapshotted too, as done by `SnapshotCollatorCommission`
+        StorageVersion::new(2).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(migrated, migrated)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<(), &'static str> {
+        if Pallet::<T>::on_chain_storage_version() < StorageVersion::new(1) {
+            if Self::undecodable_value().is_some() {
+                return Err("Staking storage values can't be decoded with the old balance type");
+            }
+            Self::set_temp_storage(true, "MigrateBalanceTypeRuns");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        if Self::get_temp_storage::<bool>("MigrateBalanceTypeRuns").is_none() {
+            // migration was already executed before
+            return Ok(());
+        }
+        // values which can't be decoded with the new balance type are skipped by `iter_values`
+        assert!(
+            <Total<T>>::try_get().is_ok(),
+            "Expected `Total` to be migrated"
+        );
+        assert!(
+            <CandidatePool<T>>::try_get().is_ok(),
+            "Expected `CandidatePool` to be migrated"
+        );
+        assert!(
+            <InflationConfig<T>>::try_get().is_ok(),
+            "Expected `InflationConfig` to be migrated"
+        );
+        assert_eq!(
+            <CandidateInfo<T>>::iter_keys().count(),
+            <CandidateInfo<T>>::iter_values().count(),
+            "Expected all `CandidateInfo` to be migrated"
+        );
+        assert_eq!(
+            <TopDelegations<T>>::iter_keys().count(),
+            <TopDelegations<T>>::iter_values().count(),
+            "Expected all `TopDelegations` to be migrated"
+        );
+        assert_eq!(
+            <BottomDelegations<T>>::iter_keys().count(),
+            <BottomDelegations<T>>::iter_values().count(),
+            "Expected all `BottomDelegations` to be migrated"
+        );
+        assert_eq!(
+            <DelegatorState<T>>::iter_keys().count(),
+            <DelegatorState<T>>::iter_values().count(),
+            "Expected all `DelegatorState` to be migrated"
+        );
+        assert_eq!(
+            <AtStake<T>>::iter_keys().count(),
+            <AtStake<T>>::iter_values().count(),
+            "Expected all `AtStake` to be migrated"
+        );
+        assert!(Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(2));
+        Ok(())
+    }
+}
+
+/// Migration to snapshot the collator commission in `AtStake`, which is done for
+/// storage version 1. Snapshots pending payout are given the current `CollatorCommission`.
+pub struct SnapshotCollatorCommission<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for SnapshotCollatorCommission<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() != StorageVersion::new(1) {
+            return T::DbWeight::get().reads(1);
+        }
+        log::info!(
+            target: "SnapshotCollatorCommission",
+            "running migration to snapshot collator commission"
+        );
+        // storage version and collator commission
+        let mut migrated: Weight = 2;
+        let commission = Pallet::<T>::collator_commission();
+        <AtStake<T>>::translate(
+            |_, _, snapshot: OldCollatorSnapshot<T::AccountId, BalanceOf<T>>| {
+                migrated += 1;
+                Some(CollatorSnapshot {
+                    bond: snapshot.bond,
+                    delegations: snapshot.delegations,
+                    total: snapshot.total,
+                    commission,
+                })
+            },
+        );
+        StorageVersion::new(2).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(migrated, migrated)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<(), &'static str> {
+        if Pallet::<T>::on_chain_storage_version() == StorageVersion::new(1) {
+            Self::set_temp_storage(true, "SnapshotCollatorCommissionRuns");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        if Self::get_temp_storage::<bool>("SnapshotCollatorCommissionRuns").is_none() {
+            // migration was already executed before
+            return Ok(());
+        }
+        // snapshots which can't be decoded with the commission are skipped by `iter_values`
+        assert_eq!(
+            <AtStake<T>>::iter_keys().count(),
+            <AtStake<T>>::iter_values().count(),
+            "Expected all `AtStake` to be migrated"
+        );
+        assert!(Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(2));
+        Ok(())
+    }
+}
+
+fn migrate_bonds<A, O: Into<N>, N>(bonds: Vec<Bond<A, O>>) -> Vec<Bond<A, N>> {
+    bonds
+        .into_iter()
+        .map(|bond| Bond {
+            owner: bond.owner,
+            amount: bond.amount.into(),
+        })
+        .collect()
+}
+
+fn migrate_delegations<A, O: Into<N>, N>(delegations: Delegations<A, O>) -> Delegations<A, N> {
+    Delegations {
+        delegations: migrate_bonds(delegations.delegations),
+        total: delegations.total.into(),
+    }
+}
+
+fn migrate_bond_less_request<O: Into<N>, N>(
+    request: CandidateBondLessRequest<O>,
+) -> CandidateBondLessRequest<N> {
+    CandidateBondLessRequest {
+        amount: request.amount.into(),
+        when_executable: request.when_executable,
+    }
+}
+
+fn migrate_candidate_metadata<O: Into<N>, N>(info: CandidateMetadata<O>) -> CandidateMetadata<N> {
+    CandidateMetadata {
+        bond: info.bond.into(),
+        delegation_count: info.delegation_count,
+        total_counted: info.total_counted.into(),
+        lowest_top_delegation_amount: info.lowest_top_delegation_amount.into(),
+        highest_bottom_delegation_amount: info.highest_bottom_delegation_amount.into(),
+        lowest_bottom_delegation_amount: info.lowest_bottom_delegation_amount.into(),
+        top_capacity: info.top_capacity,
+        bottom_capacity: info.bottom_capacity,
+        request: info.request.map(migrate_bond_less_request),
+        status: info.status,
+    }
+}
+
+fn migrate_delegator<A: Ord, O: Into<N>, N>(state: Delegator<A, O>) -> Delegator<A, N> {
+    Delegator {
+        id: state.id,
+        delegations: OrderedSet(migrate_bonds(state.delegations.0)),
+        total: state.total.into(),
+        requests: PendingDelegationRequests {
+            revocations_count: state.requests.revocations_count,
+            requests: state
+                .requests
+                .requests
+                .into_iter()
+                .map(|(collator, request)| {
+                    let request = DelegationRequest {
+                        collator: request.collator,
+                        amount: request.amount.into(),
+                        when_executable: request.when_executable,
+                        action: request.action,
+                    };
+                    (collator, request)
+                })
+                .collect(),
+            less_total: state.requests.less_total.into(),
+        },
+        status: state.status,
+    }
+}
+
+fn migrate_inflation_info<O: Into<N>, N>(info: InflationInfo<O>) -> InflationInfo<N> {
+    InflationInfo {
+        expect: Range {
+            min: info.expect.min.into(),
+            ideal: info.expect.ideal.into(),
+            max: info.expect.max.into(),
+        },
+        annual: info.annual,
+        round: info.round,
+    }
+}
@@ -16,7 +16,7 @@
 
 //! Test utilities
 use crate as parachain_staking;
-use crate::{pallet, AwardedPts, Config, InflationInfo, Points, Range};
+use crate::{pallet, AwardedPts, Config, InflationInfo, Points, Range, RoundAuthoringStats};
 use frame_support::{
     construct_runtime, parameter_types,
     traits::{Everything, GenesisBuild, OnFinalize, OnInitialize},
@@ -46,6 +46,7 @@ construct_runtime!(
     {
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Timestamp: pallet_timestamp::{Pallet, Call, Storage},
         ParachainStaking: parachain_staking::{Pallet, Call, Storage, Config<T>, Event<T>},
     }
 );
@@ -97,15 +98,32 @@ impl pallet_balances::Config for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const MinimumPeriod: u64 = 1000;
+}
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
 parameter_types! {
     pub const MinBlocksPerRound: u32 = 3;
     pub const DefaultBlocksPerRound: u32 = 5;
+    pub const DefaultBlockTime: u64 = 12_000;
+    pub static MockBlockTime: u64 = 12_000;
     pub const LeaveCandidatesDelay: u32 = 2;
     pub const CandidateBondLessDelay: u32 = 2;
+    pub const CandidateCommissionDelay: u32 = 2;
     pub const LeaveDelegatorsDelay: u32 = 2;
     pub const RevokeDelegationDelay: u32 = 2;
     pub const DelegationBondLessDelay: u32 = 2;
     pub const RewardPaymentDelay: u32 = 2;
+    pub const MissedSlotsThreshold: Percent = Percent::from_percent(50);
+    pub static MaxMissedRounds: u32 = 0;
+    pub const DowntimeSlashFraction: Perbill = Perbill::from_percent(10);
+    pub static DelegatorDowntimeSlashFraction: Perbill = Perbill::from_percent(10);
+    pub static SlashDeferDuration: u32 = 2;
     pub const MinSelectedCandidates: u32 = 5;
     pub const MaxTopDelegationsPerCandidate: u32 = 4;
     pub const MaxBottomDelegationsPerCandidate: u32 = 4;
@@ -115,6 +133,7 @@ parameter_types! {
     pub const MinCollatorStk: u128 = 10;
     pub const MinDelegatorStk: u128 = 5;
     pub const MinDelegation: u128 = 3;
+    pub const TreasuryAccount: AccountId = 100;
 }
 impl Config for Test {
     type Event = Event;
@@ -122,12 +141,20 @@ impl Config for Test {
     type MonetaryGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
     type MinBlocksPerRound = MinBlocksPerRound;
     type DefaultBlocksPerRound = DefaultBlocksPerRound;
+    type DefaultBlockTime = DefaultBlockTime;
+    type UnixTime = Timestamp;
     type LeaveCandidatesDelay = LeaveCandidatesDelay;
     type CandidateBondLessDelay = CandidateBondLessDelay;
+    type CandidateCommissionDelay = CandidateCommissionDelay;
     type LeaveDelegatorsDelay = LeaveDelegatorsDelay;
     type RevokeDelegationDelay = RevokeDelegationDelay;
     type DelegationBondLessDelay = DelegationBondLessDelay;
     type RewardPaymentDelay = RewardPaymentDelay;
+    type MissedSlotsThreshold = MissedSlotsThreshold;
+    type MaxMissedRounds = MaxMissedRounds;
+    type DowntimeSlashFraction = DowntimeSlashFraction;
+    type DelegatorDowntimeSlashFraction = DelegatorDowntimeSlashFraction;
+    type SlashDeferDuration = SlashDeferDuration;
     type MinSelectedCandidates = MinSelectedCandidates;
     type MaxTopDelegationsPerCandidate = MaxTopDelegationsPerCandidate;
     type MaxBottomDelegationsPerCandidate = MaxBottomDelegationsPerCandidate;
@@ -138,6 +165,7 @@ impl Config for Test {
     type MinCandidateStk = MinCollatorStk;
     type MinDelegatorStk = MinDelegatorStk;
     type MinDelegation = MinDelegation;
+    type TreasuryAccount = TreasuryAccount;
     type WeightInfo = ();
 }
 
@@ -207,6 +235,10 @@ impl ExtBuilder {
     }
 
     pub(crate) fn build(self) -> sp_io::TestExternalities {
+        MockBlockTime::set(DefaultBlockTime::get());
+        MaxMissedRounds::set(0);
+        SlashDeferDuration::set(2);
+        DelegatorDowntimeSlashFraction::set(Perbill::from_percent(10));
         let mut t = frame_system::GenesisConfig::default()
             .build_storage::<Test>()
             .expect("Frame system builds valid default genesis config");
@@ -239,6 +271,8 @@ pub(crate) fn roll_one_block() -> u64 {
     System::on_initialize(System::block_number());
     Balances::on_initialize(System::block_number());
     ParachainStaking::on_initialize(System::block_number());
+    // timestamp inherent is applied after on_initialize
+    Timestamp::set_timestamp(Timestamp::now() + MockBlockTime::get());
     System::block_number()
 }
 
@@ -382,10 +416,13 @@ macro_rules! assert_event_not_emitted {
     };
 }
 
-// Same storage changes as EventHandler::note_author impl
+// Same storage changes as EventHandler::note_author impl, 20 points per authored block
 pub(crate) fn set_author(round: u32, acc: u64, pts: u32) {
     <Points<Test>>::mutate(round, |p| *p += pts);
     <AwardedPts<Test>>::mutate(round, acc, |p| *p += pts);
+    <RoundAuthoringStats<Test>>::mutate(round, acc, |stats| {
+        stats.get_or_insert_with(Default::default).authored += pts / 20
+    });
 }
 
 #[test]
@@ -0,0 +1,103 @@
+// Copyright 2019-2022 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Helper methods for computing slashes of offline collators and their delegators
+use crate::pallet::{BalanceOf, CandidateInfo, Config, TopDelegations};
+use frame_support::traits::Get;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// Slash computed when downtime was detected, applied once its deferral ends unless cancelled
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UnappliedSlash<AccountId, Balance> {
+    /// Collator which was offline
+    pub collator: AccountId,
+    /// Amount slashed from the collator self bond
+    pub own: Balance,
+    /// Amounts slashed from the delegations counted for the collator
+    pub others: Vec<(AccountId, Balance)>,
+}
+
+impl<AccountId, Balance: Copy + Saturating + Zero> UnappliedSlash<AccountId, Balance> {
+    /// Sum of the amounts slashed from the collator and its delegators
+    pub fn total(&self) -> Balance {
+        self.others
+            .iter()
+            .fold(self.own, |total, (_, amount)| total.saturating_add(*amount))
+    }
+
+    /// Accounts slashed, the collator first
+    pub fn accounts(&self) -> impl Iterator<Item = &AccountId> {
+        sp_std::iter::once(&self.collator).chain(self.others.iter().map(|(who, _)| who))
+    }
+}
+
+/// Compute the slash of `collator` for prolonged downtime from its current bonds
+/// - `DowntimeSlashFraction` of the self bond
+/// - `DelegatorDowntimeSlashFraction` of each top delegation
+/// Returns None if the candidate doesn't exist or nothing would be slashed
+pub(crate) fn compute_downtime_slash<T: Config>(
+    collator: &T::AccountId,
+) -> Option<UnappliedSlash<T::AccountId, BalanceOf<T>>> {
+    let state = <CandidateInfo<T>>::get(collator)?;
+    let own = T::DowntimeSlashFraction::get() * state.bond;
+    let fraction = T::DelegatorDowntimeSlashFraction::get();
+    let others = if fraction.is_zero() {
+        Vec::new()
+    } else {
+        <TopDelegations<T>>::get(collator)
+            .map(|top| top.delegations)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|bond| (bond.owner, fraction * bond.amount))
+            .filter(|(_, amount)| !amount.is_zero())
+            .collect()
+    };
+    let slash = UnappliedSlash {
+        collator: collator.clone(),
+        own,
+        others,
+    };
+    if slash.total().is_zero() {
+        None
+    } else {
+        Some(slash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_sums_own_and_delegator_slashes() {
+        let slash = UnappliedSlash {
+            collator: 1u64,
+            own: 10u128,
+            others: vec![(2, 5), (3, 1)],
+        };
+        assert_eq!(slash.total(), 16);
+        let slash = UnappliedSlash::<u64, u128> {
+            collator: 1,
+            own: u128::MAX,
+            others: vec![(2, 5)],
+        };
+        assert_eq!(slash.total(), u128::MAX);
+    }
+}
@@ -23,18 +23,24 @@
 //! 4. Miscellaneous Property-Based Tests
 use crate::mock::{
     roll_one_block, roll_to, roll_to_round_begin, roll_to_round_end, set_author, Balances,
-    Event as MetaEvent, ExtBuilder, Origin, ParachainStaking, Test,
+    DelegatorDowntimeSlashFraction, Event as MetaEvent, ExtBuilder, MaxMissedRounds,
+    MockBlockTime, Origin, ParachainStaking, SlashDeferDuration, Test,
 };
 use crate::{
     assert_eq_events, assert_eq_last_events, assert_event_emitted, assert_event_not_emitted,
-    assert_last_event, assert_tail_eq, pallet::CapacityStatus, set::OrderedSet, BalanceOf, Bond,
-    BottomDelegations, CandidateInfo, CandidatePool, CandidateState, CollatorCandidate,
-    CollatorStatus, Config, DelegationChange, DelegationRequest, Delegator, DelegatorAdded,
-    DelegatorState, DelegatorStatus, Error, Event, PendingDelegationRequests, Range,
-    TopDelegations, Total,
+    assert_last_event, assert_tail_eq, pallet::CapacityStatus, set::OrderedSet, AuthoringStats,
+    AutoCompoundingDelegations, BalanceOf, Bond, BottomDelegations, CandidateInfo, CandidatePool,
+    CollatorLiveness, CollatorStatus, CommissionBounds, Config, DelegationChange,
+    DelegationRequest, Delegator, DelegatorAdded, DelegatorState, DelegationOverview,
+    DelegatorOverview, DelegatorStatus, Error, Event, InflationDecay, InflationDecaySchedule,
+    InflationInfo, PendingDelegationRequests, Range, RewardDestination, RewardDestinations,
+    StakingApr, TopDelegations, Total, UnappliedSlash,
 };
 use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
-use sp_runtime::{traits::Zero, DispatchError, Perbill, Percent, ModuleError};
+use nimbus_primitives::EventHandler;
+use sp_runtime::{
+    traits::Zero, DispatchError, FixedPointNumber, FixedU128, ModuleError, Perbill, Percent,
+};
 use sp_std::collections::btree_map::BTreeMap;
 
 // ~~ ROOT ~~
@@ -221,6 +227,71 @@ fn cannot_set_collator_commission_to_current_collator_commission() {
     });
 }
 
+// SET COMMISSION BOUNDS
+
+#[test]
+fn set_commission_bounds_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bounds = CommissionBounds {
+            min: Perbill::from_percent(5),
+            max: Perbill::from_percent(50),
+        };
+        assert_ok!(ParachainStaking::set_commission_bounds(
+            Origin::root(),
+            bounds
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::CommissionBoundsSet(
+            CommissionBounds {
+                min: Perbill::zero(),
+                max: Perbill::from_percent(70),
+            },
+            bounds,
+        )));
+        assert_eq!(ParachainStaking::candidate_commission_bounds(), bounds);
+    });
+}
+
+#[test]
+fn cannot_set_invalid_commission_bounds() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_commission_bounds(
+                Origin::signed(45),
+                ParachainStaking::candidate_commission_bounds()
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ParachainStaking::set_commission_bounds(
+                Origin::root(),
+                CommissionBounds {
+                    min: Perbill::from_percent(50),
+                    max: Perbill::from_percent(5),
+                }
+            ),
+            Error::<Test>::InvalidCommissionBounds
+        );
+        // 30% of the issuance is reserved for the parachain bond
+        assert_noop!(
+            ParachainStaking::set_commission_bounds(
+                Origin::root(),
+                CommissionBounds {
+                    min: Perbill::zero(),
+                    max: Perbill::from_percent(71),
+                }
+            ),
+            Error::<Test>::InvalidCommissionBounds
+        );
+        assert_noop!(
+            ParachainStaking::set_commission_bounds(
+                Origin::root(),
+                ParachainStaking::candidate_commission_bounds()
+            ),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
+}
+
 // SET BLOCKS PER ROUND
 
 #[test]
@@ -291,6 +362,64 @@ fn round_immediately_jumps_if_current_duration_exceeds_new_blocks_per_round() {
         });
 }
 
+// BLOCK TIME
+
+#[test]
+fn block_time_is_observed_over_round() {
+    ExtBuilder::default().build().execute_with(|| {
+        MockBlockTime::set(6_000);
+        roll_to(10);
+        // 5_259_600 blocks per year, 5 blocks per round
+        let round = crate::inflation::perbill_annual_to_perbill_round(
+            ParachainStaking::inflation_config().annual,
+            1_051_920,
+        );
+        assert_event_emitted!(Event::BlockTimeSet(
+            12_000,
+            6_000,
+            round.min,
+            round.ideal,
+            round.max
+        ));
+        assert_eq!(ParachainStaking::block_time(), 6_000);
+        assert_eq!(ParachainStaking::inflation_config().round, round);
+    });
+}
+
+#[test]
+fn block_time_drift_within_tolerance_is_ignored() {
+    ExtBuilder::default().build().execute_with(|| {
+        let round = ParachainStaking::inflation_config().round;
+        MockBlockTime::set(12_100);
+        roll_to(15);
+        assert_eq!(ParachainStaking::block_time(), 12_000);
+        assert_eq!(ParachainStaking::inflation_config().round, round);
+    });
+}
+
+#[test]
+fn set_blocks_per_round_uses_observed_block_time() {
+    ExtBuilder::default().build().execute_with(|| {
+        MockBlockTime::set(6_000);
+        roll_to(10);
+        assert_ok!(ParachainStaking::set_blocks_per_round(Origin::root(), 6u32));
+        // 5_259_600 blocks per year, 6 blocks per round
+        let round = crate::inflation::perbill_annual_to_perbill_round(
+            ParachainStaking::inflation_config().annual,
+            876_600,
+        );
+        assert_last_event!(MetaEvent::ParachainStaking(Event::BlocksPerRoundSet(
+            3,
+            10,
+            5,
+            6,
+            round.min,
+            round.ideal,
+            round.max,
+        )));
+    });
+}
+
 // ~~ MONETARY GOVERNANCE ~~
 
 #[test]
@@ -540,6 +669,181 @@ fn cannot_set_same_inflation() {
     });
 }
 
+// SET INFLATION DECAY
+
+#[test]
+fn set_inflation_decay_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_inflation_decay(
+            Origin::root(),
+            Perbill::from_percent(10),
+            Perbill::from_percent(40)
+        ));
+        // 525_960 rounds per year
+        assert_last_event!(MetaEvent::ParachainStaking(Event::InflationDecaySet(Some(
+            InflationDecay {
+                rate: Perbill::from_percent(10),
+                floor: Perbill::from_percent(40),
+                next_round: 525_961,
+            }
+        ))));
+        assert_ok!(ParachainStaking::set_inflation_decay(
+            Origin::root(),
+            Perbill::zero(),
+            Perbill::zero()
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::InflationDecaySet(None)));
+    });
+}
+
+#[test]
+fn set_inflation_decay_storage_updates_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(ParachainStaking::inflation_decay(), None);
+        assert_ok!(ParachainStaking::set_inflation_decay(
+            Origin::root(),
+            Perbill::from_percent(10),
+            Perbill::from_percent(40)
+        ));
+        roll_to(10);
+        // changing the schedule keeps the year boundary
+        assert_ok!(ParachainStaking::set_inflation_decay(
+            Origin::root(),
+            Perbill::from_percent(20),
+            Perbill::from_percent(30)
+        ));
+        assert_eq!(
+            ParachainStaking::inflation_decay(),
+            Some(InflationDecay {
+                rate: Perbill::from_percent(20),
+                floor: Perbill::from_percent(30),
+                next_round: 525_961,
+            })
+        );
+        assert_ok!(ParachainStaking::set_inflation_decay(
+            Origin::root(),
+            Perbill::zero(),
+            Perbill::zero()
+        ));
+        assert_eq!(ParachainStaking::inflation_decay(), None);
+    });
+}
+
+#[test]
+fn cannot_set_same_inflation_decay() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_inflation_decay(
+                Origin::root(),
+                Perbill::zero(),
+                Perbill::zero()
+            ),
+            Error::<Test>::NoWritingSameValue
+        );
+        assert_ok!(ParachainStaking::set_inflation_decay(
+            Origin::root(),
+            Perbill::from_percent(10),
+            Perbill::from_percent(40)
+        ));
+        assert_noop!(
+            ParachainStaking::set_inflation_decay(
+                Origin::root(),
+                Perbill::from_percent(10),
+                Perbill::from_percent(40)
+            ),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
+}
+
+#[test]
+fn cannot_set_inflation_decay_if_not_monetary_origin() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_inflation_decay(
+                Origin::signed(45),
+                Perbill::from_percent(10),
+                Perbill::from_percent(40)
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn inflation_decays_every_year_down_to_floor() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_inflation_decay(
+            Origin::root(),
+            Perbill::from_percent(10),
+            Perbill::from_percent(40)
+        ));
+        // shorten the first year to reach its end
+        <InflationDecaySchedule<Test>>::mutate(|decay| decay.as_mut().unwrap().next_round = 2);
+        roll_to(5);
+        let annual = Range {
+            min: Perbill::from_percent(45),
+            ideal: Perbill::from_percent(45),
+            max: Perbill::from_percent(45),
+        };
+        let round = crate::inflation::perbill_annual_to_perbill_round(annual, 525_960);
+        assert_event_emitted!(Event::InflationDecayed(
+            annual.min,
+            annual.ideal,
+            annual.max,
+            round.min,
+            round.ideal,
+            round.max
+        ));
+        assert_eq!(ParachainStaking::inflation_config().annual, annual);
+        assert_eq!(ParachainStaking::inflation_config().round, round);
+        assert_eq!(
+            ParachainStaking::inflation_decay().unwrap().next_round,
+            525_962
+        );
+        <InflationDecaySchedule<Test>>::mutate(|decay| decay.as_mut().unwrap().next_round = 3);
+        roll_to(10);
+        assert_eq!(
+            ParachainStaking::inflation_config().annual,
+            Range::from(Perbill::from_parts(405_000_000))
+        );
+        <InflationDecaySchedule<Test>>::mutate(|decay| decay.as_mut().unwrap().next_round = 4);
+        roll_to(15);
+        assert_eq!(
+            ParachainStaking::inflation_config().annual,
+            Range::from(Perbill::from_percent(40))
+        );
+    });
+}
+
+#[test]
+fn inflation_decay_continues_from_manual_override() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_inflation_decay(
+            Origin::root(),
+            Perbill::from_percent(10),
+            Perbill::from_percent(40)
+        ));
+        let schedule = Range {
+            min: Perbill::from_percent(30),
+            ideal: Perbill::from_percent(50),
+            max: Perbill::from_percent(60),
+        };
+        assert_ok!(ParachainStaking::set_inflation(Origin::root(), schedule));
+        <InflationDecaySchedule<Test>>::mutate(|decay| decay.as_mut().unwrap().next_round = 2);
+        roll_to(5);
+        // rates already below the floor are kept
+        assert_eq!(
+            ParachainStaking::inflation_config().annual,
+            Range {
+                min: Perbill::from_percent(30),
+                ideal: Perbill::from_percent(45),
+                max: Perbill::from_percent(54),
+            }
+        );
+    });
+}
+
 // SET PARACHAIN BOND ACCOUNT
 
 #[test]
@@ -574,12 +878,12 @@ fn set_parachain_bond_reserve_percent_event_emits_correctly() {
     ExtBuilder::default().build().execute_with(|| {
         assert_ok!(ParachainStaking::set_parachain_bond_reserve_percent(
             Origin::root(),
-            Percent::from_percent(50)
+            Percent::from_percent(20)
         ));
         assert_last_event!(MetaEvent::ParachainStaking(
             Event::ParachainBondReservePercentSet(
                 Percent::from_percent(30),
-                Percent::from_percent(50),
+                Percent::from_percent(20),
             )
         ));
     });
@@ -594,15 +898,40 @@ fn set_parachain_bond_reserve_percent_storage_updates_correctly() {
         );
         assert_ok!(ParachainStaking::set_parachain_bond_reserve_percent(
             Origin::root(),
-            Percent::from_percent(50)
+            Percent::from_percent(20)
         ));
         assert_eq!(
             ParachainStaking::parachain_bond_info().percent,
-            Percent::from_percent(50)
+            Percent::from_percent(20)
         );
     });
 }
 
+#[test]
+fn cannot_set_parachain_bond_reserve_percent_above_commission_bounds() {
+    ExtBuilder::default().build().execute_with(|| {
+        // commissions up to 70% are allowed
+        assert_noop!(
+            ParachainStaking::set_parachain_bond_reserve_percent(
+                Origin::root(),
+                Percent::from_percent(31)
+            ),
+            Error::<Test>::InvalidCommissionBounds
+        );
+        assert_ok!(ParachainStaking::set_commission_bounds(
+            Origin::root(),
+            CommissionBounds {
+                min: Perbill::zero(),
+                max: Perbill::from_percent(50),
+            }
+        ));
+        assert_ok!(ParachainStaking::set_parachain_bond_reserve_percent(
+            Origin::root(),
+            Percent::from_percent(50)
+        ));
+    });
+}
+
 #[test]
 fn cannot_set_same_parachain_bond_reserve_percent() {
     ExtBuilder::default().build().execute_with(|| {
@@ -1654,87 +1983,251 @@ fn only_candidate_can_cancel_candidate_bond_less_request() {
         });
 }
 
-// NOMINATE
+// SCHEDULE CANDIDATE COMMISSION CHANGE
 
 #[test]
-fn delegate_event_emits_correctly() {
+fn schedule_candidate_commission_change_event_emits_correctly() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30)])
         .with_candidates(vec![(1, 30)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::delegate(Origin::signed(2), 1, 10, 0, 0));
-            assert_last_event!(MetaEvent::ParachainStaking(Event::Delegation(
-                2,
-                10,
-                1,
-                DelegatorAdded::AddedToTop { new_total: 40 },
-            )));
+            assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+                Origin::signed(1),
+                Perbill::from_percent(10)
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(
+                Event::CandidateCommissionChangeScheduled(1, Perbill::from_percent(10), 3)
+            ));
         });
 }
 
 #[test]
-fn delegate_reserves_balance() {
+fn cannot_schedule_candidate_commission_change_if_not_candidate() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::schedule_candidate_commission_change(
+                Origin::signed(1),
+                Perbill::from_percent(10)
+            ),
+            Error::<Test>::CandidateDNE
+        );
+    });
+}
+
+#[test]
+fn cannot_schedule_candidate_commission_change_out_of_bounds() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30)])
         .with_candidates(vec![(1, 30)])
         .build()
         .execute_with(|| {
-            assert_eq!(Balances::reserved_balance(&2), 0);
-            assert_eq!(Balances::free_balance(&2), 10);
-            assert_ok!(ParachainStaking::delegate(Origin::signed(2), 1, 10, 0, 0));
-            assert_eq!(Balances::reserved_balance(&2), 10);
-            assert_eq!(Balances::free_balance(&2), 0);
+            assert_ok!(ParachainStaking::set_commission_bounds(
+                Origin::root(),
+                CommissionBounds {
+                    min: Perbill::from_percent(5),
+                    max: Perbill::from_percent(50),
+                }
+            ));
+            assert_noop!(
+                ParachainStaking::schedule_candidate_commission_change(
+                    Origin::signed(1),
+                    Perbill::from_percent(60)
+                ),
+                Error::<Test>::CommissionOutOfBounds
+            );
+            assert_noop!(
+                ParachainStaking::schedule_candidate_commission_change(
+                    Origin::signed(1),
+                    Perbill::from_percent(1)
+                ),
+                Error::<Test>::CommissionOutOfBounds
+            );
         });
 }
 
 #[test]
-fn delegate_updates_delegator_state() {
+fn candidate_commission_change_applies_after_delay() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30)])
         .with_candidates(vec![(1, 30)])
         .build()
         .execute_with(|| {
-            assert!(ParachainStaking::delegator_state(2).is_none());
-            assert_ok!(ParachainStaking::delegate(Origin::signed(2), 1, 10, 0, 0));
-            let delegator_state =
-                ParachainStaking::delegator_state(2).expect("just delegated => exists");
-            assert_eq!(delegator_state.total, 10);
-            assert_eq!(delegator_state.delegations.0[0].owner, 1);
-            assert_eq!(delegator_state.delegations.0[0].amount, 10);
+            assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+                Origin::signed(1),
+                Perbill::from_percent(10)
+            ));
+            roll_to(9);
+            assert_eq!(ParachainStaking::candidate_commission(1), None);
+            assert_eq!(
+                ParachainStaking::at_stake(2, 1).commission,
+                Perbill::from_percent(20)
+            );
+            roll_to(10);
+            assert_eq!(
+                ParachainStaking::candidate_commission(1),
+                Some(Perbill::from_percent(10))
+            );
+            assert!(ParachainStaking::candidate_commission_request(1).is_none());
+            assert_event_emitted!(Event::CandidateCommissionSet(
+                1,
+                Perbill::from_percent(20),
+                Perbill::from_percent(10)
+            ));
+            assert_eq!(
+                ParachainStaking::at_stake(3, 1).commission,
+                Perbill::from_percent(10)
+            );
         });
 }
 
 #[test]
-fn delegate_updates_collator_state() {
+fn replaced_candidate_commission_change_applies_in_its_own_round() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 30), (2, 10)])
+        .with_balances(vec![(1, 30)])
         .with_candidates(vec![(1, 30)])
         .build()
         .execute_with(|| {
-            let candidate_state =
-                ParachainStaking::candidate_info(1).expect("registered in genesis");
-            assert_eq!(candidate_state.total_counted, 30);
-            let top_delegations =
-                ParachainStaking::top_delegations(1).expect("registered in genesis");
-            assert!(top_delegations.delegations.is_empty());
-            assert!(top_delegations.total.is_zero());
-            assert_ok!(ParachainStaking::delegate(Origin::signed(2), 1, 10, 0, 0));
-            let candidate_state =
-                ParachainStaking::candidate_info(1).expect("just delegated => exists");
-            assert_eq!(candidate_state.total_counted, 40);
-            let top_delegations =
-                ParachainStaking::top_delegations(1).expect("just delegated => exists");
-            assert_eq!(top_delegations.delegations[0].owner, 2);
-            assert_eq!(top_delegations.delegations[0].amount, 10);
-            assert_eq!(top_delegations.total, 10);
+            assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+                Origin::signed(1),
+                Perbill::from_percent(10)
+            ));
+            assert_eq!(ParachainStaking::candidate_commission_changes(3), vec![1]);
+            roll_to(5);
+            assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+                Origin::signed(1),
+                Perbill::from_percent(30)
+            ));
+            assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+                Origin::signed(1),
+                Perbill::from_percent(30)
+            ));
+            assert_eq!(ParachainStaking::candidate_commission_changes(4), vec![1]);
+            roll_to(10);
+            assert_eq!(ParachainStaking::candidate_commission(1), None);
+            assert!(ParachainStaking::candidate_commission_changes(3).is_empty());
+            roll_to(15);
+            assert_eq!(
+                ParachainStaking::candidate_commission(1),
+                Some(Perbill::from_percent(30))
+            );
+            assert!(ParachainStaking::candidate_commission_request(1).is_none());
+            assert!(ParachainStaking::candidate_commission_changes(4).is_empty());
         });
 }
 
 #[test]
-fn can_delegate_immediately_after_other_join_candidates() {
+fn candidate_commission_is_clamped_to_bounds_in_snapshot() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 20)])
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+                Origin::signed(1),
+                Perbill::from_percent(10)
+            ));
+            roll_to(10);
+            assert_ok!(ParachainStaking::set_commission_bounds(
+                Origin::root(),
+                CommissionBounds {
+                    min: Perbill::from_percent(15),
+                    max: Perbill::from_percent(50),
+                }
+            ));
+            roll_to(15);
+            assert_eq!(
+                ParachainStaking::candidate_commission(1),
+                Some(Perbill::from_percent(10))
+            );
+            assert_eq!(
+                ParachainStaking::at_stake(4, 1).commission,
+                Perbill::from_percent(15)
+            );
+        });
+}
+
+// NOMINATE
+
+#[test]
+fn delegate_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::delegate(Origin::signed(2), 1, 10, 0, 0));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Delegation(
+                2,
+                10,
+                1,
+                DelegatorAdded::AddedToTop { new_total: 40 },
+            )));
+        });
+}
+
+#[test]
+fn delegate_reserves_balance() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(Balances::reserved_balance(&2), 0);
+            assert_eq!(Balances::free_balance(&2), 10);
+            assert_ok!(ParachainStaking::delegate(Origin::signed(2), 1, 10, 0, 0));
+            assert_eq!(Balances::reserved_balance(&2), 10);
+            assert_eq!(Balances::free_balance(&2), 0);
+        });
+}
+
+#[test]
+fn delegate_updates_delegator_state() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert!(ParachainStaking::delegator_state(2).is_none());
+            assert_ok!(ParachainStaking::delegate(Origin::signed(2), 1, 10, 0, 0));
+            let delegator_state =
+                ParachainStaking::delegator_state(2).expect("just delegated => exists");
+            assert_eq!(delegator_state.total, 10);
+            assert_eq!(delegator_state.delegations.0[0].owner, 1);
+            assert_eq!(delegator_state.delegations.0[0].amount, 10);
+        });
+}
+
+#[test]
+fn delegate_updates_collator_state() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            let candidate_state =
+                ParachainStaking::candidate_info(1).expect("registered in genesis");
+            assert_eq!(candidate_state.total_counted, 30);
+            let top_delegations =
+                ParachainStaking::top_delegations(1).expect("registered in genesis");
+            assert!(top_delegations.delegations.is_empty());
+            assert!(top_delegations.total.is_zero());
+            assert_ok!(ParachainStaking::delegate(Origin::signed(2), 1, 10, 0, 0));
+            let candidate_state =
+                ParachainStaking::candidate_info(1).expect("just delegated => exists");
+            assert_eq!(candidate_state.total_counted, 40);
+            let top_delegations =
+                ParachainStaking::top_delegations(1).expect("just delegated => exists");
+            assert_eq!(top_delegations.delegations[0].owner, 2);
+            assert_eq!(top_delegations.delegations[0].amount, 10);
+            assert_eq!(top_delegations.total, 10);
+        });
+}
+
+#[test]
+fn can_delegate_immediately_after_other_join_candidates() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20)])
         .build()
         .execute_with(|| {
             assert_ok!(ParachainStaking::join_candidates(Origin::signed(1), 20, 0));
@@ -3526,88 +4019,999 @@ fn cancel_delegator_bond_less_updates_delegator_state() {
         });
 }
 
-// ~~ PROPERTY-BASED TESTS ~~
+// SET AUTO COMPOUND
 
 #[test]
-fn delegator_schedule_revocation_total() {
+fn set_auto_compound_event_emits_correctly() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20), (5, 20)])
-        .with_candidates(vec![(1, 20), (3, 20), (4, 20), (5, 20)])
-        .with_delegations(vec![(2, 1, 10), (2, 3, 10), (2, 4, 10)])
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+            assert_ok!(ParachainStaking::set_auto_compound(
                 Origin::signed(2),
-                1
+                1,
+                Percent::from_percent(50)
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::AutoCompoundSet(
+                2,
+                1,
+                Percent::from_percent(50)
+            )));
+        });
+}
+
+#[test]
+fn set_auto_compound_updates_storage_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(ParachainStaking::auto_compound(2, 1), Percent::zero());
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50)
             ));
             assert_eq!(
-                ParachainStaking::delegator_state(2)
-                    .expect("exists")
-                    .requests
-                    .less_total,
-                10
+                ParachainStaking::auto_compound(2, 1),
+                Percent::from_percent(50)
             );
-            roll_to(10);
-            assert_ok!(ParachainStaking::execute_delegation_request(
+            assert_ok!(ParachainStaking::set_auto_compound(
                 Origin::signed(2),
-                2,
-                1
+                1,
+                Percent::zero()
             ));
-            assert_eq!(
-                ParachainStaking::delegator_state(2)
-                    .expect("exists")
-                    .requests
-                    .less_total,
-                0
+            assert!(!AutoCompoundingDelegations::<Test>::contains_key(2, 1));
+        });
+}
+
+#[test]
+fn cannot_set_auto_compound_if_delegation_dne() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    Origin::signed(4),
+                    1,
+                    Percent::from_percent(50)
+                ),
+                Error::<Test>::DelegatorDNE
             );
-            assert_ok!(ParachainStaking::delegate(Origin::signed(2), 5, 10, 0, 2));
-            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    Origin::signed(2),
+                    3,
+                    Percent::from_percent(50)
+                ),
+                Error::<Test>::DelegationDNE
+            );
+        });
+}
+
+#[test]
+fn cannot_set_same_auto_compound() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_auto_compound(Origin::signed(2), 1, Percent::zero()),
+                Error::<Test>::NoWritingSameValue
+            );
+            assert_ok!(ParachainStaking::set_auto_compound(
                 Origin::signed(2),
-                3
+                1,
+                Percent::from_percent(50)
+            ));
+            assert_noop!(
+                ParachainStaking::set_auto_compound(
+                    Origin::signed(2),
+                    1,
+                    Percent::from_percent(50)
+                ),
+                Error::<Test>::NoWritingSameValue
+            );
+        });
+}
+
+#[test]
+fn execute_revoke_delegation_removes_auto_compound() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 20), (3, 30)])
+        .with_candidates(vec![(1, 30), (3, 30)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50)
             ));
             assert_ok!(ParachainStaking::schedule_revoke_delegation(
                 Origin::signed(2),
-                4
+                1
             ));
-            assert_eq!(
-                ParachainStaking::delegator_state(2)
-                    .expect("exists")
-                    .requests
-                    .less_total,
-                20
-            );
-            roll_to(20);
+            roll_to(10);
             assert_ok!(ParachainStaking::execute_delegation_request(
                 Origin::signed(2),
                 2,
-                3
+                1
             ));
-            assert_eq!(
-                ParachainStaking::delegator_state(2)
-                    .expect("exists")
-                    .requests
-                    .less_total,
-                10
-            );
-            assert_ok!(ParachainStaking::execute_delegation_request(
+            assert!(!AutoCompoundingDelegations::<Test>::contains_key(2, 1));
+        });
+}
+
+// SET REWARD DESTINATION
+
+#[test]
+fn set_reward_destination_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_reward_destination(
                 Origin::signed(2),
+                RewardDestination::Account(3)
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::RewardDestinationSet(
                 2,
-                4
+                RewardDestination::Account(3)
+            )));
+        });
+}
+
+#[test]
+fn set_reward_destination_updates_storage_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                ParachainStaking::reward_destination(1),
+                RewardDestination::Free
+            );
+            assert_ok!(ParachainStaking::set_reward_destination(
+                Origin::signed(1),
+                RewardDestination::Treasury
             ));
             assert_eq!(
-                ParachainStaking::delegator_state(2)
-                    .expect("exists")
-                    .requests
-                    .less_total,
-                0
+                ParachainStaking::reward_destination(1),
+                RewardDestination::Treasury
             );
+            assert_ok!(ParachainStaking::set_reward_destination(
+                Origin::signed(1),
+                RewardDestination::Free
+            ));
+            assert!(!RewardDestinations::<Test>::contains_key(1));
         });
 }
 
 #[test]
-fn parachain_bond_inflation_reserve_matches_config() {
-    ExtBuilder::default()
-        .with_balances(vec![
+fn cannot_set_reward_destination_if_not_candidate_nor_delegator() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_reward_destination(Origin::signed(2), RewardDestination::Stake),
+            Error::<Test>::NotCandidateNorDelegator
+        );
+    });
+}
+
+#[test]
+fn cannot_set_same_reward_destination() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_reward_destination(
+                    Origin::signed(1),
+                    RewardDestination::Free
+                ),
+                Error::<Test>::NoWritingSameValue
+            );
+        });
+}
+
+#[test]
+fn execute_leave_delegators_removes_reward_destination() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_reward_destination(
+                Origin::signed(2),
+                RewardDestination::Stake
+            ));
+            assert_ok!(ParachainStaking::schedule_leave_delegators(Origin::signed(
+                2
+            )));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_delegators(
+                Origin::signed(2),
+                2,
+                1
+            ));
+            assert!(!RewardDestinations::<Test>::contains_key(2));
+        });
+}
+
+// STAKING QUERIES
+
+#[test]
+fn pending_rewards_are_estimated_before_payout() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 200), (2, 200), (3, 200)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            set_author(2, 1, 100);
+            roll_to(10);
+            assert_eq!(ParachainStaking::pending_rewards(&1), vec![(2, 18)]);
+            assert_eq!(ParachainStaking::pending_rewards(&2), vec![(2, 6)]);
+            assert_eq!(ParachainStaking::pending_rewards(&3), vec![(2, 6)]);
+            assert_eq!(ParachainStaking::pending_rewards(&4), vec![]);
+            roll_to(16);
+            assert_eq_last_events!(vec![
+                Event::Rewarded(1, 18),
+                Event::Rewarded(2, 6),
+                Event::Rewarded(3, 6),
+            ]);
+            assert_eq!(ParachainStaking::pending_rewards(&1), vec![]);
+            assert_eq!(ParachainStaking::pending_rewards(&2), vec![]);
+        });
+}
+
+#[test]
+fn expected_reward_excludes_paid_collators() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 200), (2, 200), (3, 200), (4, 200)])
+        .with_candidates(vec![(1, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 20), (3, 4, 20)])
+        .build()
+        .execute_with(|| {
+            set_author(2, 1, 50);
+            set_author(2, 4, 50);
+            roll_to(14);
+            assert_eq!(ParachainStaking::expected_reward(&1, 2), 12);
+            assert_eq!(ParachainStaking::expected_reward(&2, 2), 8);
+            assert_eq!(ParachainStaking::expected_reward(&3, 2), 8);
+            assert_eq!(ParachainStaking::expected_reward(&4, 2), 12);
+            // one of the collators is paid in the first block of the round
+            roll_to(15);
+            assert_eq!(
+                ParachainStaking::expected_reward(&2, 2)
+                    + ParachainStaking::expected_reward(&3, 2),
+                8
+            );
+            roll_to(16);
+            assert_eq!(ParachainStaking::expected_reward(&2, 2), 0);
+            assert_eq!(ParachainStaking::expected_reward(&3, 2), 0);
+        });
+}
+
+#[test]
+fn candidate_apr_is_estimated_from_inflation_and_stake() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 1_000_000_000_000),
+            (2, 1_000_000_000_000),
+            (3, 1_000_000_000_000),
+        ])
+        .with_candidates(vec![(1, 500_000_000_000)])
+        .with_delegations(vec![(2, 1, 250_000_000_000), (3, 1, 250_000_000_000)])
+        .with_inflation(InflationInfo {
+            expect: Range {
+                min: 700,
+                ideal: 700,
+                max: 700,
+            },
+            annual: Range {
+                min: Perbill::from_percent(1),
+                ideal: Perbill::from_percent(1),
+                max: Perbill::from_percent(1),
+            },
+            round: Range {
+                min: Perbill::from_parts(10),
+                ideal: Perbill::from_parts(10),
+                max: Perbill::from_parts(10),
+            },
+        })
+        .build()
+        .execute_with(|| {
+            // 30_000 issued per round, 6_000 commission, 525_960 rounds per year
+            assert_eq!(
+                ParachainStaking::candidate_apr(&1),
+                Some(StakingApr {
+                    collator: FixedU128::saturating_from_rational(1_893_456, 100_000_000),
+                    delegator: FixedU128::saturating_from_rational(1_262_304, 100_000_000),
+                })
+            );
+            assert_eq!(ParachainStaking::candidate_apr(&2), None);
+        });
+}
+
+#[test]
+fn candidate_apr_above_100_percent_is_not_capped() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 1_000_000_000_000),
+            (2, 1_000_000_000_000),
+            (3, 1_000_000_000_000),
+        ])
+        .with_candidates(vec![(1, 500_000_000_000)])
+        .with_delegations(vec![(2, 1, 250_000_000_000), (3, 1, 250_000_000_000)])
+        .with_inflation(InflationInfo {
+            expect: Range {
+                min: 700,
+                ideal: 700,
+                max: 700,
+            },
+            annual: Range {
+                min: Perbill::from_percent(1),
+                ideal: Perbill::from_percent(1),
+                max: Perbill::from_percent(1),
+            },
+            round: Range {
+                min: Perbill::from_parts(10_000),
+                ideal: Perbill::from_parts(10_000),
+                max: Perbill::from_parts(10_000),
+            },
+        })
+        .build()
+        .execute_with(|| {
+            // 30_000_000 issued per round, 6_000_000 commission, 525_960 rounds per year
+            assert_eq!(
+                ParachainStaking::candidate_apr(&1),
+                Some(StakingApr {
+                    collator: FixedU128::saturating_from_rational(1_893_456, 100_000),
+                    delegator: FixedU128::saturating_from_rational(1_262_304, 100_000),
+                })
+            );
+        });
+}
+
+#[test]
+fn delegator_overview_includes_pending_requests() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 30), (3, 30)])
+        .with_candidates(vec![(1, 20), (3, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50)
+            ));
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                Origin::signed(2),
+                3
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_overview(&2),
+                Some(DelegatorOverview {
+                    total: 20,
+                    less_total: 10,
+                    status: DelegatorStatus::Active,
+                    reward_destination: RewardDestination::Free,
+                    delegations: vec![
+                        DelegationOverview {
+                            candidate: 1,
+                            amount: 10,
+                            auto_compound: Percent::from_percent(50),
+                            request: None,
+                        },
+                        DelegationOverview {
+                            candidate: 3,
+                            amount: 10,
+                            auto_compound: Percent::zero(),
+                            request: Some(DelegationRequest {
+                                collator: 3,
+                                amount: 10,
+                                when_executable: 3,
+                                action: DelegationChange::Revoke,
+                            }),
+                        },
+                    ],
+                })
+            );
+            assert_eq!(ParachainStaking::delegator_overview(&1), None);
+        });
+}
+
+// COLLATOR LIVENESS
+
+#[test]
+fn authoring_stats_are_recorded_per_round() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            // 5 blocks per round, 2 selected collators
+            set_author(1, 1, 40);
+            set_author(1, 2, 20);
+            roll_to(5);
+            assert_eq!(
+                ParachainStaking::authoring_stats(1, 1),
+                Some(AuthoringStats {
+                    authored: 2,
+                    expected: 2
+                })
+            );
+            assert_eq!(
+                ParachainStaking::authoring_stats(1, 2),
+                Some(AuthoringStats {
+                    authored: 1,
+                    expected: 2
+                })
+            );
+            // missing half of the slots doesn't exceed the threshold
+            assert_eq!(ParachainStaking::missed_rounds(2), 0);
+            roll_to(10);
+            assert_eq!(ParachainStaking::missed_rounds(1), 1);
+            assert_eq!(
+                ParachainStaking::collator_liveness(&1),
+                CollatorLiveness {
+                    missed_rounds: 1,
+                    rounds: vec![
+                        (
+                            1,
+                            AuthoringStats {
+                                authored: 2,
+                                expected: 2
+                            }
+                        ),
+                        (
+                            2,
+                            AuthoringStats {
+                                authored: 0,
+                                expected: 2
+                            }
+                        ),
+                    ],
+                }
+            );
+            roll_to(15);
+            assert_eq!(ParachainStaking::authoring_stats(1, 1), None);
+            assert_eq!(ParachainStaking::collator_liveness(&1).rounds.len(), 2);
+        });
+}
+
+#[test]
+fn authored_blocks_are_counted_by_note_author() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            roll_to(1);
+            ParachainStaking::note_author(1);
+            ParachainStaking::note_author(1);
+            assert_eq!(
+                ParachainStaking::authoring_stats(1, 1),
+                Some(AuthoringStats {
+                    authored: 2,
+                    expected: 0
+                })
+            );
+            roll_to(5);
+            assert_eq!(
+                ParachainStaking::authoring_stats(1, 1),
+                Some(AuthoringStats {
+                    authored: 2,
+                    expected: 5
+                })
+            );
+        });
+}
+
+#[test]
+fn missed_rounds_reset_when_authoring_resumes() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            roll_to(10);
+            assert_eq!(ParachainStaking::missed_rounds(1), 2);
+            set_author(3, 1, 100);
+            roll_to(15);
+            assert_eq!(ParachainStaking::missed_rounds(1), 0);
+        });
+}
+
+#[test]
+fn candidate_goes_offline_after_missing_too_many_rounds() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .build()
+        .execute_with(|| {
+            MaxMissedRounds::set(2);
+            set_author(1, 1, 40);
+            set_author(2, 1, 40);
+            roll_to(5);
+            assert_eq!(ParachainStaking::missed_rounds(2), 1);
+            assert!(ParachainStaking::candidate_info(2).unwrap().is_active());
+            roll_to(10);
+            assert_event_emitted!(Event::CandidateWentOfflineForMissedSlots(2, 2));
+            assert!(!ParachainStaking::candidate_info(2).unwrap().is_active());
+            assert_eq!(ParachainStaking::missed_rounds(2), 0);
+            assert_eq!(
+                ParachainStaking::candidate_pool().0,
+                vec![Bond {
+                    owner: 1,
+                    amount: 20
+                }]
+            );
+            assert_eq!(ParachainStaking::selected_candidates(), vec![1]);
+        });
+}
+
+#[test]
+fn candidate_stays_online_if_max_missed_rounds_is_zero() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            roll_to(20);
+            assert_eq!(ParachainStaking::missed_rounds(1), 4);
+            assert!(ParachainStaking::candidate_info(1).unwrap().is_active());
+        });
+}
+
+// SLASHING
+
+/// Candidates 1 and 2 with 2 delegated by 3 and 4, where 2 is taken offline at the end of round 2
+/// for missing its slots, deferring its slash to round 5
+fn slashing_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 2, 20), (4, 2, 10)])
+        .build();
+    ext.execute_with(|| {
+        MaxMissedRounds::set(2);
+        set_author(1, 1, 40);
+        set_author(2, 1, 40);
+        roll_to(10);
+        MaxMissedRounds::set(0);
+    });
+    ext
+}
+
+#[test]
+fn downtime_slash_is_deferred() {
+    slashing_ext().execute_with(|| {
+        assert_event_emitted!(Event::CandidateWentOfflineForMissedSlots(2, 2));
+        assert_event_emitted!(Event::SlashDeferred(2, 5, 5));
+        assert_eq!(
+            ParachainStaking::unapplied_slashes(5),
+            vec![UnappliedSlash {
+                collator: 2,
+                own: 2,
+                others: vec![(3, 2), (4, 1)],
+            }]
+        );
+        roll_to(19);
+        assert_eq!(ParachainStaking::candidate_info(2).unwrap().bond, 20);
+        assert_eq!(Balances::reserved_balance(&3), 20);
+    });
+}
+
+#[test]
+fn deferred_slash_is_applied_to_the_treasury() {
+    slashing_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::go_online(Origin::signed(2)));
+        roll_to(20);
+        assert_event_emitted!(Event::Slashed(2, 2, 2));
+        assert_event_emitted!(Event::Slashed(3, 2, 2));
+        assert_event_emitted!(Event::Slashed(4, 2, 1));
+        assert!(ParachainStaking::unapplied_slashes(5).is_empty());
+        let candidate = ParachainStaking::candidate_info(2).unwrap();
+        assert_eq!(candidate.bond, 18);
+        assert_eq!(candidate.total_counted, 45);
+        assert!(ParachainStaking::candidate_pool().0.contains(&Bond {
+            owner: 2,
+            amount: 45
+        }));
+        assert_eq!(
+            ParachainStaking::top_delegations(2).unwrap().delegations,
+            vec![
+                Bond {
+                    owner: 3,
+                    amount: 18
+                },
+                Bond {
+                    owner: 4,
+                    amount: 9
+                }
+            ]
+        );
+        assert_eq!(ParachainStaking::delegator_state(3).unwrap().total, 18);
+        assert_eq!(Balances::reserved_balance(&2), 18);
+        assert_eq!(Balances::reserved_balance(&3), 18);
+        assert_eq!(Balances::reserved_balance(&4), 9);
+        assert_eq!(Balances::free_balance(&100), 5);
+        assert_eq!(ParachainStaking::total(), 65);
+    });
+}
+
+#[test]
+fn delegators_are_spared_if_their_slash_fraction_is_zero() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 2, 20)])
+        .build()
+        .execute_with(|| {
+            MaxMissedRounds::set(2);
+            DelegatorDowntimeSlashFraction::set(Perbill::zero());
+            set_author(1, 1, 40);
+            set_author(2, 1, 40);
+            roll_to(10);
+            assert_eq!(
+                ParachainStaking::unapplied_slashes(5),
+                vec![UnappliedSlash {
+                    collator: 2,
+                    own: 2,
+                    others: vec![],
+                }]
+            );
+            roll_to(20);
+            assert_eq!(Balances::reserved_balance(&2), 18);
+            assert_eq!(Balances::reserved_balance(&3), 20);
+        });
+}
+
+#[test]
+fn slash_reduces_pending_revocation() {
+    slashing_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::schedule_revoke_delegation(
+            Origin::signed(3),
+            2
+        ));
+        roll_to(20);
+        let state = ParachainStaking::delegator_state(3).unwrap();
+        assert_eq!(state.requests.less_total, 18);
+        assert_eq!(state.requests.requests.get(&2).unwrap().amount, 18);
+        assert_ok!(ParachainStaking::execute_delegation_request(
+            Origin::signed(3),
+            3,
+            2
+        ));
+        assert_eq!(Balances::reserved_balance(&3), 0);
+        assert_eq!(Balances::free_balance(&3), 98);
+        assert_eq!(ParachainStaking::total(), 47);
+    });
+}
+
+#[test]
+fn exit_scheduled_after_going_offline_waits_for_the_slash() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 2, 20), (4, 2, 10)])
+        .build()
+        .execute_with(|| {
+            // the slash is deferred longer than the exits
+            SlashDeferDuration::set(4);
+            MaxMissedRounds::set(2);
+            set_author(1, 1, 40);
+            set_author(2, 1, 40);
+            roll_to(10);
+            assert_event_emitted!(Event::SlashDeferred(2, 7, 5));
+            assert_eq!(ParachainStaking::pending_slashes(2), 1);
+            assert_eq!(ParachainStaking::pending_slashes(3), 1);
+            assert_ok!(ParachainStaking::schedule_leave_candidates(
+                Origin::signed(2),
+                2
+            ));
+            assert_ok!(ParachainStaking::schedule_leave_delegators(Origin::signed(
+                3
+            )));
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                Origin::signed(4),
+                2
+            ));
+            roll_to(20);
+            assert_noop!(
+                ParachainStaking::execute_leave_candidates(Origin::signed(2), 2, 2),
+                Error::<Test>::DeferredSlashPending
+            );
+            assert_noop!(
+                ParachainStaking::execute_leave_delegators(Origin::signed(3), 3, 1),
+                Error::<Test>::DeferredSlashPending
+            );
+            assert_noop!(
+                ParachainStaking::execute_delegation_request(Origin::signed(4), 4, 2),
+                Error::<Test>::DeferredSlashPending
+            );
+            roll_to(30);
+            assert_eq!(ParachainStaking::pending_slashes(2), 0);
+            assert_ok!(ParachainStaking::execute_leave_delegators(
+                Origin::signed(3),
+                3,
+                1
+            ));
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                Origin::signed(4),
+                4,
+                2
+            ));
+            assert_ok!(ParachainStaking::execute_leave_candidates(
+                Origin::signed(2),
+                2,
+                0
+            ));
+            assert_eq!(Balances::free_balance(&2), 98);
+            assert_eq!(Balances::free_balance(&3), 98);
+            assert_eq!(Balances::free_balance(&4), 99);
+            assert_eq!(Balances::free_balance(&100), 5);
+        });
+}
+
+#[test]
+fn slash_below_min_bond_takes_candidate_offline() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100)])
+        .with_candidates(vec![(1, 20), (2, 10)])
+        .build()
+        .execute_with(|| {
+            MaxMissedRounds::set(2);
+            set_author(1, 1, 40);
+            set_author(2, 1, 40);
+            roll_to(10);
+            MaxMissedRounds::set(0);
+            assert_ok!(ParachainStaking::go_online(Origin::signed(2)));
+            roll_to(20);
+            assert_event_emitted!(Event::Slashed(2, 2, 1));
+            assert_event_emitted!(Event::CandidateWentOffline(2));
+            assert_eq!(ParachainStaking::candidate_info(2).unwrap().bond, 9);
+            assert!(!ParachainStaking::candidate_pool()
+                .0
+                .iter()
+                .any(|bond| bond.owner == 2));
+            assert_noop!(
+                ParachainStaking::go_online(Origin::signed(2)),
+                Error::<Test>::CandidateBondBelowMin
+            );
+            assert_ok!(ParachainStaking::candidate_bond_more(Origin::signed(2), 1));
+            assert_ok!(ParachainStaking::go_online(Origin::signed(2)));
+        });
+}
+
+#[test]
+fn slash_cancels_pending_decrease_of_more_than_the_remaining_delegation() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 2, 20)])
+        .build()
+        .execute_with(|| {
+            MaxMissedRounds::set(2);
+            DelegatorDowntimeSlashFraction::set(Perbill::from_percent(50));
+            set_author(1, 1, 40);
+            set_author(2, 1, 40);
+            roll_to(10);
+            MaxMissedRounds::set(0);
+            assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+                Origin::signed(3),
+                2,
+                15
+            ));
+            roll_to(20);
+            assert_event_emitted!(Event::Slashed(3, 2, 10));
+            let state = ParachainStaking::delegator_state(3).unwrap();
+            assert!(state.requests.requests.is_empty());
+            assert_eq!(state.requests.less_total, 0);
+            assert_eq!(state.total, 10);
+            assert_noop!(
+                ParachainStaking::execute_delegation_request(Origin::signed(3), 3, 2),
+                Error::<Test>::PendingDelegationRequestDNE
+            );
+        });
+}
+
+#[test]
+fn slash_below_min_delegation_kicks_the_delegation() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![(3, 2, 20), (4, 1, 10), (4, 2, 10)])
+        .build()
+        .execute_with(|| {
+            MaxMissedRounds::set(2);
+            DelegatorDowntimeSlashFraction::set(Perbill::from_percent(90));
+            set_author(1, 1, 40);
+            set_author(2, 1, 40);
+            roll_to(10);
+            MaxMissedRounds::set(0);
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                Origin::signed(3),
+                2
+            ));
+            roll_to(20);
+            // 3 is left with 2 of its only delegation, below `MinDelegation`
+            assert_event_emitted!(Event::Slashed(3, 2, 18));
+            assert_event_emitted!(Event::DelegationKicked(3, 2, 2));
+            assert_event_emitted!(Event::DelegatorLeft(3, 2));
+            assert!(ParachainStaking::delegator_state(3).is_none());
+            assert_eq!(Balances::reserved_balance(&3), 0);
+            assert_eq!(Balances::free_balance(&3), 82);
+            // 4 keeps its other delegation
+            assert_event_emitted!(Event::Slashed(4, 2, 9));
+            assert_event_emitted!(Event::DelegationKicked(4, 2, 1));
+            let state = ParachainStaking::delegator_state(4).unwrap();
+            assert_eq!(state.total, 10);
+            assert_eq!(
+                state.delegations.0,
+                vec![Bond {
+                    owner: 1,
+                    amount: 10
+                }]
+            );
+            assert_eq!(Balances::reserved_balance(&4), 10);
+            assert!(ParachainStaking::top_delegations(2)
+                .unwrap()
+                .delegations
+                .is_empty());
+            assert_eq!(
+                ParachainStaking::candidate_info(2)
+                    .unwrap()
+                    .delegation_count,
+                0
+            );
+        });
+}
+
+#[test]
+fn cancel_deferred_slash_works() {
+    slashing_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::cancel_deferred_slash(
+            Origin::root(),
+            5,
+            2
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::DeferredSlashCancelled(
+            5, 2
+        )));
+        assert!(ParachainStaking::unapplied_slashes(5).is_empty());
+        assert_eq!(ParachainStaking::pending_slashes(2), 0);
+        assert_eq!(ParachainStaking::pending_slashes(3), 0);
+        roll_to(20);
+        assert_event_not_emitted!(Event::Slashed(2, 2, 2));
+        assert_eq!(ParachainStaking::candidate_info(2).unwrap().bond, 20);
+        assert_eq!(Balances::free_balance(&100), 0);
+    });
+}
+
+#[test]
+fn cannot_cancel_deferred_slash_which_dne() {
+    slashing_ext().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::cancel_deferred_slash(Origin::root(), 4, 2),
+            Error::<Test>::DeferredSlashDNE
+        );
+        assert_noop!(
+            ParachainStaking::cancel_deferred_slash(Origin::root(), 5, 1),
+            Error::<Test>::DeferredSlashDNE
+        );
+    });
+}
+
+#[test]
+fn cancel_deferred_slash_requires_root() {
+    slashing_ext().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::cancel_deferred_slash(Origin::signed(2), 5, 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+// ~~ PROPERTY-BASED TESTS ~~
+
+#[test]
+fn delegator_schedule_revocation_total() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20), (5, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20), (5, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10), (2, 4, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                Origin::signed(2),
+                1
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_state(2)
+                    .expect("exists")
+                    .requests
+                    .less_total,
+                10
+            );
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                Origin::signed(2),
+                2,
+                1
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_state(2)
+                    .expect("exists")
+                    .requests
+                    .less_total,
+                0
+            );
+            assert_ok!(ParachainStaking::delegate(Origin::signed(2), 5, 10, 0, 2));
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                Origin::signed(2),
+                3
+            ));
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                Origin::signed(2),
+                4
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_state(2)
+                    .expect("exists")
+                    .requests
+                    .less_total,
+                20
+            );
+            roll_to(20);
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                Origin::signed(2),
+                2,
+                3
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_state(2)
+                    .expect("exists")
+                    .requests
+                    .less_total,
+                10
+            );
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                Origin::signed(2),
+                2,
+                4
+            ));
+            assert_eq!(
+                ParachainStaking::delegator_state(2)
+                    .expect("exists")
+                    .requests
+                    .less_total,
+                0
+            );
+        });
+}
+
+#[test]
+fn parachain_bond_inflation_reserve_matches_config() {
+    ExtBuilder::default()
+        .with_balances(vec![
             (1, 100),
             (2, 100),
             (3, 100),
@@ -3737,6 +5141,13 @@ fn parachain_bond_inflation_reserve_matches_config() {
             expected.append(&mut new2);
             assert_eq_events!(expected.clone());
             assert_eq!(Balances::free_balance(&11), 65);
+            assert_ok!(ParachainStaking::set_commission_bounds(
+                Origin::root(),
+                CommissionBounds {
+                    min: Perbill::zero(),
+                    max: Perbill::from_percent(50),
+                }
+            ));
             assert_ok!(ParachainStaking::set_parachain_bond_reserve_percent(
                 Origin::root(),
                 Percent::from_percent(50)
@@ -3746,6 +5157,16 @@ fn parachain_bond_inflation_reserve_matches_config() {
             roll_to(35);
             // keep paying 6
             let mut new3 = vec![
+                Event::CommissionBoundsSet(
+                    CommissionBounds {
+                        min: Perbill::zero(),
+                        max: Perbill::from_percent(70),
+                    },
+                    CommissionBounds {
+                        min: Perbill::zero(),
+                        max: Perbill::from_percent(50),
+                    },
+                ),
                 Event::ParachainBondReservePercentSet(
                     Percent::from_percent(30),
                     Percent::from_percent(50),
@@ -3844,67 +5265,321 @@ fn parachain_bond_inflation_reserve_matches_config() {
 }
 
 #[test]
-fn paid_collator_commission_matches_config() {
+fn paid_collator_commission_matches_config() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 100),
+            (2, 100),
+            (3, 100),
+            (4, 100),
+            (5, 100),
+            (6, 100),
+        ])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            roll_to(8);
+            // chooses top TotalSelectedCandidates (5), in order
+            let mut expected = vec![
+                Event::CollatorChosen(2, 1, 40),
+                Event::NewRound(5, 2, 1, 40),
+            ];
+            assert_eq_events!(expected.clone());
+            assert_ok!(ParachainStaking::join_candidates(
+                Origin::signed(4),
+                20u128,
+                100u32
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(
+                Event::JoinedCollatorCandidates(4, 20u128, 60u128,)
+            ));
+            roll_to(9);
+            assert_ok!(ParachainStaking::delegate(Origin::signed(5), 4, 10, 10, 10));
+            assert_ok!(ParachainStaking::delegate(Origin::signed(6), 4, 10, 10, 10));
+            roll_to(11);
+            let mut new = vec![
+                Event::JoinedCollatorCandidates(4, 20, 60),
+                Event::Delegation(5, 10, 4, DelegatorAdded::AddedToTop { new_total: 30 }),
+                Event::Delegation(6, 10, 4, DelegatorAdded::AddedToTop { new_total: 40 }),
+                Event::CollatorChosen(3, 1, 40),
+                Event::CollatorChosen(3, 4, 40),
+                Event::NewRound(10, 3, 2, 80),
+            ];
+            expected.append(&mut new);
+            assert_eq_events!(expected.clone());
+            // only reward author with id 4
+            set_author(3, 4, 100);
+            roll_to(21);
+            // 20% of 10 is commission + due_portion (0) = 2 + 4 = 6
+            // all delegator payouts are 10-2 = 8 * stake_pct
+            let mut new2 = vec![
+                Event::CollatorChosen(4, 1, 40),
+                Event::CollatorChosen(4, 4, 40),
+                Event::NewRound(15, 4, 2, 80),
+                Event::CollatorChosen(5, 1, 40),
+                Event::CollatorChosen(5, 4, 40),
+                Event::NewRound(20, 5, 2, 80),
+                Event::Rewarded(4, 18),
+                Event::Rewarded(5, 6),
+                Event::Rewarded(6, 6),
+            ];
+            expected.append(&mut new2);
+            assert_eq_events!(expected);
+        });
+}
+
+#[test]
+fn auto_compound_bonds_rewards_into_delegation() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 200), (2, 200), (3, 200)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50)
+            ));
+            roll_to(8);
+            set_author(2, 1, 100);
+            roll_to(16);
+            // 2 and 3 are both paid 6, half of the reward of 2 is bonded back
+            assert_event_emitted!(Event::Rewarded(2, 6));
+            assert_event_emitted!(Event::Rewarded(3, 6));
+            assert_event_emitted!(Event::DelegationIncreased(2, 1, 3, true));
+            assert_event_emitted!(Event::AutoCompounded(2, 1, 3));
+            assert_event_not_emitted!(Event::AutoCompounded(3, 1, 3));
+            assert_eq!(Balances::reserved_balance(&2), 13);
+            assert_eq!(Balances::free_balance(&2), 193);
+            assert_eq!(Balances::reserved_balance(&3), 10);
+            assert_eq!(Balances::free_balance(&3), 196);
+            assert_eq!(ParachainStaking::delegator_state(2).unwrap().total, 13);
+            let top_delegations = ParachainStaking::top_delegations(1).unwrap();
+            assert_eq!(
+                top_delegations.delegations[0],
+                Bond {
+                    owner: 2,
+                    amount: 13
+                }
+            );
+            let candidate = ParachainStaking::candidate_info(1).unwrap();
+            assert_eq!(candidate.total_counted, 43);
+            assert_eq!(ParachainStaking::total(), 43);
+            assert_eq!(
+                ParachainStaking::candidate_pool().0[0],
+                Bond {
+                    owner: 1,
+                    amount: 43
+                }
+            );
+        });
+}
+
+#[test]
+fn auto_compound_skips_delegation_scheduled_for_revocation() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 200), (2, 200), (3, 200)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                Origin::signed(2),
+                1,
+                Percent::from_percent(50)
+            ));
+            roll_to(8);
+            set_author(2, 1, 100);
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                Origin::signed(2),
+                1
+            ));
+            roll_to(16);
+            assert_event_emitted!(Event::Rewarded(2, 6));
+            assert_event_not_emitted!(Event::AutoCompounded(2, 1, 3));
+            assert_eq!(Balances::reserved_balance(&2), 10);
+            assert_eq!(Balances::free_balance(&2), 196);
+        });
+}
+
+#[test]
+fn paid_collator_commission_matches_candidate_commission() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 200), (2, 200), (3, 200)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+                Origin::signed(1),
+                Perbill::from_percent(60)
+            ));
+            // the change is applied at the start of round 3
+            set_author(3, 1, 100);
+            roll_to(21);
+            // 60% of 30 is commission + due_portion (6) = 18 + 6 = 24
+            // all delegator payouts are 30-18 = 12 * stake_pct
+            assert_eq_last_events!(vec![
+                Event::Rewarded(1, 24),
+                Event::Rewarded(2, 3),
+                Event::Rewarded(3, 3),
+            ]);
+        });
+}
+
+#[test]
+fn paid_collator_commission_of_whole_reward() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 200), (2, 200), (3, 200)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_parachain_bond_reserve_percent(
+                Origin::root(),
+                Percent::zero()
+            ));
+            assert_ok!(ParachainStaking::set_commission_bounds(
+                Origin::root(),
+                CommissionBounds {
+                    min: Perbill::zero(),
+                    max: Perbill::one(),
+                }
+            ));
+            assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+                Origin::signed(1),
+                Perbill::one()
+            ));
+            // the change is applied at the start of round 3
+            set_author(3, 1, 100);
+            roll_to(21);
+            // the whole reward of 30 is commission, nothing is due to delegators
+            assert_event_emitted!(Event::Rewarded(1, 30));
+            assert_eq!(Balances::free_balance(&2), 190);
+            assert_eq!(Balances::free_balance(&3), 190);
+        });
+}
+
+#[test]
+fn paid_collator_commission_uses_round_snapshot() {
     ExtBuilder::default()
-        .with_balances(vec![
-            (1, 100),
-            (2, 100),
-            (3, 100),
-            (4, 100),
-            (5, 100),
-            (6, 100),
-        ])
+        .with_balances(vec![(1, 200), (2, 200), (3, 200)])
         .with_candidates(vec![(1, 20)])
         .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
         .build()
         .execute_with(|| {
-            roll_to(8);
-            // chooses top TotalSelectedCandidates (5), in order
-            let mut expected = vec![
-                Event::CollatorChosen(2, 1, 40),
-                Event::NewRound(5, 2, 1, 40),
-            ];
-            assert_eq_events!(expected.clone());
-            assert_ok!(ParachainStaking::join_candidates(
-                Origin::signed(4),
-                20u128,
-                100u32
+            assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+                Origin::signed(1),
+                Perbill::from_percent(60)
             ));
-            assert_last_event!(MetaEvent::ParachainStaking(
-                Event::JoinedCollatorCandidates(4, 20u128, 60u128,)
+            // round 2 is paid after the change is applied, but with the commission of round 2
+            set_author(2, 1, 100);
+            roll_to(16);
+            assert_eq!(
+                ParachainStaking::candidate_commission(1),
+                Some(Perbill::from_percent(60))
+            );
+            assert_eq_last_events!(vec![
+                Event::Rewarded(1, 18),
+                Event::Rewarded(2, 6),
+                Event::Rewarded(3, 6),
+            ]);
+        });
+}
+
+#[test]
+fn rewards_are_paid_to_other_account() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 200), (2, 200), (3, 200)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_reward_destination(
+                Origin::signed(2),
+                RewardDestination::Account(4)
             ));
-            roll_to(9);
-            assert_ok!(ParachainStaking::delegate(Origin::signed(5), 4, 10, 10, 10));
-            assert_ok!(ParachainStaking::delegate(Origin::signed(6), 4, 10, 10, 10));
-            roll_to(11);
-            let mut new = vec![
-                Event::JoinedCollatorCandidates(4, 20, 60),
-                Event::Delegation(5, 10, 4, DelegatorAdded::AddedToTop { new_total: 30 }),
-                Event::Delegation(6, 10, 4, DelegatorAdded::AddedToTop { new_total: 40 }),
-                Event::CollatorChosen(3, 1, 40),
-                Event::CollatorChosen(3, 4, 40),
-                Event::NewRound(10, 3, 2, 80),
-            ];
-            expected.append(&mut new);
-            assert_eq_events!(expected.clone());
-            // only reward author with id 4
-            set_author(3, 4, 100);
-            roll_to(21);
-            // 20% of 10 is commission + due_portion (0) = 2 + 4 = 6
-            // all delegator payouts are 10-2 = 8 * stake_pct
-            let mut new2 = vec![
-                Event::CollatorChosen(4, 1, 40),
-                Event::CollatorChosen(4, 4, 40),
-                Event::NewRound(15, 4, 2, 80),
-                Event::CollatorChosen(5, 1, 40),
-                Event::CollatorChosen(5, 4, 40),
-                Event::NewRound(20, 5, 2, 80),
-                Event::Rewarded(4, 18),
-                Event::Rewarded(5, 6),
-                Event::Rewarded(6, 6),
-            ];
-            expected.append(&mut new2);
-            assert_eq_events!(expected);
+            set_author(2, 1, 100);
+            roll_to(16);
+            assert_eq_last_events!(vec![
+                Event::Rewarded(1, 18),
+                Event::RewardedToDestination(2, RewardDestination::Account(4), 6),
+                Event::Rewarded(3, 6),
+            ]);
+            assert_eq!(Balances::free_balance(&2), 190);
+            assert_eq!(Balances::free_balance(&4), 6);
+        });
+}
+
+#[test]
+fn rewards_are_donated_to_treasury() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 200), (2, 200), (3, 200)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_reward_destination(
+                Origin::signed(1),
+                RewardDestination::Treasury
+            ));
+            set_author(2, 1, 100);
+            roll_to(16);
+            assert_eq_last_events!(vec![
+                Event::RewardedToDestination(1, RewardDestination::Treasury, 18),
+                Event::Rewarded(2, 6),
+                Event::Rewarded(3, 6),
+            ]);
+            assert_eq!(Balances::free_balance(&1), 180);
+            assert_eq!(Balances::free_balance(&100), 18);
+        });
+}
+
+#[test]
+fn rewards_are_bonded_into_stake() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 200), (2, 200), (3, 200)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_reward_destination(
+                Origin::signed(1),
+                RewardDestination::Stake
+            ));
+            assert_ok!(ParachainStaking::set_reward_destination(
+                Origin::signed(3),
+                RewardDestination::Stake
+            ));
+            set_author(2, 1, 100);
+            roll_to(16);
+            assert_event_emitted!(Event::RewardedToDestination(
+                1,
+                RewardDestination::Stake,
+                18
+            ));
+            assert_event_emitted!(Event::CandidateBondedMore(1, 18, 38));
+            assert_event_emitted!(Event::RewardedToDestination(3, RewardDestination::Stake, 6));
+            assert_event_emitted!(Event::DelegationIncreased(3, 1, 6, true));
+            assert_eq!(Balances::reserved_balance(&1), 38);
+            assert_eq!(Balances::free_balance(&1), 180);
+            assert_eq!(Balances::reserved_balance(&3), 16);
+            assert_eq!(Balances::free_balance(&3), 190);
+            assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 38);
+            assert_eq!(
+                ParachainStaking::candidate_info(1).unwrap().total_counted,
+                64
+            );
+            assert_eq!(ParachainStaking::total(), 64);
+            assert_eq!(
+                ParachainStaking::candidate_pool().0[0],
+                Bond {
+                    owner: 1,
+                    amount: 64
+                }
+            );
         });
 }
 
@@ -5217,116 +6892,185 @@ fn deferred_payment_steady_state_event_flow() {
         });
 }
 
-// HOTFIX UNIT TESTs
+// MIGRATION UNIT TESTS
+use crate::migrations::{Collator2, CollatorCandidate, Nominator2, RemoveDeprecatedStorage};
+use frame_support::{
+    migration::{have_storage_value, put_storage_value},
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    StorageHasher, Twox64Concat,
+};
+use parity_scale_codec::Encode;
+
+/// Store the state of the account in a storage item no longer declared by the pallet
+fn put_deprecated<V: Encode>(item: &[u8], account: u64, value: V) {
+    put_storage_value(
+        b"ParachainStaking",
+        item,
+        &Twox64Concat::hash(&account.encode()),
+        value,
+    );
+}
+
+fn has_deprecated(item: &[u8], account: u64) -> bool {
+    have_storage_value(
+        b"ParachainStaking",
+        item,
+        &Twox64Concat::hash(&account.encode()),
+    )
+}
+
+fn remove_deprecated_storage() {
+    StorageVersion::new(2).put::<crate::Pallet<Test>>();
+    RemoveDeprecatedStorage::<Test>::on_runtime_upgrade();
+    assert_eq!(
+        crate::Pallet::<Test>::on_chain_storage_version(),
+        StorageVersion::new(3)
+    );
+}
 
 #[test]
-fn hotfix_remove_delegation_requests_works() {
-    ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 20)])
-        .with_candidates(vec![(1, 20)])
-        .build()
-        .execute_with(|| {
-            let mut requests: BTreeMap<
-                <Test as frame_system::Config>::AccountId,
-                DelegationRequest<<Test as frame_system::Config>::AccountId, BalanceOf<Test>>,
-            > = BTreeMap::new();
-            requests.insert(
-                3,
-                DelegationRequest {
-                    collator: 3,
-                    amount: 5,
-                    when_executable: 0,
-                    action: DelegationChange::Decrease,
-                },
-            );
-            requests.insert(
-                4,
-                DelegationRequest {
-                    collator: 4,
-                    amount: 20,
-                    when_executable: 0,
-                    action: DelegationChange::Revoke,
-                },
-            );
-            let corrupted_delegator_state = Delegator {
-                id: 2,
-                delegations: OrderedSet::from(vec![Bond {
-                    owner: 1,
-                    amount: 20,
-                }]),
+fn remove_deprecated_storage_migrates_collator_state2() {
+    ExtBuilder::default().build().execute_with(|| {
+        put_deprecated(
+            b"CollatorState2",
+            1,
+            Collator2::<u64, u128> {
+                id: 1,
+                bond: 20,
+                nominators: OrderedSet::from(vec![2]),
+                top_nominators: vec![Bond {
+                    owner: 2,
+                    amount: 10,
+                }],
+                bottom_nominators: vec![],
+                total_counted: 30,
+                total_backing: 30,
+                state: CollatorStatus::Active,
+            },
+        );
+        remove_deprecated_storage();
+        assert!(!has_deprecated(b"CollatorState2", 1));
+        let info = ParachainStaking::candidate_info(1).expect("candidate is migrated");
+        assert_eq!(info.bond, 20);
+        assert_eq!(info.delegation_count, 1);
+        assert_eq!(info.total_counted, 30);
+        assert_eq!(info.lowest_top_delegation_amount, 10);
+        assert_eq!(info.top_capacity, CapacityStatus::Partial);
+        assert_eq!(info.bottom_capacity, CapacityStatus::Empty);
+        assert_eq!(info.request, None);
+        assert_eq!(info.status, CollatorStatus::Active);
+        let top = ParachainStaking::top_delegations(1).expect("delegations are migrated");
+        assert_eq!(top.total, 10);
+        assert_eq!(
+            top.delegations,
+            vec![Bond {
+                owner: 2,
+                amount: 10
+            }]
+        );
+        assert!(ParachainStaking::bottom_delegations(1)
+            .expect("delegations are migrated")
+            .delegations
+            .is_empty());
+    });
+}
+
+#[test]
+fn remove_deprecated_storage_migrates_nominator_state2() {
+    ExtBuilder::default().build().execute_with(|| {
+        put_deprecated(
+            b"NominatorState2",
+            3,
+            Nominator2::<u64, u128> {
+                delegations: OrderedSet::from(vec![
+                    Bond {
+                        owner: 1,
+                        amount: 10,
+                    },
+                    Bond {
+                        owner: 2,
+                        amount: 10,
+                    },
+                ]),
+                revocations: OrderedSet::from(vec![2]),
                 total: 20,
-                requests: PendingDelegationRequests {
-                    revocations_count: 1,
-                    requests,
-                    less_total: 25,
-                },
+                scheduled_revocations_count: 1,
+                scheduled_revocations_total: 10,
                 status: DelegatorStatus::Active,
-            };
-            <DelegatorState<Test>>::insert(2, corrupted_delegator_state);
-            assert_ok!(ParachainStaking::hotfix_remove_delegation_requests(
-                Origin::root(),
-                vec![2, 5]
-            ));
-            assert!(ParachainStaking::delegator_state(&5).is_none());
-            let fixed_delegator_state =
-                ParachainStaking::delegator_state(&2).expect("inserted => exists");
-            assert_eq!(fixed_delegator_state.requests.revocations_count, 0);
-            assert_eq!(fixed_delegator_state.requests.less_total, 0);
-        });
+            },
+        );
+        remove_deprecated_storage();
+        assert!(!has_deprecated(b"NominatorState2", 3));
+        let state = ParachainStaking::delegator_state(3).expect("delegator is migrated");
+        assert_eq!(state.id, 3);
+        assert_eq!(state.total, 20);
+        assert_eq!(state.delegations.0.len(), 2);
+        assert_eq!(state.status, DelegatorStatus::Active);
+        // revocation is scheduled again, its round was kept by the removed exit queue
+        assert_eq!(state.requests.revocations_count, 1);
+        assert_eq!(state.requests.less_total, 10);
+        assert_eq!(
+            state.requests.requests.get(&2),
+            Some(&DelegationRequest {
+                collator: 2,
+                amount: 10,
+                when_executable: 3,
+                action: DelegationChange::Revoke,
+            })
+        );
+    });
 }
 
 #[test]
-fn hotfix_update_candidate_pool_value_updates_candidate_pool() {
+fn remove_deprecated_storage_drops_dead_state() {
     ExtBuilder::default()
-        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
-        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .with_balances(vec![(1, 20), (2, 10)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10)])
         .build()
         .execute_with(|| {
-            // corrupt CandidatePool
-            <CandidatePool<Test>>::put(OrderedSet::from(vec![
-                Bond {
-                    owner: 1,
-                    amount: 15,
-                },
-                Bond {
-                    owner: 2,
-                    amount: 16,
-                },
-                Bond {
-                    owner: 3,
-                    amount: 17,
-                },
-                Bond {
-                    owner: 4,
-                    amount: 18,
+            put_deprecated(
+                b"CandidateState",
+                1,
+                CollatorCandidate::<u64, u128> {
+                    id: 1,
+                    bond: 99,
+                    delegators: OrderedSet::new(),
+                    top_delegations: vec![],
+                    bottom_delegations: vec![],
+                    total_counted: 99,
+                    total_backing: 99,
+                    request: None,
+                    state: CollatorStatus::Active,
                 },
-                Bond {
-                    owner: 5,
-                    amount: 19,
+            );
+            put_deprecated(
+                b"NominatorState2",
+                2,
+                Nominator2::<u64, u128> {
+                    delegations: OrderedSet::new(),
+                    revocations: OrderedSet::new(),
+                    total: 99,
+                    scheduled_revocations_count: 0,
+                    scheduled_revocations_total: 0,
+                    status: DelegatorStatus::Active,
                 },
-            ]));
-            // run migration and pass in 6 even though not a candidate
-            assert_ok!(ParachainStaking::hotfix_update_candidate_pool_value(
-                Origin::root(),
-                vec![1, 2, 3, 4, 5, 6]
-            ));
-            // CandidatePool is now fixed for all input accounts
-            let pool = <CandidatePool<Test>>::get();
-            for Bond { owner, amount } in pool.0 {
-                // 6 is not in the candidate pool despite being passed in
-                assert!(owner <= 5 && owner >= 1);
-                // all amounts are fixed
-                assert_eq!(amount, 20);
-            }
+            );
+            remove_deprecated_storage();
+            assert!(!has_deprecated(b"CandidateState", 1));
+            assert!(!has_deprecated(b"NominatorState2", 2));
+            assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 20);
+            assert_eq!(
+                ParachainStaking::candidate_info(1).unwrap().total_counted,
+                30
+            );
+            assert_eq!(ParachainStaking::delegator_state(2).unwrap().total, 10);
         });
 }
 
-// MIGRATION UNIT TESTS
-use frame_support::traits::OnRuntimeUpgrade;
-
 #[test]
 /// Kicks extra bottom delegations to force leave delegators if last delegation
-fn split_candidate_state_kicks_extra_bottom_delegators_to_exit() {
+fn deprecated_candidate_state_kicks_extra_bottom_delegators_to_exit() {
     ExtBuilder::default()
         .with_balances(vec![(11, 22), (12, 20)])
         .build()
@@ -5408,13 +7152,13 @@ fn split_candidate_state_kicks_extra_bottom_delegators_to_exit() {
                     request: None,
                     state: CollatorStatus::Active,
                 };
-                <CandidateState<Test>>::insert(&i, old_candidate_state);
+                put_deprecated(b"CandidateState", i, old_candidate_state);
             }
             // total is 165 * 2 = 330
             <Total<Test>>::put(330);
             assert!(ParachainStaking::is_delegator(&11));
             assert!(ParachainStaking::is_delegator(&12));
-            crate::migrations::SplitCandidateStateToDecreasePoV::<Test>::on_runtime_upgrade();
+            remove_deprecated_storage();
             assert_event_emitted!(Event::DelegationKicked(11, 1, 11));
             assert_event_emitted!(Event::DelegationKicked(11, 2, 11));
             assert_event_emitted!(Event::DelegationKicked(12, 1, 10));
@@ -5485,7 +7229,7 @@ fn split_candidate_state_kicks_extra_bottom_delegators_to_exit() {
 
 #[test]
 /// Force revokes candidate state
-fn split_candidate_state_kicks_extra_bottom_delegations_without_exit() {
+fn deprecated_candidate_state_kicks_extra_bottom_delegations_without_exit() {
     ExtBuilder::default()
         .with_balances(vec![(11, 32), (12, 30)])
         .build()
@@ -5571,13 +7315,13 @@ fn split_candidate_state_kicks_extra_bottom_delegations_without_exit() {
                     request: None,
                     state: CollatorStatus::Active,
                 };
-                <CandidateState<Test>>::insert(&i, old_candidate_state);
+                put_deprecated(b"CandidateState", i, old_candidate_state);
             }
             // total is 165 * 2 + 20 = 330
             <Total<Test>>::put(350);
             assert!(ParachainStaking::is_delegator(&11));
             assert!(ParachainStaking::is_delegator(&12));
-            crate::migrations::SplitCandidateStateToDecreasePoV::<Test>::on_runtime_upgrade();
+            remove_deprecated_storage();
             assert_event_emitted!(Event::DelegationKicked(11, 1, 11));
             assert_event_emitted!(Event::DelegationKicked(11, 2, 11));
             assert_event_emitted!(Event::DelegationKicked(12, 1, 10));
@@ -5649,7 +7393,7 @@ fn split_candidate_state_kicks_extra_bottom_delegations_without_exit() {
 }
 
 #[test]
-fn split_candidate_state_migrates_empty_delegations_correctly() {
+fn deprecated_candidate_state_migrates_empty_delegations_correctly() {
     ExtBuilder::default()
         // .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20)])
         // .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20)])
@@ -5668,9 +7412,9 @@ fn split_candidate_state_migrates_empty_delegations_correctly() {
                     request: None,
                     state: CollatorStatus::Active,
                 };
-                <CandidateState<Test>>::insert(&i, old_candidate_state);
+                put_deprecated(b"CandidateState", i, old_candidate_state);
             }
-            crate::migrations::SplitCandidateStateToDecreasePoV::<Test>::on_runtime_upgrade();
+            remove_deprecated_storage();
             for i in 1..5 {
                 let top_delegations = <TopDelegations<Test>>::get(&i).unwrap();
                 assert_eq!(top_delegations.total, 0);
@@ -5689,7 +7433,7 @@ fn split_candidate_state_migrates_empty_delegations_correctly() {
 }
 
 #[test]
-fn split_candidate_state_migrates_partial_top_delegations_correctly() {
+fn deprecated_candidate_state_migrates_partial_top_delegations_correctly() {
     ExtBuilder::default()
         // .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20)])
         // .with_candidates(vec![(1, 20), (2, 20)])
@@ -5718,9 +7462,9 @@ fn split_candidate_state_migrates_partial_top_delegations_correctly() {
                     request: None,
                     state: CollatorStatus::Active,
                 };
-                <CandidateState<Test>>::insert(&i, old_candidate_state);
+                put_deprecated(b"CandidateState", i, old_candidate_state);
             }
-            crate::migrations::SplitCandidateStateToDecreasePoV::<Test>::on_runtime_upgrade();
+            remove_deprecated_storage();
             for i in 1..3 {
                 let top_delegations = <TopDelegations<Test>>::get(&i).unwrap();
                 assert_eq!(top_delegations.total, 20);
@@ -5751,7 +7495,7 @@ fn split_candidate_state_migrates_partial_top_delegations_correctly() {
 }
 
 #[test]
-fn split_candidate_state_migrates_full_top_delegations_correctly() {
+fn deprecated_candidate_state_migrates_full_top_delegations_correctly() {
     ExtBuilder::default()
         // .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 20)])
         // .with_candidates(vec![(1, 20), (2, 20)])
@@ -5797,9 +7541,9 @@ fn split_candidate_state_migrates_full_top_delegations_correctly() {
                     request: None,
                     state: CollatorStatus::Active,
                 };
-                <CandidateState<Test>>::insert(&i, old_candidate_state);
+                put_deprecated(b"CandidateState", i, old_candidate_state);
             }
-            crate::migrations::SplitCandidateStateToDecreasePoV::<Test>::on_runtime_upgrade();
+            remove_deprecated_storage();
             for i in 1..3 {
                 let top_delegations = <TopDelegations<Test>>::get(&i).unwrap();
                 assert_eq!(top_delegations.total, 40);
@@ -5838,7 +7582,7 @@ fn split_candidate_state_migrates_full_top_delegations_correctly() {
 }
 
 #[test]
-fn split_candidate_state_migrates_full_top_partial_bottom_delegations_correctly() {
+fn deprecated_candidate_state_migrates_full_top_partial_bottom_delegations_correctly() {
     ExtBuilder::default()
         // .with_balances(vec![
         // 	(1, 20),
@@ -5906,9 +7650,9 @@ fn split_candidate_state_migrates_full_top_partial_bottom_delegations_correctly(
                     request: None,
                     state: CollatorStatus::Active,
                 };
-                <CandidateState<Test>>::insert(&i, old_candidate_state);
+                put_deprecated(b"CandidateState", i, old_candidate_state);
             }
-            crate::migrations::SplitCandidateStateToDecreasePoV::<Test>::on_runtime_upgrade();
+            remove_deprecated_storage();
             for i in 1..3 {
                 let top_delegations = <TopDelegations<Test>>::get(&i).unwrap();
                 assert_eq!(top_delegations.total, 70);
@@ -5959,7 +7703,7 @@ fn split_candidate_state_migrates_full_top_partial_bottom_delegations_correctly(
 }
 
 #[test]
-fn split_candidate_state_migrates_full_top_and_bottom_delegations_correctly() {
+fn deprecated_candidate_state_migrates_full_top_and_bottom_delegations_correctly() {
     ExtBuilder::default()
         // .with_balances(vec![
         // 	(1, 20),
@@ -6041,9 +7785,9 @@ fn split_candidate_state_migrates_full_top_and_bottom_delegations_correctly() {
                     request: None,
                     state: CollatorStatus::Active,
                 };
-                <CandidateState<Test>>::insert(&i, old_candidate_state);
+                put_deprecated(b"CandidateState", i, old_candidate_state);
             }
-            crate::migrations::SplitCandidateStateToDecreasePoV::<Test>::on_runtime_upgrade();
+            remove_deprecated_storage();
             for i in 1..3 {
                 let top_delegations = <TopDelegations<Test>>::get(&i).unwrap();
                 assert_eq!(top_delegations.total, 70);
@@ -6101,163 +7845,6 @@ fn split_candidate_state_migrates_full_top_and_bottom_delegations_correctly() {
         });
 }
 
-// #[test]
-// fn remove_exit_queue_migration_migrates_leaving_candidates() {
-// 	use crate::pallet::ExitQueue2;
-// 	use crate::set::*;
-// 	use crate::*;
-// 	ExtBuilder::default()
-// 		.with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
-// 		.with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
-// 		.build()
-// 		.execute_with(|| {
-// 			// prepare leaving state for all 5 candidates before the migration
-// 			for i in 1..6 {
-// 				// manually change the CollatorState2 status
-// 				<CollatorState2<Test>>::insert(
-// 					i,
-// 					Collator2 {
-// 						id: i,
-// 						bond: 20,
-// 						nominators: OrderedSet::new(),
-// 						top_nominators: Vec::new(),
-// 						bottom_nominators: Vec::new(),
-// 						total_counted: 20,
-// 						total_backing: 20,
-// 						// set to leaving
-// 						state: CollatorStatus::Leaving(3),
-// 					},
-// 				);
-// 			}
-// 			<ExitQueue2<Test>>::put(ExitQ {
-// 				candidates: OrderedSet(vec![1, 2, 3, 4, 5]),
-// 				candidate_schedule: vec![(1, 3), (2, 3), (3, 3), (4, 3), (5, 3)],
-// 				..Default::default()
-// 			});
-// 			// execute migration
-// 			migrations::RemoveExitQueue::<Test>::on_runtime_upgrade();
-// 			// check expected candidate state reflects previous state
-// 			for i in 1..6 {
-// 				assert!(<CollatorState2<Test>>::get(i).is_none());
-// 				assert_eq!(
-// 					<CandidateState<Test>>::get(i).unwrap().state,
-// 					CollatorStatus::Leaving(3)
-// 				);
-// 			}
-// 			// exit queue should be empty
-// 			assert_eq!(<ExitQueue2<Test>>::get(), ExitQ::default());
-// 		});
-// }
-
-// #[test]
-// fn remove_exit_queue_migration_migrates_leaving_delegators() {
-// 	use crate::pallet::ExitQueue2;
-// 	use crate::set::*;
-// 	use crate::*;
-// 	ExtBuilder::default()
-// 		.with_balances(vec![(2, 100), (3, 100), (4, 100), (5, 100), (6, 100)])
-// 		.with_candidates(vec![(2, 20)])
-// 		.with_delegations(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
-// 		.build()
-// 		.execute_with(|| {
-// 			// prepare leaving state for all 4 delegators before the migration
-// 			for i in 3..7 {
-// 				<NominatorState2<Test>>::insert(
-// 					i,
-// 					Nominator2 {
-// 						delegations: OrderedSet(vec![Bond {
-// 							owner: 1,
-// 							amount: 10,
-// 						}]),
-// 						revocations: OrderedSet::new(),
-// 						total: 10,
-// 						scheduled_revocations_count: 0u32,
-// 						scheduled_revocations_total: 0u32.into(),
-// 						status: DelegatorStatus::Leaving(3),
-// 					},
-// 				);
-// 			}
-// 			<ExitQueue2<Test>>::put(ExitQ {
-// 				nominators_leaving: OrderedSet(vec![3, 4, 5, 6]),
-// 				nominator_schedule: vec![(3, None, 3), (4, None, 3), (5, None, 3), (6, None, 3)],
-// 				..Default::default()
-// 			});
-// 			// execute migration
-// 			migrations::RemoveExitQueue::<Test>::on_runtime_upgrade();
-// 			// check expected delegator state reflects previous state
-// 			for i in 3..7 {
-// 				assert!(<NominatorState2<Test>>::get(i).is_none());
-// 				assert_eq!(
-// 					ParachainStaking::delegator_state(i).unwrap().status,
-// 					DelegatorStatus::Leaving(3)
-// 				);
-// 			}
-// 			// exit queue should be empty
-// 			assert_eq!(<ExitQueue2<Test>>::get(), ExitQ::default());
-// 		});
-// }
-
-// #[test]
-// fn remove_exit_queue_migration_migrates_delegator_revocations() {
-// 	use crate::pallet::ExitQueue2;
-// 	use crate::set::*;
-// 	use crate::*;
-// 	ExtBuilder::default()
-// 		.with_balances(vec![(2, 100), (3, 100), (4, 100), (5, 100), (6, 100)])
-// 		.with_candidates(vec![(2, 20)])
-// 		.with_delegations(vec![(3, 1, 10), (4, 1, 10), (5, 1, 10), (6, 1, 10)])
-// 		.build()
-// 		.execute_with(|| {
-// 			// prepare leaving state for all 4 delegators before the migration
-// 			for i in 3..7 {
-// 				<NominatorState2<Test>>::insert(
-// 					i,
-// 					Nominator2 {
-// 						delegations: OrderedSet(vec![Bond {
-// 							owner: 1,
-// 							amount: 10,
-// 						}]),
-// 						revocations: OrderedSet(vec![1]),
-// 						total: 10,
-// 						scheduled_revocations_count: 1u32,
-// 						scheduled_revocations_total: 10u32.into(),
-// 						status: DelegatorStatus::Active,
-// 					},
-// 				);
-// 			}
-// 			<ExitQueue2<Test>>::put(ExitQ {
-// 				nominator_schedule: vec![
-// 					(3, Some(1), 3),
-// 					(4, Some(1), 3),
-// 					(5, Some(1), 3),
-// 					(6, Some(1), 3),
-// 				],
-// 				..Default::default()
-// 			});
-// 			// execute migration
-// 			migrations::RemoveExitQueue::<Test>::on_runtime_upgrade();
-// 			// check expected delegator state reflects previous state
-// 			for i in 3..7 {
-// 				assert!(<NominatorState2<Test>>::get(i).is_none());
-// 				assert_eq!(
-// 					ParachainStaking::delegator_state(i)
-// 						.unwrap()
-// 						.requests
-// 						.requests
-// 						.get(&1),
-// 					Some(&DelegationRequest {
-// 						collator: 1,
-// 						amount: 10,
-// 						when_executable: 3,
-// 						action: DelegationChange::Revoke
-// 					})
-// 				);
-// 			}
-// 			// exit queue should be empty
-// 			assert_eq!(<ExitQueue2<Test>>::get(), ExitQ::default());
-// 		});
-// }
-
 #[test]
 fn verify_purge_storage_migration_works() {
     use crate::{Points, Round, RoundInfo, Staked};
@@ -6288,3 +7875,216 @@ fn verify_purge_storage_migration_works() {
         }
     });
 }
+
+#[test]
+fn verify_balance_type_migration_works() {
+    use crate::{
+        migrations::{MigrateBalanceType, OldCollatorSnapshot},
+        AtStake, CandidateBondLessRequest, CandidateMetadata, Delegations, InflationConfig,
+        InflationInfo, Pallet, Staked,
+    };
+    use frame_support::{
+        storage::unhashed,
+        traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    };
+    ExtBuilder::default().build().execute_with(|| {
+        StorageVersion::new(0).put::<Pallet<Test>>();
+        // store staking state encoded with the old `u64` balance type
+        unhashed::put(&<Total<Test>>::hashed_key(), &70u64);
+        unhashed::put(&<Staked<Test>>::hashed_key_for(1), &50u64);
+        unhashed::put(
+            &<CandidatePool<Test>>::hashed_key(),
+            &OrderedSet(vec![Bond {
+                owner: 1u64,
+                amount: 50u64,
+            }]),
+        );
+        unhashed::put(
+            &<CandidateInfo<Test>>::hashed_key_for(1),
+            &CandidateMetadata::<u64> {
+                bond: 20,
+                delegation_count: 1,
+                total_counted: 50,
+                lowest_top_delegation_amount: 30,
+                highest_bottom_delegation_amount: 0,
+                lowest_bottom_delegation_amount: 0,
+                top_capacity: CapacityStatus::Partial,
+                bottom_capacity: CapacityStatus::Empty,
+                request: Some(CandidateBondLessRequest {
+                    amount: 5,
+                    when_executable: 3,
+                }),
+                status: CollatorStatus::Active,
+            },
+        );
+        unhashed::put(
+            &<TopDelegations<Test>>::hashed_key_for(1),
+            &Delegations::<u64, u64> {
+                delegations: vec![Bond {
+                    owner: 2,
+                    amount: 30,
+                }],
+                total: 30,
+            },
+        );
+        unhashed::put(
+            &<AtStake<Test>>::hashed_key_for(1, 1),
+            &OldCollatorSnapshot::<u64, u64> {
+                bond: 20,
+                delegations: vec![Bond {
+                    owner: 2,
+                    amount: 30,
+                }],
+                total: 50,
+            },
+        );
+        let mut requests = BTreeMap::new();
+        requests.insert(
+            1u64,
+            DelegationRequest {
+                collator: 1u64,
+                amount: 10u64,
+                when_executable: 3,
+                action: DelegationChange::Decrease,
+            },
+        );
+        unhashed::put(
+            &<DelegatorState<Test>>::hashed_key_for(2),
+            &Delegator::<u64, u64> {
+                id: 2,
+                delegations: OrderedSet(vec![Bond {
+                    owner: 1,
+                    amount: 30,
+                }]),
+                total: 30,
+                requests: PendingDelegationRequests {
+                    revocations_count: 0,
+                    requests,
+                    less_total: 10,
+                },
+                status: DelegatorStatus::Active,
+            },
+        );
+        unhashed::put(
+            &<InflationConfig<Test>>::hashed_key(),
+            &InflationInfo::<u64> {
+                expect: Range {
+                    min: 10,
+                    ideal: 20,
+                    max: 30,
+                },
+                annual: Range::from(Perbill::from_percent(5)),
+                round: Range::from(Perbill::from_percent(1)),
+            },
+        );
+
+        // execute the migration
+        MigrateBalanceType::<Test, u64>::on_runtime_upgrade();
+
+        assert_eq!(
+            Pallet::<Test>::on_chain_storage_version(),
+            StorageVersion::new(2)
+        );
+        assert_eq!(ParachainStaking::total(), 70);
+        assert_eq!(ParachainStaking::staked(1), 50);
+        assert_eq!(ParachainStaking::candidate_pool().0[0].amount, 50);
+        let info = ParachainStaking::candidate_info(1).expect("candidate is migrated");
+        assert_eq!(info.bond, 20);
+        assert_eq!(info.total_counted, 50);
+        assert_eq!(info.lowest_top_delegation_amount, 30);
+        assert_eq!(info.top_capacity, CapacityStatus::Partial);
+        assert_eq!(
+            info.request,
+            Some(CandidateBondLessRequest {
+                amount: 5,
+                when_executable: 3,
+            })
+        );
+        let top = ParachainStaking::top_delegations(1).expect("delegations are migrated");
+        assert_eq!(top.total, 30);
+        assert_eq!(top.delegations[0].owner, 2);
+        assert_eq!(top.delegations[0].amount, 30);
+        let snapshot = ParachainStaking::at_stake(1, 1);
+        assert_eq!(snapshot.bond, 20);
+        assert_eq!(snapshot.delegations[0].amount, 30);
+        assert_eq!(snapshot.total, 50);
+        assert_eq!(snapshot.commission, Perbill::from_percent(20));
+        let state = ParachainStaking::delegator_state(2).expect("delegator is migrated");
+        assert_eq!(state.total, 30);
+        assert_eq!(state.delegations.0[0].amount, 30);
+        assert_eq!(state.requests.less_total, 10);
+        assert_eq!(state.requests.requests[&1].amount, 10);
+        assert_eq!(
+            ParachainStaking::inflation_config().expect,
+            Range {
+                min: 10,
+                ideal: 20,
+                max: 30,
+            }
+        );
+    });
+}
+
+#[test]
+fn balance_type_migration_is_aborted_on_undecodable_value() {
+    use crate::{migrations::MigrateBalanceType, Pallet, Staked};
+    use frame_support::{
+        storage::unhashed,
+        traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    };
+    ExtBuilder::default().build().execute_with(|| {
+        StorageVersion::new(0).put::<Pallet<Test>>();
+        // `Total` is already encoded with the new balance type
+        unhashed::put(&<Total<Test>>::hashed_key(), &70u128);
+        unhashed::put(&<Staked<Test>>::hashed_key_for(1), &50u64);
+
+        MigrateBalanceType::<Test, u64>::on_runtime_upgrade();
+
+        assert_eq!(
+            Pallet::<Test>::on_chain_storage_version(),
+            StorageVersion::new(0)
+        );
+        assert_eq!(
+            unhashed::get_raw(&<Staked<Test>>::hashed_key_for(1)),
+            Some(50u64.encode())
+        );
+    });
+}
+
+#[test]
+fn verify_snapshot_collator_commission_migration_works() {
+    use crate::{
+        migrations::{OldCollatorSnapshot, SnapshotCollatorCommission},
+        AtStake, Pallet,
+    };
+    use frame_support::{
+        storage::unhashed,
+        traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    };
+    ExtBuilder::default().build().execute_with(|| {
+        StorageVersion::new(1).put::<Pallet<Test>>();
+        unhashed::put(
+            &<AtStake<Test>>::hashed_key_for(1, 1),
+            &OldCollatorSnapshot::<u64, u128> {
+                bond: 20,
+                delegations: vec![Bond {
+                    owner: 2,
+                    amount: 30,
+                }],
+                total: 50,
+            },
+        );
+
+        SnapshotCollatorCommission::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            Pallet::<Test>::on_chain_storage_version(),
+            StorageVersion::new(2)
+        );
+        let snapshot = ParachainStaking::at_stake(1, 1);
+        assert_eq!(snapshot.bond, 20);
+        assert_eq!(snapshot.delegations[0].amount, 30);
+        assert_eq!(snapshot.total, 50);
+        assert_eq!(snapshot.commission, Perbill::from_percent(20));
+    });
+}
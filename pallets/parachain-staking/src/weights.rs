@@ -39,6 +39,13 @@
 // --template=./benchmarking/frame-weight-template.hbs
 // --output
 // /tmp/
+//
+// NOT BENCHMARK OUTPUT: weights of `set_inflation_decay`, `set_commission_bounds`,
+// `schedule_candidate_commission_change`, `set_auto_compound`, `set_reward_destination`,
+// `cancel_deferred_slash` and `apply_slash` are estimated. Regenerate the file from `benchmarks.rs`
+// on reference hardware before relying on them:
+//
+// make bench-parachain-staking
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -51,14 +58,14 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for parachain_staking.
 pub trait WeightInfo {
-    fn hotfix_remove_delegation_requests(x: u32) -> Weight;
-    fn hotfix_update_candidate_pool_value(x: u32) -> Weight;
     fn set_staking_expectations() -> Weight;
     fn set_inflation() -> Weight;
+    fn set_inflation_decay() -> Weight;
     fn set_parachain_bond_account() -> Weight;
     fn set_parachain_bond_reserve_percent() -> Weight;
     fn set_total_selected() -> Weight;
     fn set_collator_commission() -> Weight;
+    fn set_commission_bounds() -> Weight;
     fn set_blocks_per_round() -> Weight;
     fn join_candidates(x: u32) -> Weight;
     fn schedule_leave_candidates(x: u32) -> Weight;
@@ -69,6 +76,7 @@ pub trait WeightInfo {
     fn candidate_bond_more() -> Weight;
     fn schedule_candidate_bond_less() -> Weight;
     fn execute_candidate_bond_less() -> Weight;
+    fn schedule_candidate_commission_change() -> Weight;
     fn cancel_candidate_bond_less() -> Weight;
     fn delegate(x: u32, y: u32) -> Weight;
     fn schedule_leave_delegators() -> Weight;
@@ -81,6 +89,10 @@ pub trait WeightInfo {
     fn execute_delegator_bond_less() -> Weight;
     fn cancel_revoke_delegation() -> Weight;
     fn cancel_delegator_bond_less() -> Weight;
+    fn set_auto_compound() -> Weight;
+    fn set_reward_destination() -> Weight;
+    fn cancel_deferred_slash() -> Weight;
+    fn apply_slash(d: u32) -> Weight;
     fn round_transition_on_initialize(x: u32, y: u32) -> Weight;
     fn base_on_initialize() -> Weight;
     fn pay_one_collator_reward(y: u32) -> Weight;
@@ -89,18 +101,6 @@ pub trait WeightInfo {
 /// Weights for parachain_staking using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-    fn hotfix_remove_delegation_requests(x: u32) -> Weight {
-        (0 as Weight) // Standard Error: 3_000
-            .saturating_add((8_132_000 as Weight).saturating_mul(x as Weight))
-            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(x as Weight)))
-            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(x as Weight)))
-    }
-    fn hotfix_update_candidate_pool_value(x: u32) -> Weight {
-        (0 as Weight) // Standard Error: 147_000
-            .saturating_add((26_825_000 as Weight).saturating_mul(x as Weight))
-            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(x as Weight)))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
-    }
     fn set_staking_expectations() -> Weight {
         (20_719_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
@@ -111,6 +111,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(6 as Weight))
             .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
+    fn set_inflation_decay() -> Weight {
+        (18_652_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
     fn set_parachain_bond_account() -> Weight {
         (20_434_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
@@ -131,6 +136,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
             .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
+    fn set_commission_bounds() -> Weight {
+        (20_131_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
     fn set_blocks_per_round() -> Weight {
         (65_939_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(6 as Weight))
@@ -187,6 +197,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(9 as Weight))
             .saturating_add(T::DbWeight::get().writes(6 as Weight))
     }
+    fn schedule_candidate_commission_change() -> Weight {
+        (24_602_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
     fn cancel_candidate_bond_less() -> Weight {
         (25_564_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
@@ -252,6 +267,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(9 as Weight))
             .saturating_add(T::DbWeight::get().writes(7 as Weight))
     }
+    fn set_auto_compound() -> Weight {
+        (29_384_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_reward_destination() -> Weight {
+        (27_518_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // pending slash counts of the collator and up to 10 top delegators
+    fn cancel_deferred_slash() -> Weight {
+        (21_306_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(12 as Weight))
+            .saturating_add(T::DbWeight::get().writes(12 as Weight))
+    }
+    fn apply_slash(d: u32) -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().reads((10 as Weight).saturating_mul(d as Weight)))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes((10 as Weight).saturating_mul(d as Weight)))
+    }
     fn round_transition_on_initialize(x: u32, y: u32) -> Weight {
         (0 as Weight) // Standard Error: 4_087_000
             // Standard Error: 12_000
@@ -266,30 +305,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn pay_one_collator_reward(y: u32) -> Weight {
         (0 as Weight)
             // Standard Error: 6_000
-            .saturating_add((23_284_000 as Weight).saturating_mul(y as Weight))
-            .saturating_add(T::DbWeight::get().reads(11 as Weight))
-            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(y as Weight)))
+            .saturating_add((89_041_000 as Weight).saturating_mul(y as Weight))
+            .saturating_add(T::DbWeight::get().reads(12 as Weight))
+            .saturating_add(T::DbWeight::get().reads((10 as Weight).saturating_mul(y as Weight)))
             .saturating_add(T::DbWeight::get().writes(6 as Weight))
-            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(y as Weight)))
+            .saturating_add(T::DbWeight::get().writes((7 as Weight).saturating_mul(y as Weight)))
     }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-    fn hotfix_remove_delegation_requests(x: u32) -> Weight {
-        (0 as Weight) // Standard Error: 3_000
-            .saturating_add((8_132_000 as Weight).saturating_mul(x as Weight))
-            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(x as Weight)))
-            .saturating_add(
-                RocksDbWeight::get().writes((1 as Weight).saturating_mul(x as Weight)),
-            )
-    }
-    fn hotfix_update_candidate_pool_value(x: u32) -> Weight {
-        (0 as Weight) // Standard Error: 147_000
-            .saturating_add((26_825_000 as Weight).saturating_mul(x as Weight))
-            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(x as Weight)))
-            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
-    }
     fn set_staking_expectations() -> Weight {
         (20_719_000 as Weight)
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
@@ -300,6 +325,11 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(6 as Weight))
             .saturating_add(RocksDbWeight::get().writes(3 as Weight))
     }
+    fn set_inflation_decay() -> Weight {
+        (18_652_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
     fn set_parachain_bond_account() -> Weight {
         (20_434_000 as Weight)
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
@@ -320,6 +350,11 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
             .saturating_add(RocksDbWeight::get().writes(3 as Weight))
     }
+    fn set_commission_bounds() -> Weight {
+        (20_131_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
     fn set_blocks_per_round() -> Weight {
         (65_939_000 as Weight)
             .saturating_add(RocksDbWeight::get().reads(6 as Weight))
@@ -378,6 +413,11 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(9 as Weight))
             .saturating_add(RocksDbWeight::get().writes(6 as Weight))
     }
+    fn schedule_candidate_commission_change() -> Weight {
+        (24_602_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
     fn cancel_candidate_bond_less() -> Weight {
         (25_564_000 as Weight)
             .saturating_add(RocksDbWeight::get().reads(5 as Weight))
@@ -445,6 +485,34 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(7 as Weight))
             .saturating_add(RocksDbWeight::get().writes(4 as Weight))
     }
+    fn set_auto_compound() -> Weight {
+        (29_384_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn set_reward_destination() -> Weight {
+        (27_518_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    // pending slash counts of the collator and up to 10 top delegators
+    fn cancel_deferred_slash() -> Weight {
+        (21_306_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(12 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(12 as Weight))
+    }
+    fn apply_slash(d: u32) -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add((60_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(
+                RocksDbWeight::get().reads((10 as Weight).saturating_mul(d as Weight)),
+            )
+            .saturating_add(RocksDbWeight::get().writes(6 as Weight))
+            .saturating_add(
+                RocksDbWeight::get().writes((10 as Weight).saturating_mul(d as Weight)),
+            )
+    }
     fn round_transition_on_initialize(x: u32, y: u32) -> Weight {
         (0 as Weight) // Standard Error: 4_087_000
             // Standard Error: 12_000
@@ -461,12 +529,14 @@ impl WeightInfo for () {
     fn pay_one_collator_reward(y: u32) -> Weight {
         (0 as Weight)
             // Standard Error: 6_000
-            .saturating_add((23_284_000 as Weight).saturating_mul(y as Weight))
-            .saturating_add(RocksDbWeight::get().reads(11 as Weight))
-            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(y as Weight)))
+            .saturating_add((89_041_000 as Weight).saturating_mul(y as Weight))
+            .saturating_add(RocksDbWeight::get().reads(12 as Weight))
+            .saturating_add(
+                RocksDbWeight::get().reads((10 as Weight).saturating_mul(y as Weight)),
+            )
             .saturating_add(RocksDbWeight::get().writes(6 as Weight))
             .saturating_add(
-                RocksDbWeight::get().writes((1 as Weight).saturating_mul(y as Weight)),
+                RocksDbWeight::get().writes((7 as Weight).saturating_mul(y as Weight)),
             )
     }
 }
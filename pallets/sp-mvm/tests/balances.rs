@@ -46,10 +46,12 @@ where
 
 fn check_storage_u64<T>(address: AccountAddress, expected: T)
 where
-    T: Into<u64>,
+    T: TryInto<u64>,
 {
     let expected = StoreU64 {
-        val: expected.into(),
+        val: expected
+            .try_into()
+            .unwrap_or_else(|_| panic!("expected value doesn't fit into u64")),
     };
     let tag = StructTag {
         address,
@@ -353,7 +355,7 @@ fn check_token_total_supply() {
 }
 
 mod adapter {
-    use move_vm::io::traits::BalanceAccess;
+    use move_vm::io::traits::{Balance as VmBalance, BalanceAccess};
     use sp_mvm::balance::BalancesAdapter;
     use sp_mvm::balance::boxed::BalancesAdapter as BoxedBalancesAdapter;
     use frame_support::traits::tokens::currency::Currency;
@@ -364,6 +366,10 @@ mod adapter {
 
     pub type AccountId = <Test as frame_system::Config>::AccountId;
 
+    fn to_vm_balance(balance: Balance) -> VmBalance {
+        VmBalance::try_from(balance).expect("balance fits into VM balance type")
+    }
+
     fn test_get_balance_with<T: BalanceAccess>(adapter: &T) {
         RuntimeBuilder::new()
             .set_balances(vec![
@@ -378,7 +384,7 @@ mod adapter {
                 let expected = balances::Pallet::<Test>::free_balance(&origin);
 
                 let value = adapter.get_balance(&account, currency.symbol().as_ref());
-                assert_eq!(Some(expected), value);
+                assert_eq!(Some(to_vm_balance(expected)), value);
 
                 let missed_balance = adapter.get_balance(&account, "".as_bytes());
                 assert_eq!(missed_balance, None);
@@ -406,7 +412,7 @@ mod adapter {
                 assert_eq!(expected, to_deposit);
 
                 let value = adapter.get_balance(&account, currency.symbol().as_ref());
-                assert_eq!(Some(expected), value);
+                assert_eq!(Some(to_vm_balance(expected)), value);
             });
     }
 
@@ -425,7 +431,11 @@ mod adapter {
 
                 let expected_balance = initial_balance / 2;
 
-                adapter.sub(&account, currency.symbol().as_ref(), expected_balance);
+                adapter.sub(
+                    &account,
+                    currency.symbol().as_ref(),
+                    to_vm_balance(expected_balance),
+                );
 
                 let actual_balance = balances::Pallet::<Test>::free_balance(&origin);
 
@@ -456,7 +466,11 @@ mod adapter {
 
                 let expected_balance = initial_balance / 2;
 
-                adapter.sub(&account, currency.symbol().as_ref(), expected_balance);
+                adapter.sub(
+                    &account,
+                    currency.symbol().as_ref(),
+                    to_vm_balance(expected_balance),
+                );
 
                 let actual_balance = orml_tokens::Pallet::<Test>::free_balance(currency, &origin);
 
@@ -481,7 +495,11 @@ mod adapter {
                 let _ =
                     balances::Pallet::<Test>::deposit_creating(&pallet_account, initial_balance);
 
-                adapter.add(&account, currency.symbol().as_ref(), initial_balance);
+                adapter.add(
+                    &account,
+                    currency.symbol().as_ref(),
+                    to_vm_balance(initial_balance),
+                );
 
                 let actual_balance = balances::Pallet::<Test>::free_balance(&origin);
                 let pallet_actual_balance =
@@ -517,7 +535,11 @@ mod adapter {
                 let initial_balance =
                     orml_tokens::Pallet::<Test>::free_balance(currency, &origin);
 
-                adapter.add(&account, currency.symbol().as_ref(), initial_balance);
+                adapter.add(
+                    &account,
+                    currency.symbol().as_ref(),
+                    to_vm_balance(initial_balance),
+                );
 
                 let actual_balance = orml_tokens::Pallet::<Test>::free_balance(currency, &origin);
                 let pallet_actual_balance =
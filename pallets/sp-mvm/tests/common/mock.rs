@@ -79,9 +79,9 @@ parameter_types! {
 }
 
 pub type AccountId = sp_core::sr25519::Public;
-pub type Amount = i64;
+pub type Amount = primitives::Amount;
 pub type BlockNumber = u64;
-pub type Balance = u64;
+pub type Balance = primitives::Balance;
 
 impl system::Config for Test {
     type BaseCallFilter = frame_support::traits::Everything;
@@ -216,6 +216,24 @@ impl module_currencies::Config for Test {
     type WeightInfo = ();
     type SweepOrigin = EnsureRoot<AccountId>;
     type OnDust = ();
+    type DustAccounts = ();
+    type MaxDustSweepAccounts = ConstU32<0>;
+    type MaxBatchTransfers = ConstU32<10>;
+    type RegistryOrigin = EnsureRoot<AccountId>;
+    type StringLimit = ConstU32<32>;
+    type CurrencyIdConvert = ();
+    type BuiltInCurrencies = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CurrencyBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CurrencyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl module_currencies::BenchmarkHelper<CurrencyId> for CurrencyBenchmarkHelper {
+    fn registrable_currency() -> CurrencyId {
+        CurrencyId::ForeignAsset(0)
+    }
 }
 
 // -------- move vm pallet --------- //
@@ -236,6 +254,7 @@ parameter_types! {
     pub const DepositBase: u64 = 0;
     pub const DepositFactor: u64 = 0;
     pub const MaxSignatories: u16 = 16;
+    pub const MaxBatchCalls: u32 = 8;
 }
 
 impl groupsign::Config for Test {
@@ -244,6 +263,7 @@ impl groupsign::Config for Test {
     type Public = AccountId;
     type Signature = AnySignature;
     type MyOrigin = Origin;
+    type MaxBatchCalls = MaxBatchCalls;
     type WeightInfo = PontemWeights<Self>;
 }
 
@@ -0,0 +1,19 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+// clippy doesn't likes sp- macros
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use sp_std::prelude::*;
+
+// Describe Runtime API for Transaction Pause pallet.
+sp_api::decl_runtime_apis! {
+    pub trait TransactionPauseApiRuntime<BlockNumber> where
+        BlockNumber: codec::Codec,
+    {
+        // Get paused transactions: pallet name, function name and optional expiry block.
+        fn paused_transactions() -> Vec<(Vec<u8>, Vec<u8>, Option<BlockNumber>)>;
+
+        // Check if encoded call (or any call nested into it) is paused.
+        fn is_call_paused(encoded_call: Vec<u8>) -> Result<bool, Vec<u8>>;
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    generic::BlockId,
+    traits::{Block as BlockT},
+};
+use sp_api::ProvideRuntimeApi;
+use transaction_pause_rpc_runtime::TransactionPauseApiRuntime;
+use serde::{Serialize, Deserialize};
+use fc_rpc_core::types::Bytes;
+
+// Paused transaction struct with serde.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PausedTransaction<BlockNumber> {
+    pub pallet_name: String,
+    pub function_name: String,
+    pub expire_at: Option<BlockNumber>,
+}
+
+impl<BlockNumber> From<(Vec<u8>, Vec<u8>, Option<BlockNumber>)> for PausedTransaction<BlockNumber> {
+    fn from((pallet_name, function_name, expire_at): (Vec<u8>, Vec<u8>, Option<BlockNumber>)) -> Self {
+        Self {
+            pallet_name: String::from_utf8_lossy(&pallet_name).into(),
+            function_name: String::from_utf8_lossy(&function_name).into(),
+            expire_at,
+        }
+    }
+}
+
+// RPC calls.
+#[rpc]
+pub trait TransactionPauseApiRpc<BlockHash, BlockNumber> {
+    #[rpc(name = "transactionPause_pausedTransactions")]
+    fn paused_transactions(
+        &self,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<PausedTransaction<BlockNumber>>>;
+
+    #[rpc(name = "transactionPause_isCallPaused")]
+    fn is_call_paused(&self, encoded_call: Bytes, at: Option<BlockHash>) -> Result<bool>;
+}
+
+pub struct TransactionPauseApi<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> TransactionPauseApi<C, P> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, BlockNumber> TransactionPauseApiRpc<<Block as BlockT>::Hash, BlockNumber>
+    for TransactionPauseApi<C, Block>
+where
+    Block: BlockT,
+    BlockNumber: Codec + Serialize + Send + Sync + 'static,
+    C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: TransactionPauseApiRuntime<Block, BlockNumber>,
+{
+    fn paused_transactions(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<PausedTransaction<BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        let res = api.paused_transactions(&at).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(500),
+            message: "Error during requesting Runtime API".into(),
+            data: Some(format!("{:?}", e).into()),
+        })?;
+
+        Ok(res.into_iter().map(PausedTransaction::from).collect())
+    }
+
+    fn is_call_paused(
+        &self,
+        encoded_call: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<bool> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+        api.is_call_paused(&at, encoded_call.into_vec())
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(500),
+                message: "Error during requesting Runtime API".into(),
+                data: Some(format!("{:?}", e).into()),
+            })?
+            .map_err(|e| RpcError {
+                code: ErrorCode::InvalidParams,
+                message: "Error from method".into(),
+                data: Some(
+                    std::str::from_utf8(e.as_slice())
+                        .unwrap_or("can't decode error")
+                        .into(),
+                ),
+            })
+    }
+}
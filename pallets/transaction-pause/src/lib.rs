@@ -7,8 +7,15 @@ use frame_support::{
     transactional,
 };
 use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension, Zero},
+    transaction_validity::{
+        InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    },
+};
 use sp_std::{prelude::*, vec::Vec};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -16,17 +23,92 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The wildcard symbol: function name `*` pauses the whole pallet, `prefix*` pauses all
+/// functions starting with `prefix`.
+pub const WILDCARD: u8 = b'*';
+
+/// Check if function name is a wildcard pattern.
+pub fn is_pattern(function_name: &[u8]) -> bool {
+    function_name.last() == Some(&WILDCARD)
+}
+
+/// Maximum depth of nested calls checked by `CheckPausedTransaction`.
+pub const MAX_NESTED_CALLS_DEPTH: u32 = 8;
+
+/// Extracts calls wrapped into another call (e.g. groupsign or multisig calls).
+pub trait NestedCalls<Call> {
+    /// Returns calls dispatched by the call, empty if the call doesn't wrap other calls.
+    fn nested_calls(call: &Call) -> Vec<Call>;
+}
+
+impl<Call> NestedCalls<Call> for () {
+    fn nested_calls(_call: &Call) -> Vec<Call> {
+        vec![]
+    }
+}
+
+/// Limit of calls of the function.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RateLimit<BlockNumber> {
+    /// At most `max_calls` calls of the function in a block.
+    PerBlock { max_calls: u32 },
+    /// At most `max_calls` calls of the function by an account during `period` blocks.
+    PerAccount { max_calls: u32, period: BlockNumber },
+}
+
+/// Data passed by `CheckPausedTransaction` from `pre_dispatch` to `post_dispatch`.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum CheckPausedPre<AccountId> {
+    /// The transaction is signed by an exempt account, its calls pass `PausedTransactionFilter`.
+    Exempt,
+    /// The transaction dispatches rate limited functions.
+    RateLimited {
+        /// The transaction signer.
+        who: AccountId,
+        /// Rate limited functions dispatched by the transaction, with the number of their calls.
+        rate_limited: Vec<((Vec<u8>, Vec<u8>), u32)>,
+    },
+}
+
+/// Check if function name matches the wildcard pattern.
+pub fn matches_pattern(pattern: &[u8], function_name: &[u8]) -> bool {
+    match pattern.split_last() {
+        Some((&WILDCARD, prefix)) => function_name.starts_with(prefix),
+        _ => pattern == function_name,
+    }
+}
+
 #[frame_support::pallet]
 pub mod module {
     use super::*;
 
+    /// The current storage version.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-        /// The origin which may set filter.
+        /// The origin which may unpause transactions and manage exempt accounts.
         type UpdateOrigin: EnsureOrigin<Self::Origin>;
 
+        /// The origin which may pause transactions (e.g. faster emergency origin).
+        type PauseOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Runtime calls metadata, used to validate paused pallet and function names.
+        type RuntimeCalls: GetCallMetadata;
+
+        /// Calls wrapped into other calls, checked by `CheckPausedTransaction`.
+        type NestedCalls: NestedCalls<<Self as frame_system::Config>::Call>;
+
+        /// Calls which are never paused nor rate limited (e.g. System and Timestamp calls).
+        type CoreCalls: Contains<<Self as frame_system::Config>::Call>;
+
+        /// Maximum number of account call counts checked for ended periods in `on_idle`,
+        /// zero disables the pruning.
+        #[pallet::constant]
+        type MaxPrunedCallCounts: Get<u32>;
+
         /// Weight information for the extrinsics in this module.
         type WeightInfo: WeightInfo;
     }
@@ -37,6 +119,14 @@ pub mod module {
         CannotPause,
         /// invalid character encoding
         InvalidCharacter,
+        /// expiry block is not in the future
+        InvalidExpiry,
+        /// wildcard is allowed only at the end of function name
+        InvalidPattern,
+        /// rate limit must allow at least one call during non-empty period
+        InvalidRateLimit,
+        /// runtime has no such pallet or function
+        UnknownCall,
     }
 
     #[pallet::event]
@@ -46,22 +136,121 @@ pub mod module {
         TransactionPaused(Vec<u8>, Vec<u8>),
         /// Unpaused transaction . \[pallet_name_bytes, function_name_bytes\]
         TransactionUnpaused(Vec<u8>, Vec<u8>),
+        /// Account exempted from pauses . \[account\]
+        ExemptAccountAdded(T::AccountId),
+        /// Account exemption removed . \[account\]
+        ExemptAccountRemoved(T::AccountId),
+        /// Rate limit set or removed . \[pallet_name_bytes, function_name_bytes, limit\]
+        RateLimitSet(Vec<u8>, Vec<u8>, Option<RateLimit<T::BlockNumber>>),
+        /// Rate limit reached, further calls rejected until block or period ends . \[pallet_name_bytes, function_name_bytes, account\]
+        RateLimitReached(Vec<u8>, Vec<u8>, Option<T::AccountId>),
     }
 
     /// The paused transaction map
     ///
-    /// map (PalletNameBytes, FunctionNameBytes) => Option<()>
+    /// map (PalletNameBytes, FunctionNameBytes) => Option<Option<ExpiryBlockNumber>>
     #[pallet::storage]
     #[pallet::getter(fn paused_transactions)]
     pub type PausedTransactions<T: Config> =
-        StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
+        StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), Option<T::BlockNumber>, OptionQuery>;
+
+    /// The paused transactions which should be unpaused at the block.
+    ///
+    /// map ExpiryBlockNumber => Vec<(PalletNameBytes, FunctionNameBytes)>
+    #[pallet::storage]
+    #[pallet::getter(fn pause_expiries)]
+    pub type PauseExpiries<T: Config> =
+        StorageMap<_, Twox64Concat, T::BlockNumber, Vec<(Vec<u8>, Vec<u8>)>, ValueQuery>;
+
+    /// The paused wildcard patterns of pallet functions.
+    ///
+    /// Each pattern is also stored in `PausedTransactions` with its expiry.
+    ///
+    /// map PalletNameBytes => Vec<FunctionPatternBytes>
+    #[pallet::storage]
+    #[pallet::getter(fn paused_patterns)]
+    pub type PausedPatterns<T: Config> =
+        StorageMap<_, Twox64Concat, Vec<u8>, Vec<Vec<u8>>, ValueQuery>;
+
+    /// The rate limited transactions.
+    ///
+    /// map (PalletNameBytes, FunctionNameBytes) => Option<RateLimit>
+    #[pallet::storage]
+    #[pallet::getter(fn rate_limits)]
+    pub type RateLimits<T: Config> =
+        StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), RateLimit<T::BlockNumber>, OptionQuery>;
+
+    /// Calls of the rate limited transaction in the block.
+    ///
+    /// map (PalletNameBytes, FunctionNameBytes) => (BlockNumber, Calls)
+    #[pallet::storage]
+    #[pallet::getter(fn block_call_counts)]
+    pub type BlockCallCounts<T: Config> =
+        StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (T::BlockNumber, u32), ValueQuery>;
+
+    /// Calls of the rate limited transaction by account during the period.
+    ///
+    /// double_map (PalletNameBytes, FunctionNameBytes), AccountId => (PeriodStartBlockNumber, Calls)
+    #[pallet::storage]
+    #[pallet::getter(fn account_call_counts)]
+    pub type AccountCallCounts<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        (Vec<u8>, Vec<u8>),
+        Blake2_128Concat,
+        T::AccountId,
+        (T::BlockNumber, u32),
+        ValueQuery,
+    >;
+
+    /// Position of the call counts pruning in `on_idle`: the raw storage key of the last checked
+    /// count, `None` to start from the first one.
+    #[pallet::storage]
+    #[pallet::getter(fn call_counts_prune_cursor)]
+    pub type CallCountsPruneCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+    /// The accounts which may dispatch paused transactions.
+    ///
+    /// map AccountId => Option<()>
+    #[pallet::storage]
+    #[pallet::getter(fn exempt_accounts)]
+    pub type ExemptAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Whether the applied extrinsic is signed by an exempt account.
+    ///
+    /// Set by `CheckPausedTransaction` for the dispatch of the extrinsic only.
+    #[pallet::storage]
+    #[pallet::getter(fn dispatching_exempt)]
+    pub type DispatchingExempt<T: Config> = StorageValue<_, bool, ValueQuery>;
 
     #[pallet::pallet]
     #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+        /// Unpause transactions which pause expires at the current block.
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let expired = PauseExpiries::<T>::take(now);
+            let count = expired.len() as Weight;
+
+            for (pallet_name, function_name) in expired {
+                let key = (pallet_name, function_name);
+                // Pause could be removed or prolonged since expiry was scheduled.
+                if PausedTransactions::<T>::get(&key) == Some(Some(now)) {
+                    Self::_unpause_transaction(key.0, key.1);
+                }
+            }
+
+            T::DbWeight::get().reads_writes(1 + 2 * count, 1 + 2 * count)
+        }
+
+        fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            Self::prune_call_counts_on_idle(now, remaining_weight)
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -71,9 +260,10 @@ pub mod module {
             origin: OriginFor<T>,
             pallet_name: Vec<u8>,
             function_name: Vec<u8>,
+            expire_at: Option<T::BlockNumber>,
         ) -> DispatchResult {
-            T::UpdateOrigin::ensure_origin(origin)?;
-            Self::_pause_transaction(pallet_name, function_name)
+            T::PauseOrigin::ensure_origin(origin)?;
+            Self::_pause_transaction(pallet_name, function_name, expire_at)
         }
 
         #[pallet::weight(T::WeightInfo::unpause_transaction())]
@@ -84,9 +274,67 @@ pub mod module {
             function_name: Vec<u8>,
         ) -> DispatchResult {
             T::UpdateOrigin::ensure_origin(origin)?;
-            if PausedTransactions::<T>::take((&pallet_name, &function_name)).is_some() {
-                Self::deposit_event(Event::TransactionUnpaused(pallet_name, function_name));
-            };
+            Self::_unpause_transaction(pallet_name, function_name);
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::add_exempt_account())]
+        pub fn add_exempt_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            if !ExemptAccounts::<T>::contains_key(&account) {
+                ExemptAccounts::<T>::insert(&account, ());
+                Self::deposit_event(Event::ExemptAccountAdded(account));
+            }
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::remove_exempt_account())]
+        pub fn remove_exempt_account(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            if ExemptAccounts::<T>::take(&account).is_some() {
+                Self::deposit_event(Event::ExemptAccountRemoved(account));
+            }
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::set_rate_limit())]
+        #[transactional]
+        pub fn set_rate_limit(
+            origin: OriginFor<T>,
+            pallet_name: Vec<u8>,
+            function_name: Vec<u8>,
+            limit: Option<RateLimit<T::BlockNumber>>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::ensure_can_pause(&pallet_name)?;
+
+            let key = (pallet_name, function_name);
+            match limit {
+                Some(limit) => {
+                    ensure!(
+                        !is_pattern(&key.1) && Self::is_known_call(&key.0, &key.1),
+                        Error::<T>::UnknownCall
+                    );
+                    let is_valid = match limit {
+                        RateLimit::PerBlock { max_calls } => max_calls > 0,
+                        RateLimit::PerAccount { max_calls, period } => {
+                            max_calls > 0 && !period.is_zero()
+                        }
+                    };
+                    ensure!(is_valid, Error::<T>::InvalidRateLimit);
+                    RateLimits::<T>::insert(&key, limit);
+                }
+                None => {
+                    RateLimits::<T>::remove(&key);
+                    BlockCallCounts::<T>::remove(&key);
+                    AccountCallCounts::<T>::remove_prefix(&key, None);
+                }
+            }
+
+            Self::deposit_event(Event::RateLimitSet(key.0, key.1, limit));
             Ok(())
         }
     }
@@ -95,21 +343,39 @@ pub mod module {
         pub fn _pause_transaction(
             pallet_name: Vec<u8>,
             function_name: Vec<u8>,
+            expire_at: Option<T::BlockNumber>,
         ) -> DispatchResult {
-            // not allowed to pause calls of this pallet to ensure safe
-            let pallet_name_string =
-                sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+            Self::ensure_can_pause(&pallet_name)?;
 
+            if let Some((_, prefix)) = function_name.split_last() {
+                ensure!(!prefix.contains(&WILDCARD), Error::<T>::InvalidPattern);
+            }
             ensure!(
-                pallet_name_string != <Self as PalletInfoAccess>::name(),
-                Error::<T>::CannotPause
+                Self::is_known_call(&pallet_name, &function_name),
+                Error::<T>::UnknownCall
             );
 
+            if let Some(expire_at) = expire_at {
+                ensure!(
+                    expire_at > frame_system::Pallet::<T>::block_number(),
+                    Error::<T>::InvalidExpiry
+                );
+            }
+
             PausedTransactions::<T>::mutate_exists(
                 (pallet_name.clone(), function_name.clone()),
                 |maybe_paused| {
-                    if maybe_paused.is_none() {
-                        *maybe_paused = Some(());
+                    if *maybe_paused != Some(expire_at) {
+                        if maybe_paused.is_none() && is_pattern(&function_name) {
+                            PausedPatterns::<T>::append(&pallet_name, function_name.clone());
+                        }
+                        *maybe_paused = Some(expire_at);
+                        if let Some(expire_at) = expire_at {
+                            PauseExpiries::<T>::append(
+                                expire_at,
+                                (pallet_name.clone(), function_name.clone()),
+                            );
+                        }
                         Self::deposit_event(Event::TransactionPaused(pallet_name, function_name));
                     }
                 },
@@ -117,6 +383,151 @@ pub mod module {
 
             Ok(())
         }
+
+        fn ensure_can_pause(pallet_name: &[u8]) -> DispatchResult {
+            // not allowed to pause calls of this pallet to ensure safe
+            let pallet_name_string =
+                sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+
+            ensure!(
+                pallet_name_string != <Self as PalletInfoAccess>::name(),
+                Error::<T>::CannotPause
+            );
+
+            Ok(())
+        }
+
+        /// Check if the runtime has the pallet and the function,
+        /// or at least one function matching the wildcard pattern.
+        pub fn is_known_call(pallet_name: &[u8], function_name: &[u8]) -> bool {
+            let pallet_name = match sp_std::str::from_utf8(pallet_name) {
+                Ok(pallet_name) => pallet_name,
+                Err(_) => return false,
+            };
+
+            // `get_call_names` panics on unknown pallet.
+            if !T::RuntimeCalls::get_module_names().contains(&pallet_name) {
+                return false;
+            }
+
+            T::RuntimeCalls::get_call_names(pallet_name)
+                .iter()
+                .any(|call_name| matches_pattern(function_name, call_name.as_bytes()))
+        }
+
+        /// Check if `calls` more calls of the function exceed its rate limit in the current block,
+        /// and for the account if provided.
+        pub fn exceeds_rate_limit(
+            key: &(Vec<u8>, Vec<u8>),
+            who: Option<&T::AccountId>,
+            calls: u32,
+        ) -> bool {
+            let now = frame_system::Pallet::<T>::block_number();
+            match (RateLimits::<T>::get(key), who) {
+                (Some(RateLimit::PerBlock { max_calls }), _) => {
+                    let (block, counted) = BlockCallCounts::<T>::get(key);
+                    let counted = if block == now { counted } else { 0 };
+                    counted.saturating_add(calls) > max_calls
+                }
+                (Some(RateLimit::PerAccount { max_calls, period }), Some(who)) => {
+                    let (period_start, counted) = AccountCallCounts::<T>::get(key, who);
+                    let counted = if now < period_start.saturating_add(period) {
+                        counted
+                    } else {
+                        0
+                    };
+                    counted.saturating_add(calls) > max_calls
+                }
+                _ => false,
+            }
+        }
+
+        /// Count the dispatched calls of the rate limited function.
+        pub fn note_rate_limited_calls(key: (Vec<u8>, Vec<u8>), who: &T::AccountId, count: u32) {
+            let now = frame_system::Pallet::<T>::block_number();
+            let reached = match RateLimits::<T>::get(&key) {
+                Some(RateLimit::PerBlock { max_calls }) => {
+                    BlockCallCounts::<T>::mutate(&key, |(block, calls)| {
+                        if *block != now {
+                            *block = now;
+                            *calls = 0;
+                        }
+                        *calls = calls.saturating_add(count);
+                        (*calls >= max_calls).then(|| None)
+                    })
+                }
+                Some(RateLimit::PerAccount { max_calls, period }) => {
+                    AccountCallCounts::<T>::mutate(&key, who, |(period_start, calls)| {
+                        if now >= period_start.saturating_add(period) {
+                            // Periods are aligned to multiples of `period`.
+                            *period_start = now - now % period;
+                            *calls = 0;
+                        }
+                        *calls = calls.saturating_add(count);
+                        (*calls >= max_calls).then(|| Some(who.clone()))
+                    })
+                }
+                None => None,
+            };
+
+            if let Some(account) = reached {
+                Self::deposit_event(Event::RateLimitReached(key.0, key.1, account));
+            }
+        }
+
+        /// Remove the account call counts of ended periods following `CallCountsPruneCursor`
+        /// within `remaining_weight`.
+        ///
+        /// Counts are walked once per pruning, starting over after the last one.
+        fn prune_call_counts_on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            let base_weight = T::WeightInfo::prune_call_counts(0);
+            let count_weight = T::WeightInfo::prune_call_counts(1)
+                .saturating_sub(base_weight)
+                .max(1);
+            let limit = (remaining_weight.saturating_sub(base_weight) / count_weight)
+                .min(T::MaxPrunedCallCounts::get().into()) as u32;
+            if limit.is_zero() {
+                return 0;
+            }
+
+            let mut iter = match CallCountsPruneCursor::<T>::get() {
+                Some(cursor) => AccountCallCounts::<T>::iter_from(cursor),
+                None => AccountCallCounts::<T>::iter(),
+            };
+            let counts: Vec<_> = iter.by_ref().take(limit as usize).collect();
+            let cursor = (counts.len() == limit as usize).then(|| iter.last_raw_key().to_vec());
+            for (key, who, (period_start, _)) in counts {
+                // Counts left by a rate limit changed to per block count nothing.
+                let ended = match RateLimits::<T>::get(&key) {
+                    Some(RateLimit::PerAccount { period, .. }) => {
+                        now >= period_start.saturating_add(period)
+                    }
+                    _ => true,
+                };
+                if ended {
+                    AccountCallCounts::<T>::remove(&key, &who);
+                }
+            }
+            CallCountsPruneCursor::<T>::set(cursor);
+
+            T::WeightInfo::prune_call_counts(limit)
+        }
+
+        pub fn _unpause_transaction(pallet_name: Vec<u8>, function_name: Vec<u8>) {
+            if PausedTransactions::<T>::take((&pallet_name, &function_name)).is_some() {
+                if is_pattern(&function_name) {
+                    PausedPatterns::<T>::mutate_exists(&pallet_name, |maybe_patterns| {
+                        if let Some(patterns) = maybe_patterns {
+                            patterns.retain(|pattern| *pattern != function_name);
+                            if patterns.is_empty() {
+                                *maybe_patterns = None;
+                            }
+                        }
+                    });
+                }
+                Self::deposit_event(Event::TransactionUnpaused(pallet_name, function_name));
+            }
+        }
     }
 
     /// Genesis configuration.
@@ -125,8 +536,8 @@ pub mod module {
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub _phantom: std::marker::PhantomData<T>,
-        // The vector contains pallet name and function name.
-        pub paused: Vec<(Vec<u8>, Vec<u8>)>,
+        // The vector contains pallet name, function name and optional expiry block.
+        pub paused: Vec<(Vec<u8>, Vec<u8>, Option<T::BlockNumber>)>,
     }
 
     #[cfg(feature = "std")]
@@ -143,9 +554,13 @@ pub mod module {
     #[pallet::genesis_build]
     impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
         fn build(&self) {
-            for (pallet_name, function_name) in &self.paused {
-                Pallet::<T>::_pause_transaction(pallet_name.to_vec(), function_name.to_vec())
-                    .expect("can't register paused extrinsic in genesis");
+            for (pallet_name, function_name, expire_at) in &self.paused {
+                Pallet::<T>::_pause_transaction(
+                    pallet_name.to_vec(),
+                    function_name.to_vec(),
+                    *expire_at,
+                )
+                .expect("can't register paused extrinsic in genesis");
             }
         }
     }
@@ -157,10 +572,230 @@ where
     <T as frame_system::Config>::Call: GetCallMetadata,
 {
     fn contains(call: &T::Call) -> bool {
+        if T::CoreCalls::contains(call) {
+            return false;
+        }
+
+        let key = Pallet::<T>::call_key(call);
+        let is_paused =
+            Pallet::<T>::is_key_paused(&key) || Pallet::<T>::exceeds_rate_limit(&key, None, 1);
+
+        // Transactions of exempt accounts are marked by `CheckPausedTransaction`.
+        is_paused && !DispatchingExempt::<T>::get()
+    }
+}
+
+impl<T: Config> Pallet<T>
+where
+    <T as frame_system::Config>::Call: GetCallMetadata,
+{
+    /// Get all paused transactions: pallet name, function name and optional expiry block.
+    pub fn paused_transactions_list() -> Vec<(Vec<u8>, Vec<u8>, Option<T::BlockNumber>)> {
+        PausedTransactions::<T>::iter()
+            .map(|((pallet_name, function_name), expire_at)| {
+                (pallet_name, function_name, expire_at)
+            })
+            .collect()
+    }
+
+    /// Check if the call or any call nested into it is paused.
+    pub fn is_paused(call: &<T as frame_system::Config>::Call) -> bool {
+        Self::is_paused_nested(call, 0)
+    }
+
+    /// Get rate limited functions of the call and calls nested into it,
+    /// with the number of their calls.
+    pub fn rate_limited_calls(
+        call: &<T as frame_system::Config>::Call,
+    ) -> Vec<((Vec<u8>, Vec<u8>), u32)> {
+        let mut rate_limited = vec![];
+        Self::collect_rate_limited(call, 0, &mut rate_limited);
+        rate_limited
+    }
+
+    fn call_key(call: &<T as frame_system::Config>::Call) -> (Vec<u8>, Vec<u8>) {
         let CallMetadata {
             function_name,
             pallet_name,
         } = call.get_call_metadata();
-        PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), function_name.as_bytes()))
+        (
+            pallet_name.as_bytes().to_vec(),
+            function_name.as_bytes().to_vec(),
+        )
+    }
+
+    fn is_key_paused((pallet_name, function_name): &(Vec<u8>, Vec<u8>)) -> bool {
+        PausedTransactions::<T>::contains_key((pallet_name, function_name))
+            || PausedPatterns::<T>::get(pallet_name)
+                .iter()
+                .any(|pattern| matches_pattern(pattern, function_name))
+    }
+
+    fn is_paused_nested(call: &<T as frame_system::Config>::Call, depth: u32) -> bool {
+        if T::CoreCalls::contains(call) {
+            return false;
+        }
+
+        if Self::is_key_paused(&Self::call_key(call)) {
+            return true;
+        }
+
+        // Too deep nesting can't be checked cheaply, reject it.
+        if depth >= MAX_NESTED_CALLS_DEPTH {
+            return true;
+        }
+
+        T::NestedCalls::nested_calls(call)
+            .iter()
+            .any(|nested| Self::is_paused_nested(nested, depth + 1))
+    }
+
+    fn collect_rate_limited(
+        call: &<T as frame_system::Config>::Call,
+        depth: u32,
+        rate_limited: &mut Vec<((Vec<u8>, Vec<u8>), u32)>,
+    ) {
+        if T::CoreCalls::contains(call) {
+            return;
+        }
+
+        let key = Self::call_key(call);
+        if RateLimits::<T>::contains_key(&key) {
+            match rate_limited.iter_mut().find(|(limited, _)| *limited == key) {
+                Some((_, calls)) => *calls = calls.saturating_add(1),
+                None => rate_limited.push((key, 1)),
+            }
+        }
+
+        // Too deep nesting is rejected as paused.
+        if depth >= MAX_NESTED_CALLS_DEPTH {
+            return;
+        }
+
+        for nested in T::NestedCalls::nested_calls(call) {
+            Self::collect_rate_limited(&nested, depth + 1, rate_limited);
+        }
+    }
+}
+
+/// Rejects paused and rate limited transactions during transaction pool validation,
+/// so they are not included into block and no fees are charged.
+/// Also counts dispatched calls of rate limited transactions,
+/// and lets calls of exempt accounts pass `PausedTransactionFilter` during their dispatch.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckPausedTransaction<T: Config + Send + Sync>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckPausedTransaction<T> {
+    /// Create new `SignedExtension` to check paused transactions.
+    pub fn new() -> Self {
+        Self(sp_std::marker::PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> Default for CheckPausedTransaction<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckPausedTransaction<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "CheckPausedTransaction")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckPausedTransaction<T>
+where
+    <T as frame_system::Config>::Call: GetCallMetadata,
+{
+    const IDENTIFIER: &'static str = "CheckPausedTransaction";
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::Call;
+    type AdditionalSigned = ();
+    type Pre = Option<CheckPausedPre<T::AccountId>>;
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        if ExemptAccounts::<T>::contains_key(who) {
+            return Ok(ValidTransaction::default());
+        }
+
+        if Pallet::<T>::is_paused(call) {
+            return Err(InvalidTransaction::Call.into());
+        }
+
+        // Rate limited transaction could become valid in the next block or period.
+        // All calls nested into the transaction count, so a batch can't exceed the limit.
+        let exceeds_rate_limit = Pallet::<T>::rate_limited_calls(call)
+            .iter()
+            .any(|(key, calls)| Pallet::<T>::exceeds_rate_limit(key, Some(who), *calls));
+        if exceeds_rate_limit {
+            return Err(InvalidTransaction::ExhaustsResources.into());
+        }
+
+        Ok(ValidTransaction::default())
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        self.validate(who, call, info, len)?;
+
+        // Calls of exempt accounts are not counted.
+        if ExemptAccounts::<T>::contains_key(who) {
+            DispatchingExempt::<T>::put(true);
+            return Ok(Some(CheckPausedPre::Exempt));
+        }
+
+        let rate_limited = Pallet::<T>::rate_limited_calls(call);
+        if rate_limited.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(CheckPausedPre::RateLimited {
+            who: who.clone(),
+            rate_limited,
+        }))
+    }
+
+    fn post_dispatch(
+        pre: Self::Pre,
+        _info: &DispatchInfoOf<Self::Call>,
+        _post_info: &PostDispatchInfoOf<Self::Call>,
+        _len: usize,
+        _result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        match pre {
+            Some(CheckPausedPre::Exempt) => DispatchingExempt::<T>::kill(),
+            Some(CheckPausedPre::RateLimited { who, rate_limited }) => {
+                // Calls are counted after dispatch, so the filter allows the call which reaches the limit.
+                for (key, calls) in rate_limited {
+                    Pallet::<T>::note_rate_limited_calls(key, &who, calls);
+                }
+            }
+            None => {}
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,83 @@
+//! Storage migrations for the transaction pause pallet.
+
+use crate::{
+    AccountCallCounts, BlockCallCounts, Config, Pallet, PausedTransactions, RateLimits,
+    STORAGE_VERSION,
+};
+use frame_support::{
+    pallet_prelude::PhantomData,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use sp_std::vec::Vec;
+
+/// Migrates `PausedTransactions` values from `()` to the optional expiry block.
+///
+/// All existing pauses are kept without expiry.
+pub struct AddPauseExpiry<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for AddPauseExpiry<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut migrated: Weight = 0;
+        PausedTransactions::<T>::translate::<(), _>(|_, ()| {
+            migrated += 1;
+            Some(None)
+        });
+        StorageVersion::new(1).put::<Pallet<T>>();
+
+        T::DbWeight::get().reads_writes(migrated + 1, migrated + 1)
+    }
+}
+
+/// Removes pauses and rate limits of pallets and functions missing in the runtime.
+///
+/// Such entries are left by typos or by runtime upgrades removing calls, and affect nothing.
+/// The migration is idempotent, so could be kept in the runtime for all upgrades.
+pub struct RemoveUnknownCalls<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for RemoveUnknownCalls<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut reads: Weight = 0;
+        let mut writes: Weight = 0;
+
+        let unknown_pauses: Vec<_> = PausedTransactions::<T>::iter_keys()
+            .inspect(|_| reads += 1)
+            .filter(|(pallet_name, function_name)| {
+                !Pallet::<T>::is_known_call(pallet_name, function_name)
+            })
+            .collect();
+        for (pallet_name, function_name) in unknown_pauses {
+            log::warn!(
+                target: "runtime::transaction-pause",
+                "removing pause of unknown call {}::{}",
+                sp_std::str::from_utf8(&pallet_name).unwrap_or("<invalid>"),
+                sp_std::str::from_utf8(&function_name).unwrap_or("<invalid>"),
+            );
+            Pallet::<T>::_unpause_transaction(pallet_name, function_name);
+            writes += 2;
+        }
+
+        let unknown_limits: Vec<_> = RateLimits::<T>::iter_keys()
+            .inspect(|_| reads += 1)
+            .filter(|(pallet_name, function_name)| {
+                !Pallet::<T>::is_known_call(pallet_name, function_name)
+            })
+            .collect();
+        for key in unknown_limits {
+            log::warn!(
+                target: "runtime::transaction-pause",
+                "removing rate limit of unknown call {}::{}",
+                sp_std::str::from_utf8(&key.0).unwrap_or("<invalid>"),
+                sp_std::str::from_utf8(&key.1).unwrap_or("<invalid>"),
+            );
+            RateLimits::<T>::remove(&key);
+            BlockCallCounts::<T>::remove(&key);
+            AccountCallCounts::<T>::remove_prefix(&key, None);
+            writes += 3;
+        }
+
+        T::DbWeight::get().reads_writes(reads + writes, writes)
+    }
+}
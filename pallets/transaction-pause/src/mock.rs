@@ -3,7 +3,7 @@
 use super::*;
 use frame_support::{
     construct_runtime, ord_parameter_types, parameter_types,
-    traits::{Everything, Nothing, ConstU32},
+    traits::{Contains, Everything, Nothing, ConstU32, Hooks, SortedMembers},
 };
 use frame_system::{EnsureSignedBy};
 use orml_traits::parameter_type_with_key;
@@ -89,9 +89,46 @@ ord_parameter_types! {
     pub const One: AccountId = 1;
 }
 
+/// Accounts allowed to pause transactions.
+pub struct PauseMembers;
+impl SortedMembers<AccountId> for PauseMembers {
+    fn sorted_members() -> Vec<AccountId> {
+        vec![1, 2]
+    }
+}
+
+/// Calls which are never paused.
+pub struct CoreCalls;
+impl Contains<Call> for CoreCalls {
+    fn contains(call: &Call) -> bool {
+        matches!(
+            call,
+            Call::System(frame_system::Call::remark_with_event { .. })
+        )
+    }
+}
+
+/// Remarks of encoded calls act as batches of the calls.
+pub struct RemarkedCalls;
+impl NestedCalls<Call> for RemarkedCalls {
+    fn nested_calls(call: &Call) -> Vec<Call> {
+        match call {
+            Call::System(frame_system::Call::remark { remark }) => {
+                Vec::<Call>::decode(&mut &remark[..]).unwrap_or_default()
+            }
+            _ => vec![],
+        }
+    }
+}
+
 impl Config for Runtime {
     type Event = Event;
     type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+    type PauseOrigin = EnsureSignedBy<PauseMembers, AccountId>;
+    type RuntimeCalls = Call;
+    type NestedCalls = RemarkedCalls;
+    type CoreCalls = CoreCalls;
+    type MaxPrunedCallCounts = ConstU32<2>;
     type WeightInfo = ();
 }
 
@@ -128,3 +165,11 @@ impl ExtBuilder {
         t.into()
     }
 }
+
+/// Roll till the block, initializing the pallet at every block.
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        TransactionPause::on_initialize(System::block_number());
+    }
+}
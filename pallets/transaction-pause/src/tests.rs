@@ -26,7 +26,8 @@ fn pause_transaction_work() {
             TransactionPause::pause_transaction(
                 Origin::signed(5),
                 b"Balances".to_vec(),
-                b"transfer".to_vec()
+                b"transfer".to_vec(),
+                None
             ),
             BadOrigin
         );
@@ -38,7 +39,8 @@ fn pause_transaction_work() {
         assert_ok!(TransactionPause::pause_transaction(
             Origin::signed(1),
             b"Balances".to_vec(),
-            b"transfer".to_vec()
+            b"transfer".to_vec(),
+            None
         ));
         System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused(
             b"Balances".to_vec(),
@@ -46,14 +48,15 @@ fn pause_transaction_work() {
         )));
         assert_eq!(
             TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
-            Some(())
+            Some(None)
         );
 
         assert_noop!(
             TransactionPause::pause_transaction(
                 Origin::signed(1),
                 b"TransactionPause".to_vec(),
-                b"pause_transaction".to_vec()
+                b"pause_transaction".to_vec(),
+                None
             ),
             Error::<Runtime>::CannotPause
         );
@@ -61,15 +64,20 @@ fn pause_transaction_work() {
             TransactionPause::pause_transaction(
                 Origin::signed(1),
                 b"TransactionPause".to_vec(),
-                b"some_other_call".to_vec()
+                b"some_other_call".to_vec(),
+                None
             ),
             Error::<Runtime>::CannotPause
         );
-        assert_ok!(TransactionPause::pause_transaction(
-            Origin::signed(1),
-            b"OtherPallet".to_vec(),
-            b"pause_transaction".to_vec()
-        ));
+        assert_noop!(
+            TransactionPause::pause_transaction(
+                Origin::signed(1),
+                b"OtherPallet".to_vec(),
+                b"pause_transaction".to_vec(),
+                None
+            ),
+            Error::<Runtime>::UnknownCall
+        );
     });
 }
 
@@ -81,11 +89,12 @@ fn unpause_transaction_work() {
         assert_ok!(TransactionPause::pause_transaction(
             Origin::signed(1),
             b"Balances".to_vec(),
-            b"transfer".to_vec()
+            b"transfer".to_vec(),
+            None
         ));
         assert_eq!(
             TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
-            Some(())
+            Some(None)
         );
 
         assert_noop!(
@@ -125,12 +134,14 @@ fn paused_transaction_filter_work() {
         assert_ok!(TransactionPause::pause_transaction(
             Origin::signed(1),
             b"Balances".to_vec(),
-            b"transfer".to_vec()
+            b"transfer".to_vec(),
+            None
         ));
         assert_ok!(TransactionPause::pause_transaction(
             Origin::signed(1),
             b"Tokens".to_vec(),
-            b"transfer".to_vec()
+            b"transfer".to_vec(),
+            None
         ));
         assert!(PausedTransactionFilter::<Runtime>::contains(
             BALANCE_TRANSFER
@@ -156,3 +167,854 @@ fn paused_transaction_filter_work() {
         ));
     });
 }
+
+#[test]
+fn pause_transaction_with_expiry_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            TransactionPause::pause_transaction(
+                Origin::signed(1),
+                b"Balances".to_vec(),
+                b"transfer".to_vec(),
+                Some(1)
+            ),
+            Error::<Runtime>::InvalidExpiry
+        );
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            Some(3)
+        ));
+        assert_eq!(
+            TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+            Some(Some(3))
+        );
+        assert!(PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+
+        run_to_block(2);
+        assert!(PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+
+        run_to_block(3);
+        System::assert_last_event(Event::TransactionPause(crate::Event::TransactionUnpaused(
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+        )));
+        assert_eq!(
+            TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+            None
+        );
+        assert_eq!(TransactionPause::pause_expiries(3), vec![]);
+        assert!(!PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+    });
+}
+
+#[test]
+fn prolonged_pause_is_not_expired_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            Some(3)
+        ));
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            None
+        ));
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Tokens".to_vec(),
+            b"transfer".to_vec(),
+            Some(3)
+        ));
+        assert_ok!(TransactionPause::unpause_transaction(
+            Origin::signed(1),
+            b"Tokens".to_vec(),
+            b"transfer".to_vec()
+        ));
+
+        run_to_block(3);
+        assert_eq!(
+            TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+            Some(None)
+        );
+        assert!(PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+        // Only explicit unpause of `Tokens::transfer` emitted the event.
+        assert_eq!(
+            System::events()
+                .iter()
+                .filter(|record| matches!(
+                    record.event,
+                    Event::TransactionPause(crate::Event::TransactionUnpaused(..))
+                ))
+                .count(),
+            1
+        );
+    });
+}
+
+#[test]
+fn add_pause_expiry_migration_work() {
+    use frame_support::{
+        storage::unhashed,
+        traits::{OnRuntimeUpgrade, StorageVersion},
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let key = (b"Balances".to_vec(), b"transfer".to_vec());
+        unhashed::put_raw(&PausedTransactions::<Runtime>::hashed_key_for(&key), &[]);
+        StorageVersion::new(0).put::<TransactionPause>();
+
+        crate::migrations::AddPauseExpiry::<Runtime>::on_runtime_upgrade();
+
+        assert_eq!(TransactionPause::paused_transactions(&key), Some(None));
+        assert_eq!(
+            StorageVersion::get::<TransactionPause>(),
+            crate::STORAGE_VERSION
+        );
+        assert!(PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+    });
+}
+
+#[test]
+fn pause_whole_pallet_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            TransactionPause::pause_transaction(
+                Origin::signed(1),
+                b"TransactionPause".to_vec(),
+                b"*".to_vec(),
+                None
+            ),
+            Error::<Runtime>::CannotPause
+        );
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"*".to_vec(),
+            None
+        ));
+        assert_eq!(
+            TransactionPause::paused_patterns(b"Balances".to_vec()),
+            vec![b"*".to_vec()]
+        );
+        assert!(PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+        assert!(!PausedTransactionFilter::<Runtime>::contains(
+            TOKENS_TRANSFER
+        ));
+
+        assert_ok!(TransactionPause::unpause_transaction(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"*".to_vec()
+        ));
+        assert_eq!(
+            TransactionPause::paused_patterns(b"Balances".to_vec()),
+            Vec::<Vec<u8>>::new()
+        );
+        assert!(!PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+    });
+}
+
+#[test]
+fn pause_prefix_pattern_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            TransactionPause::pause_transaction(
+                Origin::signed(1),
+                b"Tokens".to_vec(),
+                b"tr*nsfer".to_vec(),
+                None
+            ),
+            Error::<Runtime>::InvalidPattern
+        );
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Tokens".to_vec(),
+            b"force_*".to_vec(),
+            None
+        ));
+        assert!(!PausedTransactionFilter::<Runtime>::contains(
+            TOKENS_TRANSFER
+        ));
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Tokens".to_vec(),
+            b"trans*".to_vec(),
+            Some(3)
+        ));
+        assert!(PausedTransactionFilter::<Runtime>::contains(
+            TOKENS_TRANSFER
+        ));
+        assert!(!PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+
+        run_to_block(3);
+        assert_eq!(
+            TransactionPause::paused_patterns(b"Tokens".to_vec()),
+            vec![b"force_*".to_vec()]
+        );
+        assert!(!PausedTransactionFilter::<Runtime>::contains(
+            TOKENS_TRANSFER
+        ));
+    });
+}
+
+#[test]
+fn check_paused_transaction_work() {
+    use frame_support::weights::DispatchInfo;
+    use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
+
+    ExtBuilder::default().build().execute_with(|| {
+        let info = DispatchInfo::default();
+
+        assert_ok!(CheckPausedTransaction::<Runtime>::new().validate(
+            &ALICE,
+            BALANCE_TRANSFER,
+            &info,
+            0
+        ));
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            None
+        ));
+
+        assert_eq!(
+            CheckPausedTransaction::<Runtime>::new().validate(&ALICE, BALANCE_TRANSFER, &info, 0),
+            Err(InvalidTransaction::Call.into())
+        );
+        assert_eq!(
+            CheckPausedTransaction::<Runtime>::new().pre_dispatch(
+                &ALICE,
+                BALANCE_TRANSFER,
+                &info,
+                0
+            ),
+            Err(InvalidTransaction::Call.into())
+        );
+        assert_ok!(CheckPausedTransaction::<Runtime>::new().validate(
+            &ALICE,
+            TOKENS_TRANSFER,
+            &info,
+            0
+        ));
+    });
+}
+
+#[test]
+fn core_calls_are_not_paused() {
+    use frame_support::weights::DispatchInfo;
+    use sp_runtime::traits::SignedExtension;
+
+    const REMARK: &<Runtime as frame_system::Config>::Call =
+        &mock::Call::System(frame_system::Call::remark_with_event { remark: vec![] });
+
+    ExtBuilder::default().build().execute_with(|| {
+        let info = DispatchInfo::default();
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"System".to_vec(),
+            b"*".to_vec(),
+            None
+        ));
+
+        // The filter and the transaction pool agree on core calls.
+        assert!(!PausedTransactionFilter::<Runtime>::contains(REMARK));
+        assert_ok!(CheckPausedTransaction::<Runtime>::new().validate(&ALICE, REMARK, &info, 0));
+    });
+}
+
+#[test]
+fn paused_transactions_list_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_eq!(TransactionPause::paused_transactions_list(), vec![]);
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            Some(10)
+        ));
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Tokens".to_vec(),
+            b"*".to_vec(),
+            None
+        ));
+
+        let mut paused = TransactionPause::paused_transactions_list();
+        paused.sort();
+        assert_eq!(
+            paused,
+            vec![
+                (b"Balances".to_vec(), b"transfer".to_vec(), Some(10)),
+                (b"Tokens".to_vec(), b"*".to_vec(), None),
+            ]
+        );
+        assert!(TransactionPause::is_paused(TOKENS_TRANSFER));
+    });
+}
+
+#[test]
+fn pause_origin_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(2),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            None
+        ));
+
+        // Pause origin can't unpause.
+        assert_noop!(
+            TransactionPause::unpause_transaction(
+                Origin::signed(2),
+                b"Balances".to_vec(),
+                b"transfer".to_vec()
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            TransactionPause::add_exempt_account(Origin::signed(2), ALICE),
+            BadOrigin
+        );
+
+        assert_ok!(TransactionPause::unpause_transaction(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec()
+        ));
+    });
+}
+
+#[test]
+fn exempt_account_work() {
+    use frame_support::weights::{DispatchInfo, PostDispatchInfo};
+    use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
+
+    const BOB: AccountId = 3;
+
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let info = DispatchInfo::default();
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            None
+        ));
+
+        assert_ok!(TransactionPause::add_exempt_account(
+            Origin::signed(1),
+            ALICE
+        ));
+        System::assert_last_event(Event::TransactionPause(crate::Event::ExemptAccountAdded(
+            ALICE,
+        )));
+        assert_eq!(TransactionPause::exempt_accounts(ALICE), Some(()));
+
+        // Not exempt account is rejected.
+        assert_eq!(
+            CheckPausedTransaction::<Runtime>::new().validate(&BOB, BALANCE_TRANSFER, &info, 0),
+            Err(InvalidTransaction::Call.into())
+        );
+
+        // Exempt account passes the filter during its transaction dispatch only.
+        let pre = CheckPausedTransaction::<Runtime>::new()
+            .pre_dispatch(&ALICE, BALANCE_TRANSFER, &info, 0)
+            .expect("exempt account");
+        assert_eq!(pre, Some(CheckPausedPre::Exempt));
+        assert!(!PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+        assert_ok!(CheckPausedTransaction::<Runtime>::post_dispatch(
+            pre,
+            &info,
+            &PostDispatchInfo::default(),
+            0,
+            &Ok(())
+        ));
+        assert!(!TransactionPause::dispatching_exempt());
+        assert!(PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+
+        assert_ok!(TransactionPause::remove_exempt_account(
+            Origin::signed(1),
+            ALICE
+        ));
+        System::assert_last_event(Event::TransactionPause(crate::Event::ExemptAccountRemoved(
+            ALICE,
+        )));
+        assert_eq!(
+            CheckPausedTransaction::<Runtime>::new().validate(&ALICE, BALANCE_TRANSFER, &info, 0),
+            Err(InvalidTransaction::Call.into())
+        );
+    });
+}
+
+#[test]
+fn set_rate_limit_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let limit = RateLimit::PerBlock { max_calls: 2 };
+
+        assert_noop!(
+            TransactionPause::set_rate_limit(
+                Origin::signed(2),
+                b"Balances".to_vec(),
+                b"transfer".to_vec(),
+                Some(limit)
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            TransactionPause::set_rate_limit(
+                Origin::signed(1),
+                b"TransactionPause".to_vec(),
+                b"pause_transaction".to_vec(),
+                Some(limit)
+            ),
+            Error::<Runtime>::CannotPause
+        );
+        assert_noop!(
+            TransactionPause::set_rate_limit(
+                Origin::signed(1),
+                b"Balances".to_vec(),
+                b"transfer".to_vec(),
+                Some(RateLimit::PerBlock { max_calls: 0 })
+            ),
+            Error::<Runtime>::InvalidRateLimit
+        );
+        assert_noop!(
+            TransactionPause::set_rate_limit(
+                Origin::signed(1),
+                b"Balances".to_vec(),
+                b"transfer".to_vec(),
+                Some(RateLimit::PerAccount {
+                    max_calls: 1,
+                    period: 0
+                })
+            ),
+            Error::<Runtime>::InvalidRateLimit
+        );
+
+        assert_ok!(TransactionPause::set_rate_limit(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            Some(limit)
+        ));
+        System::assert_last_event(Event::TransactionPause(crate::Event::RateLimitSet(
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            Some(limit),
+        )));
+        assert_eq!(
+            TransactionPause::rate_limits((b"Balances".to_vec(), b"transfer".to_vec())),
+            Some(limit)
+        );
+
+        assert_ok!(TransactionPause::set_rate_limit(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            None
+        ));
+        System::assert_last_event(Event::TransactionPause(crate::Event::RateLimitSet(
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            None,
+        )));
+        assert_eq!(
+            TransactionPause::rate_limits((b"Balances".to_vec(), b"transfer".to_vec())),
+            None
+        );
+    });
+}
+
+#[test]
+fn per_block_rate_limit_work() {
+    use frame_support::weights::{DispatchInfo, PostDispatchInfo};
+    use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
+
+    const BOB: AccountId = 3;
+
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let info = DispatchInfo::default();
+
+        assert_ok!(TransactionPause::set_rate_limit(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            Some(RateLimit::PerBlock { max_calls: 2 })
+        ));
+
+        for who in [ALICE, BOB] {
+            assert!(!PausedTransactionFilter::<Runtime>::contains(
+                BALANCE_TRANSFER
+            ));
+            let pre = CheckPausedTransaction::<Runtime>::new()
+                .pre_dispatch(&who, BALANCE_TRANSFER, &info, 0)
+                .expect("limit is not reached");
+            assert_ok!(CheckPausedTransaction::<Runtime>::post_dispatch(
+                pre,
+                &info,
+                &PostDispatchInfo::default(),
+                0,
+                &Ok(())
+            ));
+        }
+        System::assert_last_event(Event::TransactionPause(crate::Event::RateLimitReached(
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            None,
+        )));
+
+        // Limit is shared by all accounts.
+        assert_eq!(
+            CheckPausedTransaction::<Runtime>::new().validate(&4, BALANCE_TRANSFER, &info, 0),
+            Err(InvalidTransaction::ExhaustsResources.into())
+        );
+        assert!(PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+        // Other calls are not limited.
+        assert!(!PausedTransactionFilter::<Runtime>::contains(
+            TOKENS_TRANSFER
+        ));
+
+        run_to_block(2);
+        assert!(!PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+        assert_ok!(CheckPausedTransaction::<Runtime>::new().validate(
+            &4,
+            BALANCE_TRANSFER,
+            &info,
+            0
+        ));
+    });
+}
+
+#[test]
+fn per_account_rate_limit_work() {
+    use frame_support::weights::{DispatchInfo, PostDispatchInfo};
+    use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
+
+    const BOB: AccountId = 3;
+
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let info = DispatchInfo::default();
+
+        assert_ok!(TransactionPause::set_rate_limit(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            Some(RateLimit::PerAccount {
+                max_calls: 1,
+                period: 10
+            })
+        ));
+
+        let pre = CheckPausedTransaction::<Runtime>::new()
+            .pre_dispatch(&BOB, BALANCE_TRANSFER, &info, 0)
+            .expect("limit is not reached");
+        assert_ok!(CheckPausedTransaction::<Runtime>::post_dispatch(
+            pre,
+            &info,
+            &PostDispatchInfo::default(),
+            0,
+            &Ok(())
+        ));
+        System::assert_last_event(Event::TransactionPause(crate::Event::RateLimitReached(
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            Some(BOB),
+        )));
+
+        assert_eq!(
+            CheckPausedTransaction::<Runtime>::new().validate(&BOB, BALANCE_TRANSFER, &info, 0),
+            Err(InvalidTransaction::ExhaustsResources.into())
+        );
+        // Other accounts and the filter are not limited.
+        assert_ok!(CheckPausedTransaction::<Runtime>::new().validate(
+            &ALICE,
+            BALANCE_TRANSFER,
+            &info,
+            0
+        ));
+        assert!(!PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+
+        // Period started at block 0 ends at block 10.
+        run_to_block(9);
+        assert_eq!(
+            CheckPausedTransaction::<Runtime>::new().validate(&BOB, BALANCE_TRANSFER, &info, 0),
+            Err(InvalidTransaction::ExhaustsResources.into())
+        );
+        run_to_block(10);
+        assert_ok!(CheckPausedTransaction::<Runtime>::new().validate(
+            &BOB,
+            BALANCE_TRANSFER,
+            &info,
+            0
+        ));
+
+        // Exempt accounts are neither limited nor counted.
+        assert_ok!(TransactionPause::add_exempt_account(Origin::signed(1), BOB));
+        for _ in 0..2 {
+            let pre = CheckPausedTransaction::<Runtime>::new()
+                .pre_dispatch(&BOB, BALANCE_TRANSFER, &info, 0)
+                .expect("exempt account");
+            assert_ok!(CheckPausedTransaction::<Runtime>::post_dispatch(
+                pre,
+                &info,
+                &PostDispatchInfo::default(),
+                0,
+                &Ok(())
+            ));
+        }
+        assert_eq!(
+            TransactionPause::account_call_counts(
+                (b"Balances".to_vec(), b"transfer".to_vec()),
+                BOB
+            ),
+            (0, 1)
+        );
+    });
+}
+
+#[test]
+fn prune_call_counts_on_idle_work() {
+    const BOB: AccountId = 3;
+    const CHARLIE: AccountId = 4;
+
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let key = (b"Balances".to_vec(), b"transfer".to_vec());
+        assert_ok!(TransactionPause::set_rate_limit(
+            Origin::signed(1),
+            key.0.clone(),
+            key.1.clone(),
+            Some(RateLimit::PerAccount {
+                max_calls: 2,
+                period: 10
+            })
+        ));
+        for who in [ALICE, BOB, CHARLIE] {
+            TransactionPause::note_rate_limited_calls(key.clone(), &who, 1);
+        }
+
+        assert_eq!(TransactionPause::on_idle(9, 0), 0);
+        assert_eq!(TransactionPause::call_counts_prune_cursor(), None);
+
+        // Counts of the current period are kept.
+        assert!(TransactionPause::on_idle(9, Weight::MAX) > 0);
+        assert!(TransactionPause::call_counts_prune_cursor().is_some());
+        assert!(TransactionPause::on_idle(9, Weight::MAX) > 0);
+        assert_eq!(TransactionPause::call_counts_prune_cursor(), None);
+        assert_eq!(AccountCallCounts::<Runtime>::iter_prefix(&key).count(), 3);
+
+        // At most `MaxPrunedCallCounts` counts are checked in a block.
+        assert!(TransactionPause::on_idle(10, Weight::MAX) > 0);
+        assert!(TransactionPause::call_counts_prune_cursor().is_some());
+        assert_eq!(AccountCallCounts::<Runtime>::iter_prefix(&key).count(), 1);
+        assert!(TransactionPause::on_idle(10, Weight::MAX) > 0);
+        assert_eq!(TransactionPause::call_counts_prune_cursor(), None);
+        assert_eq!(AccountCallCounts::<Runtime>::iter_prefix(&key).count(), 0);
+    });
+}
+
+#[test]
+fn nested_calls_count_to_rate_limit() {
+    use frame_support::weights::{DispatchInfo, PostDispatchInfo};
+    use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
+
+    let batch = |calls: usize| {
+        mock::Call::System(frame_system::Call::remark {
+            remark: vec![BALANCE_TRANSFER.clone(); calls].encode(),
+        })
+    };
+    let key = (b"Balances".to_vec(), b"transfer".to_vec());
+
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let info = DispatchInfo::default();
+
+        assert_ok!(TransactionPause::set_rate_limit(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            Some(RateLimit::PerBlock { max_calls: 2 })
+        ));
+
+        // Batch over the limit is rejected, even if no calls were counted yet.
+        assert_eq!(
+            CheckPausedTransaction::<Runtime>::new().validate(&ALICE, &batch(3), &info, 0),
+            Err(InvalidTransaction::ExhaustsResources.into())
+        );
+
+        let pre = CheckPausedTransaction::<Runtime>::new()
+            .pre_dispatch(&ALICE, &batch(2), &info, 0)
+            .expect("limit is not exceeded");
+        assert_eq!(
+            pre.as_ref().map(|pre| pre.rate_limited.clone()),
+            Some(vec![(key.clone(), 2)])
+        );
+        assert_ok!(CheckPausedTransaction::<Runtime>::post_dispatch(
+            pre,
+            &info,
+            &PostDispatchInfo::default(),
+            0,
+            &Ok(())
+        ));
+        assert_eq!(TransactionPause::block_call_counts(&key), (1, 2));
+        System::assert_last_event(Event::TransactionPause(crate::Event::RateLimitReached(
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            None,
+        )));
+
+        assert_eq!(
+            CheckPausedTransaction::<Runtime>::new().validate(&ALICE, BALANCE_TRANSFER, &info, 0),
+            Err(InvalidTransaction::ExhaustsResources.into())
+        );
+        assert!(PausedTransactionFilter::<Runtime>::contains(
+            BALANCE_TRANSFER
+        ));
+    });
+}
+
+#[test]
+fn unknown_call_rejected() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        for (pallet_name, function_name) in [
+            (b"Balance".to_vec(), b"transfer".to_vec()),
+            (b"Balances".to_vec(), b"transfe".to_vec()),
+            (b"Balances".to_vec(), b"mint*".to_vec()),
+            (b"Unknown".to_vec(), b"*".to_vec()),
+        ] {
+            assert_noop!(
+                TransactionPause::pause_transaction(
+                    Origin::signed(1),
+                    pallet_name.clone(),
+                    function_name.clone(),
+                    None
+                ),
+                Error::<Runtime>::UnknownCall
+            );
+            assert_noop!(
+                TransactionPause::set_rate_limit(
+                    Origin::signed(1),
+                    pallet_name,
+                    function_name,
+                    Some(RateLimit::PerBlock { max_calls: 1 })
+                ),
+                Error::<Runtime>::UnknownCall
+            );
+        }
+
+        // Rate limits are set for exact function names only.
+        assert_noop!(
+            TransactionPause::set_rate_limit(
+                Origin::signed(1),
+                b"Balances".to_vec(),
+                b"*".to_vec(),
+                Some(RateLimit::PerBlock { max_calls: 1 })
+            ),
+            Error::<Runtime>::UnknownCall
+        );
+
+        assert!(TransactionPause::is_known_call(b"Balances", b"transfer"));
+        assert!(TransactionPause::is_known_call(b"Balances", b"transfer*"));
+        assert!(TransactionPause::is_known_call(b"Tokens", b"*"));
+    });
+}
+
+#[test]
+fn remove_unknown_calls_migration_work() {
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+
+        // Entries left by typos or removed calls.
+        PausedTransactions::<Runtime>::insert((b"Balances".to_vec(), b"transfe".to_vec()), None);
+        PausedTransactions::<Runtime>::insert((b"Removed".to_vec(), b"*".to_vec()), None);
+        PausedPatterns::<Runtime>::insert(b"Removed".to_vec(), vec![b"*".to_vec()]);
+        RateLimits::<Runtime>::insert(
+            (b"Removed".to_vec(), b"call".to_vec()),
+            RateLimit::PerBlock { max_calls: 1 },
+        );
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::signed(1),
+            b"Balances".to_vec(),
+            b"transfer".to_vec(),
+            None
+        ));
+        assert_ok!(TransactionPause::set_rate_limit(
+            Origin::signed(1),
+            b"Tokens".to_vec(),
+            b"transfer".to_vec(),
+            Some(RateLimit::PerBlock { max_calls: 1 })
+        ));
+
+        migrations::RemoveUnknownCalls::<Runtime>::on_runtime_upgrade();
+
+        assert_eq!(
+            TransactionPause::paused_transactions_list(),
+            vec![(b"Balances".to_vec(), b"transfer".to_vec(), None)]
+        );
+        assert!(TransactionPause::paused_patterns(b"Removed".to_vec()).is_empty());
+        assert_eq!(
+            RateLimits::<Runtime>::iter_keys().collect::<Vec<_>>(),
+            vec![(b"Tokens".to_vec(), b"transfer".to_vec())]
+        );
+    });
+}
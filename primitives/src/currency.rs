@@ -53,6 +53,39 @@ macro_rules! static_assert {
     };
 }
 
+/// Symbol of registered currency: variant name followed by the id, e.g. `ForeignAsset42`.
+fn registered_symbol(prefix: &[u8], id: u32) -> Vec<u8> {
+    let mut digits = Vec::new();
+    let mut rest = id;
+    loop {
+        digits.push(b'0' + (rest % 10) as u8);
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+
+    let mut symbol = prefix.to_vec();
+    symbol.extend(digits.iter().rev());
+    symbol
+}
+
+/// Parse id of registered currency from its symbol.
+fn parse_registered_symbol(prefix: &[u8], symbol: &[u8]) -> Option<u32> {
+    let digits = symbol.strip_prefix(prefix)?;
+    // No leading zeros, so each id has the only symbol.
+    if digits.is_empty() || (digits.len() > 1 && digits[0] == b'0') {
+        return None;
+    }
+
+    digits.iter().try_fold(0u32, |id, digit| {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        id.checked_mul(10)?.checked_add((digit - b'0') as u32)
+    })
+}
+
 macro_rules! def_currencies {
     (
         $(#[$ty_attr:meta])*
@@ -62,6 +95,14 @@ macro_rules! def_currencies {
                 $name:ident($str:literal, $decimals:expr)
             ),*
             $(,)?
+            $(
+                ;
+                $(
+                    $(#[$reg_attr:meta])*
+                    $reg_name:ident(u32)
+                ),*
+                $(,)?
+            )?
         }
     ) => {
         $(#[$ty_attr])*
@@ -70,21 +111,39 @@ macro_rules! def_currencies {
                 $(#[$attr])*
                 $name,
             )*
+            $($(
+                $(#[$reg_attr])*
+                $reg_name(u32),
+            )*)?
         }
 
         impl $ty_name {
-            pub const fn decimals(&self) -> u8 {
+            /// Decimals of the currency.
+            ///
+            /// Decimals of registered currencies are stored in the currencies registry,
+            /// `None` is returned for them.
+            pub const fn decimals(&self) -> Option<u8> {
                 match self {
-                    $(Self::$name => $decimals,)*
+                    $(Self::$name => Some($decimals),)*
+                    $($(Self::$reg_name(_) => None,)*)?
                 }
             }
 
-            const fn value(&self) -> Balance {
-                Balance::pow(10, self.decimals() as _)
+            const fn value(&self) -> Option<Balance> {
+                match self.decimals() {
+                    Some(decimals) => Some(Balance::pow(10, decimals as _)),
+                    None => None,
+                }
             }
 
-            pub const fn times(&self, n: Balance) -> Balance {
-                self.value().saturating_mul(n)
+            /// Amount of `n` whole units of the currency.
+            ///
+            /// `None` for registered currencies, their decimals are stored in the registry.
+            pub const fn times(&self, n: Balance) -> Option<Balance> {
+                match self.value() {
+                    Some(value) => Some(value.saturating_mul(n)),
+                    None => None,
+                }
             }
 
             pub const fn millies(self) -> Millies {
@@ -94,6 +153,9 @@ macro_rules! def_currencies {
             pub fn symbol(&self) -> Vec<u8> {
                 match self {
                     $(Self::$name => $str.to_vec(),)*
+                    $($(Self::$reg_name(id) => {
+                        registered_symbol(stringify!($reg_name).as_bytes(), *id)
+                    })*)?
                 }
             }
         }
@@ -102,10 +164,7 @@ macro_rules! def_currencies {
             type Error = CurrencyConversionError;
 
             fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
-                match &v[..] {
-                    $($str => Ok(Self::$name),)*
-                    _ => Err(Self::Error::new(v)),
-                }
+                Self::try_from(&v[..])
             }
         }
 
@@ -114,9 +173,17 @@ macro_rules! def_currencies {
 
             fn try_from(v: &'_ [u8]) -> Result<Self, Self::Error> {
                 match v {
-                    $($str => Ok(Self::$name),)*
-                    _ => Err(Self::Error::new(v.to_vec())),
+                    $($str => return Ok(Self::$name),)*
+                    _ => {}
                 }
+
+                $($(
+                    if let Some(id) = parse_registered_symbol(stringify!($reg_name).as_bytes(), v) {
+                        return Ok(Self::$reg_name(id));
+                    }
+                )*)?
+
+                Err(Self::Error::new(v.to_vec()))
             }
         }
 
@@ -127,6 +194,8 @@ macro_rules! def_currencies {
         /// match currency_id {
         ///     &CurrencyId::NATIVE => {},
         ///     CurrencyId::KSM  => {}
+        ///     &CurrencyId::LIQUID => {}
+        ///     CurrencyId::ForeignAsset(_) => {}
         /// }
         /// ```
         /// Anyway it can be omitted with de-reference (imp-copy) the instance:
@@ -136,6 +205,8 @@ macro_rules! def_currencies {
         /// match *currency_id {
         ///     CurrencyId::NATIVE => {},
         ///     CurrencyId::KSM  => {}
+        ///     CurrencyId::LIQUID => {}
+        ///     CurrencyId::ForeignAsset(_) => {}
         /// }
         /// ```
         impl core::cmp::PartialEq<$ty_name> for &'_ $ty_name {
@@ -164,6 +235,12 @@ def_currencies! {
         NOX(b"NOX", 10),
         /// Relaychain's currency.
         KSM(b"KSM", 12),
+        /// Derivative of our native currency staked by the liquid staking pool.
+        #[codec(index = 3)]
+        LNOX(b"LNOX", 10);
+        /// Asset of other chain registered in the currencies registry.
+        #[codec(index = 2)]
+        ForeignAsset(u32),
     }
 }
 
@@ -177,6 +254,12 @@ def_currencies! {
         PONT(b"PONT", 10),
         /// Relaychain's currency.
         KSM(b"KSM", 12),
+        /// Derivative of our native currency staked by the liquid staking pool.
+        #[codec(index = 3)]
+        LPONT(b"LPONT", 10);
+        /// Asset of other chain registered in the currencies registry.
+        #[codec(index = 2)]
+        ForeignAsset(u32),
     }
 }
 
@@ -188,6 +271,7 @@ impl Default for CurrencyId {
 
 impl CurrencyId {
     pub const NATIVE: CurrencyId = CurrencyId::NOX;
+    pub const LIQUID: CurrencyId = CurrencyId::liquid();
 
     // Create a new CurrencyId with native currency.
     pub const fn native() -> Self {
@@ -200,33 +284,52 @@ impl CurrencyId {
             CurrencyId::PONT
         }
     }
-}
 
-pub struct Millies(pub CurrencyId);
+    // Create a new CurrencyId with derivative of native currency.
+    pub const fn liquid() -> Self {
+        #[cfg(not(feature = "pont"))]
+        {
+            CurrencyId::LNOX
+        }
+        #[cfg(feature = "pont")]
+        {
+            CurrencyId::LPONT
+        }
+    }
 
-impl Millies {
-    const fn value(&self) -> Balance {
-        self.0.value() / 1000
+    /// Amount of `n` whole units of the native currency, usable in constants.
+    pub const fn native_times(n: Balance) -> Balance {
+        Balance::pow(10, NATIVE_DECIMALS as _).saturating_mul(n)
     }
 
-    pub const fn times(&self, n: Balance) -> Balance {
-        self.value().saturating_mul(n)
+    /// Amount of `n` thousandths of the native currency, usable in constants.
+    pub const fn native_millies(n: Balance) -> Balance {
+        (Balance::pow(10, NATIVE_DECIMALS as _) / 1000).saturating_mul(n)
     }
 }
 
-impl core::ops::Mul<Balance> for CurrencyId {
-    type Output = Balance;
+/// Decimals of the native currency, it's built-in so they are known at compile time.
+pub const NATIVE_DECIMALS: u8 = match CurrencyId::NATIVE.decimals() {
+    Some(decimals) => decimals,
+    None => panic!("native currency is built-in"),
+};
+
+pub struct Millies(pub CurrencyId);
 
-    fn mul(self, n: Balance) -> Self::Output {
-        self.times(n)
+impl Millies {
+    const fn value(&self) -> Option<Balance> {
+        match self.0.value() {
+            Some(value) => Some(value / 1000),
+            None => None,
+        }
     }
-}
-
-impl core::ops::Mul<Balance> for Millies {
-    type Output = Balance;
 
-    fn mul(self, n: Balance) -> Self::Output {
-        self.times(n)
+    /// Amount of `n` thousandths of the currency, `None` for registered currencies.
+    pub const fn times(&self, n: Balance) -> Option<Balance> {
+        match self.value() {
+            Some(value) => Some(value.saturating_mul(n)),
+            None => None,
+        }
     }
 }
 
@@ -243,8 +346,25 @@ mod tests {
     #[test]
     /// Test currencies decimals.
     fn decimals() {
-        assert_eq!(CurrencyId::NATIVE.decimals(), 10);
-        assert_eq!(CurrencyId::KSM.decimals(), 12);
+        assert_eq!(CurrencyId::NATIVE.decimals(), Some(10));
+        assert_eq!(CurrencyId::KSM.decimals(), Some(12));
+        assert_eq!(CurrencyId::LIQUID.decimals(), CurrencyId::NATIVE.decimals());
+    }
+
+    #[test]
+    /// Test amounts of whole units.
+    fn amounts() {
+        assert_eq!(CurrencyId::KSM.times(2), Some(2_000_000_000_000));
+        assert_eq!(
+            CurrencyId::NATIVE.times(3),
+            Some(CurrencyId::native_times(3))
+        );
+        assert_eq!(
+            CurrencyId::NATIVE.millies().times(3),
+            Some(CurrencyId::native_millies(3))
+        );
+        assert_eq!(CurrencyId::ForeignAsset(0).times(1), None);
+        assert_eq!(CurrencyId::ForeignAsset(0).millies().times(1), None);
     }
 
     #[test]
@@ -253,6 +373,7 @@ mod tests {
         assert_eq!(CurrencyId::KSM.symbol(), b"KSM");
         assert_eq!(CurrencyId::NATIVE.symbol(), CurrencyId::native().symbol());
         assert_eq!(CurrencyId::NATIVE.symbol(), NATIVE_SYM);
+        assert_eq!(CurrencyId::LIQUID.symbol(), [b"L", NATIVE_SYM].concat());
     }
 
     #[test]
@@ -269,6 +390,35 @@ mod tests {
         assert!(CurrencyId::try_from(b"UNKNOWN".to_vec()).is_err());
     }
 
+    #[test]
+    /// Test symbols of foreign assets.
+    fn foreign_asset_symbols() {
+        assert_eq!(CurrencyId::ForeignAsset(0).symbol(), b"ForeignAsset0");
+        assert_eq!(CurrencyId::ForeignAsset(42).symbol(), b"ForeignAsset42");
+        assert_eq!(
+            CurrencyId::try_from(b"ForeignAsset42".as_ref()).unwrap(),
+            CurrencyId::ForeignAsset(42)
+        );
+        assert_eq!(
+            CurrencyId::try_from(CurrencyId::ForeignAsset(u32::MAX).symbol()).unwrap(),
+            CurrencyId::ForeignAsset(u32::MAX)
+        );
+        assert!(CurrencyId::try_from(b"ForeignAsset".as_ref()).is_err());
+        assert!(CurrencyId::try_from(b"ForeignAsset042".as_ref()).is_err());
+        assert!(CurrencyId::try_from(b"ForeignAsset4294967296".as_ref()).is_err());
+        assert_eq!(CurrencyId::ForeignAsset(42).decimals(), None);
+    }
+
+    #[test]
+    /// Test encoding of foreign assets is kept after the liquid currency was added.
+    fn encoding() {
+        use codec::Encode;
+
+        assert_eq!(CurrencyId::KSM.encode(), vec![1]);
+        assert_eq!(CurrencyId::ForeignAsset(0).encode(), vec![2, 0, 0, 0, 0]);
+        assert_eq!(CurrencyId::LIQUID.encode(), vec![3]);
+    }
+
     #[test]
     /// Test try from &[u8].
     fn try_from_slice() {
@@ -280,6 +430,10 @@ mod tests {
             CurrencyId::try_from(b"KSM".as_ref()).unwrap(),
             CurrencyId::KSM
         );
+        assert_eq!(
+            CurrencyId::try_from(CurrencyId::LIQUID.symbol().as_ref()).unwrap(),
+            CurrencyId::LIQUID
+        );
         assert!(CurrencyId::try_from(b"UNKNOWN".as_ref()).is_err());
     }
 }
@@ -27,10 +27,10 @@ pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::Account
 pub type AccountIndex = u32;
 
 /// Balance of an account.
-pub type Balance = u64;
+pub type Balance = u128;
 
 // Signed version of `Balance` for xtokens.
-pub type Amount = i64;
+pub type Amount = i128;
 
 /// Index of a transaction in the chain.
 pub type Index = u32;
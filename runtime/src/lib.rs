@@ -10,8 +10,11 @@ use sp_std::prelude::*;
 use sp_core::OpaqueMetadata;
 use sp_runtime::{
     ApplyExtrinsicResult, create_runtime_str, generic, impl_opaque_keys,
-    traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, Convert, ConvertInto},
+    traits::{
+        AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Convert, ConvertInto,
+    },
     transaction_validity::{TransactionValidity, TransactionSource},
+    FixedPointNumber, FixedU128,
 };
 use sp_api::impl_runtime_apis;
 use sp_version::RuntimeVersion;
@@ -34,7 +37,7 @@ use xcm_builder::{
 use xcm::latest::AssetId;
 use xcm_executor::{XcmExecutor, traits::WeightTrader, Assets};
 use pallet_xcm::XcmPassthrough;
-use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key};
+use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key, GetByKey};
 use orml_xcm_support::{IsNativeConcrete, MultiCurrencyAdapter, MultiNativeAsset};
 
 // A few exports that help ease life for downstream crates.
@@ -70,17 +73,19 @@ use frame_system::{
 /// Import the Move-pallet.
 pub use sp_mvm::gas::{GasWeightMapping};
 pub use sp_mvm_rpc_runtime::types::MVMApiEstimation;
-pub use parachain_staking::{InflationInfo, Range};
+pub use parachain_staking::{CollatorLiveness, DelegatorOverview, InflationInfo, Range, StakingApr};
 pub use pallet_author_slot_filter::EligibilityValue;
 
 use constants::{SS58_PREFIX, currency::*, time::*};
 use primitives::{*, currency::CurrencyId, Index};
 
-use module_currencies::BasicCurrencyAdapter;
+use module_currencies::{BasicCurrencyAdapter, CurrencyBalance, CurrencyMetadata, GenesisCurrency};
 
 ///Import the Transaction pause pallet.
 pub use transaction_pause;
 
+pub mod migrations;
+
 #[cfg(test)]
 mod tests;
 
@@ -104,10 +109,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     // codebase, it _will_ be nox-polkadot.
     impl_name: create_runtime_str!("nox"),
     authoring_version: 1,
-    spec_version: 101,
+    spec_version: 102,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 3,
     state_version: 0,
 };
 
@@ -220,7 +225,7 @@ parameter_types! {
     pub const CooloffPeriod: BlockNumber = 14 * DAYS;
 
     // 100 PONT as minimum deposit.
-    pub const MinimumDeposit: Balance = CurrencyId::NATIVE.times(100);
+    pub const MinimumDeposit: Balance = CurrencyId::native_times(100);
 
     // e.g. 100 PONT for 1 MB.
     pub const PreimageByteDeposit: Balance = 1000000;
@@ -297,8 +302,8 @@ parameter_types! {
     /// but not less than ProposalBondMinimum.
     /// This value would be slashed if proposal rejected.
     pub const ProposalBond: Permill = Permill::from_percent(5);
-    pub const ProposalBondMinimum: Balance = CurrencyId::NATIVE.times(100);
-    pub const ProposalBondMaximum: Balance = CurrencyId::NATIVE.times(1000);
+    pub const ProposalBondMinimum: Balance = CurrencyId::native_times(100);
+    pub const ProposalBondMaximum: Balance = CurrencyId::native_times(1000);
     pub const MaxApprovals: u32 = 100;
 }
 
@@ -358,11 +363,11 @@ impl pallet_timestamp::Config for Runtime {
 
 parameter_types! {
     pub const ExistentialDeposit: Balance = NATIVE_EXISTENTIAL_DEPOSIT;
-    pub const TransferFee: Balance = CurrencyId::NATIVE.millies().times(1);
-    pub const CreationFee: Balance = CurrencyId::NATIVE.millies().times(1);
-    pub const TransactionByteFee: Balance = CurrencyId::NATIVE.millies().times(1);
+    pub const TransferFee: Balance = CurrencyId::native_millies(1);
+    pub const CreationFee: Balance = CurrencyId::native_millies(1);
+    pub const TransactionByteFee: Balance = CurrencyId::native_millies(1);
     // 1 PONT.
-    pub const MinVestedTransfer: Balance = CurrencyId::NATIVE.times(1);
+    pub const MinVestedTransfer: Balance = CurrencyId::native_times(1);
 }
 
 impl pallet_vesting::Config for Runtime {
@@ -385,7 +390,8 @@ impl pallet_balances::Config for Runtime {
     type Balance = Balance;
     /// The ubiquitous event type.
     type Event = Event;
-    type DustRemoval = ();
+    // Dust goes to the treasury.
+    type DustRemoval = Treasury;
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
@@ -467,8 +473,22 @@ parameter_types! {
     pub const RevokeDelegationDelay: u32 = 2;
     /// Rounds before the delegator bond increase/decrease can be executed
     pub const DelegationBondLessDelay: u32 = 2;
+    /// Block time used to derive per-round inflation until it's observed over a round
+    pub const DefaultBlockTime: u64 = MILLISECS_PER_BLOCK;
+    /// Candidate commission changes are applied after 2 rounds
+    pub const CandidateCommissionDelay: u32 = 2;
     /// Reward payments are delayed by 2 hours (2 * 300 * block_time)
     pub const RewardPaymentDelay: u32 = 2;
+    /// Rounds in which a collator misses more than half of its slots count as missed
+    pub const MissedSlotsThreshold: Percent = Percent::from_percent(50);
+    /// Collators missing 3 rounds in a row (3 * 300 * block_time) are taken offline
+    pub const MaxMissedRounds: u32 = 3;
+    /// Collators taken offline for missed rounds are slashed 1% of their self bond
+    pub const DowntimeSlashFraction: Perbill = Perbill::from_percent(1);
+    /// Delegators aren't slashed for the downtime of their collator
+    pub const DelegatorDowntimeSlashFraction: Perbill = Perbill::zero();
+    /// Slashes are deferred by 1 day (24 * 300 * block_time) to be cancellable by governance
+    pub const SlashDeferDuration: u32 = 24;
     /// Minimum 8 collators selected per round, default at genesis and minimum forever after
     pub const MinSelectedCandidates: u32 = 8;
     /// Maximum top 10 delegators per collator metters
@@ -482,11 +502,11 @@ parameter_types! {
     /// Default percent of inflation set aside for parachain bond every round
     pub const DefaultParachainBondReservePercent: Percent = Percent::from_percent(30);
     /// Minimum stake required to become a collator is 1_000
-    pub const MinCollatorStk: Balance = CurrencyId::NATIVE.times(1000);
+    pub const MinCollatorStk: Balance = CurrencyId::native_times(1000);
     /// Minimum stake required to be reserved to be a candidate is 100
-    pub const MinCandidateStk: Balance = CurrencyId::NATIVE.times(100);
+    pub const MinCandidateStk: Balance = CurrencyId::native_times(100);
     /// Minimum stake required to be reserved to be a delegator is 1.
-    pub const MinDelegatorStk: Balance = CurrencyId::NATIVE.times(1);
+    pub const MinDelegatorStk: Balance = CurrencyId::native_times(1);
 }
 impl parachain_staking::Config for Runtime {
     type Event = Event;
@@ -494,12 +514,20 @@ impl parachain_staking::Config for Runtime {
     type MonetaryGovernanceOrigin = EnsureRoot<AccountId>;
     type MinBlocksPerRound = MinBlocksPerRound;
     type DefaultBlocksPerRound = DefaultBlocksPerRound;
+    type DefaultBlockTime = DefaultBlockTime;
+    type UnixTime = Timestamp;
     type CandidateBondLessDelay = CandidateBondLessDelay;
+    type CandidateCommissionDelay = CandidateCommissionDelay;
     type LeaveCandidatesDelay = LeaveCandidatesDelay;
     type LeaveDelegatorsDelay = LeaveDelegatorsDelay;
     type RevokeDelegationDelay = RevokeDelegationDelay;
     type DelegationBondLessDelay = DelegationBondLessDelay;
     type RewardPaymentDelay = RewardPaymentDelay;
+    type MissedSlotsThreshold = MissedSlotsThreshold;
+    type MaxMissedRounds = MaxMissedRounds;
+    type DowntimeSlashFraction = DowntimeSlashFraction;
+    type DelegatorDowntimeSlashFraction = DelegatorDowntimeSlashFraction;
+    type SlashDeferDuration = SlashDeferDuration;
     type MinSelectedCandidates = MinSelectedCandidates;
     type MaxTopDelegationsPerCandidate = MaxTopDelegationsPerCandidate;
     type MaxBottomDelegationsPerCandidate = MaxBottomDelegationsPerCandidate;
@@ -510,6 +538,7 @@ impl parachain_staking::Config for Runtime {
     type MinCandidateStk = MinCandidateStk;
     type MinDelegatorStk = MinDelegatorStk;
     type MinDelegation = MinDelegatorStk;
+    type TreasuryAccount = TreasuryAccount;
     type WeightInfo = parachain_staking::weights::SubstrateWeight<Runtime>;
 }
 
@@ -522,7 +551,7 @@ impl pallet_author_inherent::Config for Runtime {
 }
 
 parameter_types! {
-    pub const DepositAmount: Balance = CurrencyId::NATIVE.times(1);
+    pub const DepositAmount: Balance = CurrencyId::native_times(1);
 }
 // This is a simple session key manager. It should probably either work with, or be replaced
 // entirely by pallet sessions.
@@ -649,7 +678,27 @@ mod kusama {
     }
 }
 
+/// Buys weight in the native currency and KSM at fixed prices, and in other currencies at
+/// their fee rates set by governance in `currency_fees` (per unit of native price, so decimals
+/// of registered currencies are accounted by the rate). Currencies without rate can't buy weight.
 pub struct SimpleWeightTrader(MultiLocation);
+impl SimpleWeightTrader {
+    /// Price of `weight` in the currency, `None` if weight can't be bought with it.
+    fn price(currency_id: Option<CurrencyId>, weight: Weight) -> Option<u128> {
+        let native_price = weight as u128 / PONT_PER_WEIGHT;
+        match currency_id? {
+            CurrencyId::NATIVE => Some(native_price),
+            CurrencyId::KSM => {
+                use frame_support::weights::WeightToFeePolynomial;
+                Some(kusama::KusamaWeightToFee::calc(&weight) as u128)
+            }
+            currency_id @ (CurrencyId::LIQUID | CurrencyId::ForeignAsset(_)) => {
+                CurrencyFees::convert_fee(currency_id, native_price)
+            }
+        }
+    }
+}
+
 impl WeightTrader for SimpleWeightTrader {
     fn new() -> Self {
         Self(MultiLocation::parent())
@@ -666,21 +715,10 @@ impl WeightTrader for SimpleWeightTrader {
             AssetId::Concrete(multi_location) => CurrencyIdConvert::convert(multi_location),
             _ => None,
         };
-        let required = match currency_id {
-            Some(CurrencyId::NATIVE) => asset_id
-                .clone()
-                .into_multiasset(Fungibility::Fungible(weight as u128 / PONT_PER_WEIGHT)),
-            Some(CurrencyId::KSM) => {
-                use frame_support::weights::WeightToFeePolynomial;
-                let fee = kusama::KusamaWeightToFee::calc(&weight);
-                asset_id
-                    .clone()
-                    .into_multiasset(Fungibility::Fungible(fee as u128))
-            }
-            None => asset_id
-                .clone()
-                .into_multiasset(Fungibility::Fungible(weight as u128)),
-        };
+        let price = Self::price(currency_id, weight).ok_or(XcmError::TooExpensive)?;
+        let required = asset_id
+            .clone()
+            .into_multiasset(Fungibility::Fungible(price));
 
         if let MultiAsset {
             id: Concrete(ref id),
@@ -696,15 +734,7 @@ impl WeightTrader for SimpleWeightTrader {
     }
 
     fn refund_weight(&mut self, weight: Weight) -> Option<MultiAsset> {
-        let amount = match CurrencyIdConvert::convert(self.0.clone()) {
-            Some(CurrencyId::NATIVE) => weight as u128 / PONT_PER_WEIGHT,
-            Some(CurrencyId::KSM) => {
-                use frame_support::weights::WeightToFeePolynomial;
-                let fee = kusama::KusamaWeightToFee::calc(&weight);
-                fee as u128
-            }
-            None => weight as u128,
-        };
+        let amount = Self::price(CurrencyIdConvert::convert(self.0.clone()), weight)?;
         Some(MultiAsset {
             id: self.0.clone().into(),
             fun: Fungibility::Fungible(amount),
@@ -799,12 +829,17 @@ impl pallet_multisig::Config for Runtime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const MaxBatchCalls: u32 = 16;
+}
+
 impl groupsign::Config for Runtime {
     type Event = Event;
     type Call = Call;
     type Public = sp_runtime::MultiSigner;
     type Signature = sp_runtime::MultiSignature;
     type MyOrigin = Origin;
+    type MaxBatchCalls = MaxBatchCalls;
     type WeightInfo = groupsign::weights::PontemWeights<Self>;
 }
 
@@ -888,8 +923,15 @@ impl cumulus_pallet_parachain_system::CheckInherents<Block> for CheckInherents {
     }
 }
 
-pub fn dollar(currency_id: CurrencyId) -> u128 {
-    10u128.pow(currency_id.decimals().into())
+/// One whole unit of the currency, decimals of registered currencies are taken from the registry.
+///
+/// `None` for a currency which isn't registered.
+pub fn dollar(currency_id: CurrencyId) -> Option<Balance> {
+    let decimals = match currency_id.decimals() {
+        Some(decimals) => decimals,
+        None => Currencies::currency_metadata(currency_id)?.decimals,
+    };
+    Balance::checked_pow(10, decimals.into())
 }
 
 pub struct CurrencyIdConvert;
@@ -905,6 +947,9 @@ impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
                 )
                     .into(),
             ),
+            // The liquid staking derivative isn't transferable to other chains.
+            CurrencyId::LIQUID => None,
+            CurrencyId::ForeignAsset(_) => Currencies::location(id),
         }
     }
 }
@@ -925,7 +970,9 @@ impl Convert<MultiLocation, Option<CurrencyId>> for CurrencyIdConvert {
                 parents: 0,
                 interior: X1(GeneralKey(key)),
             } if key.to_vec() == CurrencyId::NATIVE.symbol() => Some(CurrencyId::NATIVE),
-            _ => None,
+            // Assets of other chains are registered by governance.
+            location => Currencies::currency_id_by_location(location)
+                .filter(|currency_id| matches!(currency_id, CurrencyId::ForeignAsset(_))),
         }
     }
 }
@@ -948,11 +995,34 @@ parameter_type_with_key! {
     pub ExistentialDeposits: |currency_id: CurrencyId| -> Balance {
         match currency_id {
             &CurrencyId::NATIVE => NATIVE_EXISTENTIAL_DEPOSIT,
-            CurrencyId::KSM  => KSM_EXISTENTIAL_DEPOSIT
+            CurrencyId::KSM  => KSM_EXISTENTIAL_DEPOSIT,
+            &CurrencyId::LIQUID => NATIVE_EXISTENTIAL_DEPOSIT,
+            // Not registered assets can't be deposited.
+            CurrencyId::ForeignAsset(_) => Currencies::existential_deposit(*currency_id)
+                .unwrap_or(Balance::MAX),
         }
     };
 }
 
+/// Currencies with existential deposit and metadata defined above, not by the registry.
+pub struct BuiltInCurrencies;
+impl Contains<CurrencyId> for BuiltInCurrencies {
+    fn contains(currency_id: &CurrencyId) -> bool {
+        !matches!(currency_id, CurrencyId::ForeignAsset(_))
+    }
+}
+
+/// Accounts which may hold balances below existential deposit: the treasury receiving dust
+/// and pallet accounts holding funds of users.
+pub struct DustRemovalWhitelist;
+impl Contains<AccountId> for DustRemovalWhitelist {
+    fn contains(account: &AccountId) -> bool {
+        *account == TreasuryAccount::get()
+            || *account == LiquidStaking::account_id()
+            || *account == Mvm::get_account_id()
+    }
+}
+
 parameter_type_with_key! {
     pub ParachainMinFee: |_location: MultiLocation| -> u128 {
         // TODO: configure fees for known parachains.
@@ -967,9 +1037,9 @@ impl orml_tokens::Config for Runtime {
     type CurrencyId = CurrencyId;
     type WeightInfo = ();
     type ExistentialDeposits = ExistentialDeposits;
-    type OnDust = ();
+    type OnDust = orml_tokens::TransferDust<Runtime, TreasuryAccount>;
     type MaxLocks = MaxLocks;
-    type DustRemovalWhitelist = Everything;
+    type DustRemovalWhitelist = DustRemovalWhitelist;
 }
 
 impl orml_unknown_tokens::Config for Runtime {
@@ -986,9 +1056,133 @@ impl module_currencies::Config for Runtime {
     type MultiCurrency = Tokens;
     type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
     type GetNativeCurrencyId = GetNativeCurrencyId;
-    type WeightInfo = ();
+    type WeightInfo = module_currencies::weights::SubstrateWeight<Runtime>;
     type SweepOrigin = EnsureRoot<AccountId>;
-    type OnDust = ();
+    type OnDust = module_currencies::TransferDust<Runtime, TreasuryAccount>;
+    type DustAccounts = module_currencies::SystemAndTokensAccounts<Runtime>;
+    type MaxDustSweepAccounts = ConstU32<100>;
+    type MaxBatchTransfers = ConstU32<100>;
+    type RegistryOrigin = EnsureRoot<AccountId>;
+    type StringLimit = ConstU32<32>;
+    type CurrencyIdConvert = CurrencyIdConvert;
+    type BuiltInCurrencies = BuiltInCurrencies;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CurrencyBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CurrencyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl module_currencies::BenchmarkHelper<CurrencyId> for CurrencyBenchmarkHelper {
+    fn registrable_currency() -> CurrencyId {
+        CurrencyId::ForeignAsset(0)
+    }
+}
+
+parameter_type_with_key! {
+    pub DefaultFeeRates: |currency_id: CurrencyId| -> Option<FixedU128> {
+        match currency_id {
+            // Native fee is `IdentityFee`, so KSM is charged per weight as by `SimpleWeightTrader`.
+            CurrencyId::KSM => {
+                use frame_support::weights::WeightToFeePolynomial;
+                Some(FixedU128::saturating_from_rational(
+                    kusama::KusamaWeightToFee::calc(&WEIGHT_PER_SECOND),
+                    WEIGHT_PER_SECOND,
+                ))
+            }
+            // Rates of other currencies are set by governance.
+            CurrencyId::NATIVE | CurrencyId::LIQUID | CurrencyId::ForeignAsset(_) => None,
+        }
+    };
+}
+
+parameter_types! {
+    pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account();
+}
+
+impl currency_fees::Config for Runtime {
+    type Event = Event;
+    type MultiCurrency = Currencies;
+    type NativeCurrencyId = GetNativeCurrencyId;
+    type DefaultFeeRates = DefaultFeeRates;
+    type FeeReceiver = TreasuryAccount;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = currency_fees::weights::PontemWeights<Runtime>;
+}
+
+parameter_types! {
+    pub const LiquidCurrencyId: CurrencyId = CurrencyId::LIQUID;
+    pub const LiquidStakingPalletId: PalletId = PalletId(*b"pont/lqs");
+    pub const MinLiquidDeposit: Balance = CurrencyId::native_times(1);
+}
+
+impl liquid_staking::Config for Runtime {
+    type Event = Event;
+    type MultiCurrency = Currencies;
+    type LiquidCurrencyId = LiquidCurrencyId;
+    type PalletId = LiquidStakingPalletId;
+    type MinDeposit = MinLiquidDeposit;
+    type WeightInfo = liquid_staking::weights::SubstrateWeight<Runtime>;
+}
+
+/// Balances of the account in the native currency and all tokens it holds.
+pub fn currency_balances(who: &AccountId) -> Vec<(CurrencyId, CurrencyBalance<Balance>)> {
+    let native = System::account(who).data;
+    let native_balance = CurrencyBalance {
+        free: native.free,
+        reserved: native.reserved,
+        frozen: native.misc_frozen.max(native.fee_frozen),
+        locks: Balances::locks(who)
+            .into_iter()
+            .map(|lock| (lock.id, lock.amount))
+            .collect(),
+    };
+
+    let tokens_balances =
+        orml_tokens::Accounts::<Runtime>::iter_prefix(who).map(|(currency_id, account)| {
+            let balance = CurrencyBalance {
+                free: account.free,
+                reserved: account.reserved,
+                frozen: account.frozen,
+                locks: Tokens::locks(who, currency_id)
+                    .into_iter()
+                    .map(|lock| (lock.id, lock.amount))
+                    .collect(),
+            };
+            (currency_id, balance)
+        });
+
+    sp_std::iter::once((GetNativeCurrencyId::get(), native_balance))
+        .chain(tokens_balances)
+        .collect()
+}
+
+/// Currencies registered in genesis and by migration of existing chains.
+pub struct KnownCurrencies;
+impl Get<Vec<GenesisCurrency<CurrencyId, Balance>>> for KnownCurrencies {
+    fn get() -> Vec<GenesisCurrency<CurrencyId, Balance>> {
+        #[cfg(not(feature = "pont"))]
+        let (native_name, liquid_name) = (b"Nox".to_vec(), b"Liquid Nox".to_vec());
+        #[cfg(feature = "pont")]
+        let (native_name, liquid_name) = (b"Pontem".to_vec(), b"Liquid Pontem".to_vec());
+
+        [
+            (CurrencyId::NATIVE, native_name),
+            (CurrencyId::KSM, b"Kusama".to_vec()),
+            (CurrencyId::LIQUID, liquid_name),
+        ]
+        .into_iter()
+        .filter_map(|(currency_id, name)| {
+            Some((
+                currency_id,
+                name,
+                currency_id.symbol(),
+                currency_id.decimals()?,
+                ExistentialDeposits::get(&currency_id),
+            ))
+        })
+        .collect()
+    }
 }
 
 pub struct AccountIdToMultiLocation;
@@ -1031,20 +1225,52 @@ impl orml_xcm::Config for Runtime {
     type SovereignOrigin = EnsureRoot<AccountId>;
 }
 
+/// Calls dispatched by groupsign and multisig calls, checked for pause in transaction pool.
+pub struct WrappedCalls;
+impl transaction_pause::NestedCalls<Call> for WrappedCalls {
+    fn nested_calls(call: &Call) -> Vec<Call> {
+        match call {
+            Call::Groupsign(groupsign::Call::groupsign_call { signed_call, .. }) => {
+                vec![*signed_call.clone()]
+            }
+            Call::Groupsign(groupsign::Call::groupsign_batch_call { calls, .. }) => calls.clone(),
+            Call::MultiSig(pallet_multisig::Call::as_multi_threshold_1 { call, .. }) => {
+                vec![*call.clone()]
+            }
+            Call::MultiSig(pallet_multisig::Call::as_multi { call, .. }) => {
+                call.try_decode().into_iter().collect()
+            }
+            _ => vec![],
+        }
+    }
+}
+
 impl transaction_pause::Config for Runtime {
     type Event = Event;
     type UpdateOrigin = EnsureRoot<AccountId>;
+    type PauseOrigin = EnsureRoot<AccountId>;
+    type RuntimeCalls = Call;
+    type NestedCalls = WrappedCalls;
+    type CoreCalls = CoreCalls;
+    type MaxPrunedCallCounts = ConstU32<100>;
     type WeightInfo = ();
 }
 
-pub struct BaseCallFilter;
-impl Contains<Call> for BaseCallFilter {
+/// Calls which can't be paused.
+pub struct CoreCalls;
+impl Contains<Call> for CoreCalls {
     fn contains(call: &Call) -> bool {
-        let is_core_call = matches!(
+        matches!(
             call,
             Call::System(_) | Call::Timestamp(_) | Call::ParachainSystem(_)
-        );
-        if is_core_call {
+        )
+    }
+}
+
+pub struct BaseCallFilter;
+impl Contains<Call> for BaseCallFilter {
+    fn contains(call: &Call) -> bool {
+        if CoreCalls::contains(call) {
             // always allow core call
             return true;
         }
@@ -1080,14 +1306,16 @@ construct_runtime!(
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 30,
         Vesting: pallet_vesting::{Pallet, Call, Storage, Config<T>, Event<T>},
         Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
-        Currencies: module_currencies::{Pallet, Call, Storage, Event<T>},
+        Currencies: module_currencies::{Pallet, Call, Storage, Config<T>, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Pallet, Storage},
+        CurrencyFees: currency_fees::{Pallet, Call, Storage, Event<T>},
 
         // Staking.
         ParachainStaking: parachain_staking::{Pallet, Call, Storage, Event<T>, Config<T>} = 40,
         AuthorInherent: pallet_author_inherent::{Pallet, Call, Storage, Inherent} = 41,
         AuthorFilter: pallet_author_slot_filter::{Pallet, Call, Storage, Event, Config} = 42,
         AuthorMapping: pallet_author_mapping::{Pallet, Call, Config<T>, Storage, Event<T>} = 43,
+        LiquidStaking: liquid_staking::{Pallet, Call, Storage, Event<T>} = 44,
 
         // Democracy
         Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>} = 50,
@@ -1131,12 +1359,23 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    transaction_pause::CheckPausedTransaction<Runtime>,
+    currency_fees::ChargeTransactionFee<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
+/// Storage migrations executed on runtime upgrade.
+pub type Migrations = (
+    migrations::MigrateBalanceToU128,
+    parachain_staking::migrations::MigrateBalanceType<Runtime, migrations::OldBalance>,
+    parachain_staking::migrations::SnapshotCollatorCommission<Runtime>,
+    parachain_staking::migrations::RemoveDeprecatedStorage<Runtime>,
+    transaction_pause::migrations::AddPauseExpiry<Runtime>,
+    transaction_pause::migrations::RemoveUnknownCalls<Runtime>,
+    module_currencies::migrations::RegisterCurrencies<Runtime, KnownCurrencies>,
+);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -1144,6 +1383,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsReversedWithSystemFirst,
+    Migrations,
 >;
 
 impl_runtime_apis! {
@@ -1283,6 +1523,70 @@ impl_runtime_apis! {
 
     }
 
+    impl transaction_pause_rpc_runtime::TransactionPauseApiRuntime<Block, BlockNumber> for Runtime {
+        // Get paused transactions.
+        fn paused_transactions() -> Vec<(Vec<u8>, Vec<u8>, Option<BlockNumber>)> {
+            TransactionPause::paused_transactions_list()
+        }
+
+        // Check if encoded call is paused.
+        fn is_call_paused(encoded_call: Vec<u8>) -> Result<bool, Vec<u8>> {
+            use codec::DecodeLimit;
+
+            let call = Call::decode_with_depth_limit(
+                sp_api::MAX_EXTRINSIC_DEPTH,
+                &mut encoded_call.as_slice(),
+            )
+            .map_err(|_| b"can't decode call".to_vec())?;
+
+            Ok(TransactionPause::is_paused(&call))
+        }
+    }
+
+    impl currencies_rpc_runtime::CurrenciesApiRuntime<Block, AccountId, CurrencyId, Balance> for Runtime {
+        // Get metadata of the registered currency.
+        fn currency_metadata(currency_id: CurrencyId) -> Option<CurrencyMetadata<Balance>> {
+            Currencies::currency_metadata(currency_id)
+        }
+
+        // Get metadata of all registered currencies.
+        fn currencies_metadata() -> Vec<(CurrencyId, CurrencyMetadata<Balance>)> {
+            Currencies::currencies_metadata()
+        }
+
+        // Get balances of the account in all currencies it holds.
+        fn balances(who: AccountId) -> Vec<(CurrencyId, CurrencyBalance<Balance>)> {
+            currency_balances(&who)
+        }
+    }
+
+    impl parachain_staking_rpc_runtime::ParachainStakingApiRuntime<Block, AccountId, Balance> for Runtime {
+        // Get rewards of the account per round for the rounds waiting for payout.
+        fn pending_rewards(who: AccountId) -> Vec<(u32, Balance)> {
+            ParachainStaking::pending_rewards(&who)
+        }
+
+        // Get reward the account is expected to receive for the round waiting for payout.
+        fn expected_reward(who: AccountId, round: u32) -> Balance {
+            ParachainStaking::expected_reward(&who, round)
+        }
+
+        // Get estimated annual return of staking with the candidate.
+        fn candidate_apr(candidate: AccountId) -> Option<StakingApr> {
+            ParachainStaking::candidate_apr(&candidate)
+        }
+
+        // Get delegations of the delegator with their pending requests.
+        fn delegator_overview(delegator: AccountId) -> Option<DelegatorOverview<AccountId, Balance>> {
+            ParachainStaking::delegator_overview(&delegator)
+        }
+
+        // Get authoring stats of the collator for the last rounds.
+        fn collator_liveness(collator: AccountId) -> CollatorLiveness {
+            ParachainStaking::collator_liveness(&collator)
+        }
+    }
+
     impl sp_session::SessionKeys<Block> for Runtime {
         fn decode_session_keys(
             encoded: Vec<u8>,
@@ -1365,6 +1669,9 @@ impl_runtime_apis! {
             list_benchmark!(list, extra, pallet_timestamp, Timestamp);
             list_benchmark!(list, extra, pallet_vesting, Vesting);
             list_benchmark!(list, extra, sp_mvm, Mvm);
+            list_benchmark!(list, extra, module_currencies, Currencies);
+            list_benchmark!(list, extra, liquid_staking, LiquidStaking);
+            list_benchmark!(list, extra, parachain_staking, ParachainStaking);
 
             let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -1400,6 +1707,9 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, pallet_timestamp, Timestamp);
             add_benchmark!(params, batches, pallet_vesting, Vesting);
             add_benchmark!(params, batches, sp_mvm, Mvm);
+            add_benchmark!(params, batches, module_currencies, Currencies);
+            add_benchmark!(params, batches, liquid_staking, LiquidStaking);
+            add_benchmark!(params, batches, parachain_staking, ParachainStaking);
 
             if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
             Ok(batches)
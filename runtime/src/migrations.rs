@@ -0,0 +1,453 @@
+//! Storage migrations of the runtime.
+
+use codec::{Decode, Encode};
+use frame_support::{
+    storage::{
+        migration::{put_storage_value, storage_iter},
+        unhashed,
+    },
+    log,
+    traits::{Get, OnRuntimeUpgrade, PalletInfoAccess},
+    weights::Weight,
+    BoundedVec, RuntimeDebug, WeakBoundedVec,
+};
+use pallet_democracy::{AccountVote, Conviction, VoteThreshold};
+use pallet_multisig::Timepoint;
+use sp_std::prelude::*;
+
+use crate::{
+    AccountId, AuthorMapping, Balance, BlockNumber, Democracy, Hash, Index, MultiSig, Runtime,
+    Treasury,
+};
+
+/// Balance type used by the runtime before switching to `u128`.
+pub type OldBalance = u64;
+
+type OldAccountInfo = frame_system::AccountInfo<Index, pallet_balances::AccountData<OldBalance>>;
+type OldVestingInfo = pallet_vesting::VestingInfo<OldBalance, BlockNumber>;
+type ReserveIdentifier = <Runtime as pallet_balances::Config>::ReserveIdentifier;
+
+/// Migrates `System`, `Balances`, `Tokens`, `Vesting`, `AuthorMapping`, `Treasury`, `Democracy`
+/// and `MultiSig` storage from `u64` to `u128` balances.
+///
+/// The migration is skipped when `Balances` total issuance is already encoded as `u128`,
+/// so it affects neither new chains nor repeated runtime upgrades.
+/// Parachain staking state is migrated by `parachain_staking::migrations::MigrateBalanceType`.
+/// Calls kept encoded in storage (multisig calls and democracy preimages) are not re-encoded.
+/// Bounded vectors longer than their bound are truncated with an error instead of being dropped.
+///
+/// The migration runs in a single block, its proof size is measured by
+/// `test_migrate_balance_to_u128_proof_size` for `MEASURED_ACCOUNTS` accounts
+/// and has to be checked against the state of the chain before the upgrade is enacted.
+pub struct MigrateBalanceToU128;
+impl OnRuntimeUpgrade for MigrateBalanceToU128 {
+    fn on_runtime_upgrade() -> Weight {
+        let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+        let total_issuance_key = pallet_balances::TotalIssuance::<Runtime>::hashed_key();
+        match unhashed::get_raw(&total_issuance_key) {
+            Some(raw) if raw.len() == sp_std::mem::size_of::<OldBalance>() => {}
+            _ => return db_weight.reads(1),
+        }
+
+        // total issuance of `Balances`
+        let mut migrated: Weight = 1;
+        let _ = pallet_balances::TotalIssuance::<Runtime>::translate(
+            |issuance: Option<OldBalance>| issuance.map(Balance::from),
+        );
+        frame_system::Account::<Runtime>::translate(|_: AccountId, info: OldAccountInfo| {
+            migrated += 1;
+            Some(frame_system::AccountInfo {
+                nonce: info.nonce,
+                consumers: info.consumers,
+                providers: info.providers,
+                sufficients: info.sufficients,
+                data: pallet_balances::AccountData {
+                    free: info.data.free.into(),
+                    reserved: info.data.reserved.into(),
+                    misc_frozen: info.data.misc_frozen.into(),
+                    fee_frozen: info.data.fee_frozen.into(),
+                },
+            })
+        });
+        pallet_balances::Locks::<Runtime>::translate(
+            |_: AccountId, locks: Vec<pallet_balances::BalanceLock<OldBalance>>| {
+                migrated += 1;
+                let locks = locks
+                    .into_iter()
+                    .map(|lock| pallet_balances::BalanceLock {
+                        id: lock.id,
+                        amount: lock.amount.into(),
+                        reasons: lock.reasons,
+                    })
+                    .collect();
+                Some(WeakBoundedVec::force_from(locks, Some("Balances locks")))
+            },
+        );
+        pallet_balances::Reserves::<Runtime>::translate(
+            |_: AccountId,
+             reserves: Vec<pallet_balances::ReserveData<ReserveIdentifier, OldBalance>>| {
+                migrated += 1;
+                let reserves: Vec<_> = reserves
+                    .into_iter()
+                    .map(|reserve| pallet_balances::ReserveData {
+                        id: reserve.id,
+                        amount: reserve.amount.into(),
+                    })
+                    .collect();
+                Some(truncate_to_bound("Balances reserves", reserves))
+            },
+        );
+
+        orml_tokens::TotalIssuance::<Runtime>::translate(|_, issuance: OldBalance| {
+            migrated += 1;
+            Some(issuance.into())
+        });
+        orml_tokens::Accounts::<Runtime>::translate(
+            |_, _, account: orml_tokens::AccountData<OldBalance>| {
+                migrated += 1;
+                Some(orml_tokens::AccountData {
+                    free: account.free.into(),
+                    reserved: account.reserved.into(),
+                    frozen: account.frozen.into(),
+                })
+            },
+        );
+        orml_tokens::Locks::<Runtime>::translate(
+            |_, _, locks: Vec<orml_tokens::BalanceLock<OldBalance>>| {
+                migrated += 1;
+                let locks: Vec<_> = locks
+                    .into_iter()
+                    .map(|lock| orml_tokens::BalanceLock {
+                        id: lock.id,
+                        amount: lock.amount.into(),
+                    })
+                    .collect();
+                Some(truncate_to_bound("Tokens locks", locks))
+            },
+        );
+
+        pallet_vesting::Vesting::<Runtime>::translate(
+            |_: AccountId, schedules: Vec<OldVestingInfo>| {
+                migrated += 1;
+                let schedules: Vec<_> = schedules
+                    .into_iter()
+                    .map(|schedule| {
+                        pallet_vesting::VestingInfo::new(
+                            schedule.locked().into(),
+                            schedule.per_block().into(),
+                            schedule.starting_block(),
+                        )
+                    })
+                    .collect();
+                Some(truncate_to_bound("Vesting schedules", schedules))
+            },
+        );
+
+        migrated += translate_values::<
+            AuthorMapping,
+            RegistrationInfo<OldBalance>,
+            RegistrationInfo<Balance>,
+        >(b"MappingWithDeposit", Into::into);
+        migrated += translate_values::<
+            Treasury,
+            TreasuryProposal<OldBalance>,
+            TreasuryProposal<Balance>,
+        >(b"Proposals", Into::into);
+        migrated += translate_values::<
+            Democracy,
+            (Vec<AccountId>, OldBalance),
+            (Vec<AccountId>, Balance),
+        >(b"DepositOf", |(depositors, deposit)| {
+            (depositors, deposit.into())
+        });
+        migrated += translate_values::<
+            Democracy,
+            PreimageStatus<OldBalance>,
+            PreimageStatus<Balance>,
+        >(b"Preimages", Into::into);
+        migrated += translate_values::<
+            Democracy,
+            ReferendumInfo<OldBalance>,
+            ReferendumInfo<Balance>,
+        >(b"ReferendumInfoOf", Into::into);
+        migrated += translate_values::<Democracy, Voting<OldBalance>, Voting<Balance>>(
+            b"VotingOf",
+            Into::into,
+        );
+        migrated += translate_values::<MultiSig, Multisig<OldBalance>, Multisig<Balance>>(
+            b"Multisigs",
+            Into::into,
+        );
+        migrated += translate_values::<
+            MultiSig,
+            (Vec<u8>, AccountId, OldBalance),
+            (Vec<u8>, AccountId, Balance),
+        >(b"Calls", |(call, depositor, deposit)| {
+            (call, depositor, deposit.into())
+        });
+
+        db_weight.reads_writes(migrated + 1, migrated)
+    }
+}
+
+/// Collect `values` of `storage` into a bounded vector,
+/// values over the bound are dropped with an error instead of the whole vector.
+fn truncate_to_bound<V, S: Get<u32>>(storage: &str, values: Vec<V>) -> BoundedVec<V, S> {
+    let len = values.len();
+    let mut bounded = BoundedVec::default();
+    for value in values {
+        if bounded.try_push(value).is_err() {
+            log::error!(
+                target: "MigrateBalanceToU128",
+                "{} {} exceed the bound of {}, truncated",
+                len,
+                storage,
+                S::get(),
+            );
+            break;
+        }
+    }
+    bounded
+}
+
+/// Re-encode all values of map `storage` of pallet `P` from `Old` to `New`,
+/// returns the number of translated values. Values which can't be decoded are kept.
+fn translate_values<P: PalletInfoAccess, Old: Decode, New: Encode>(
+    storage: &[u8],
+    f: impl Fn(Old) -> New,
+) -> Weight {
+    let pallet = P::name().as_bytes();
+    let values: Vec<(Vec<u8>, Old)> = storage_iter(pallet, storage).collect();
+    let translated = values.len() as Weight;
+    for (key, value) in values {
+        put_storage_value(pallet, storage, &key, f(value));
+    }
+    translated
+}
+
+// Types below are encoded as storage values of `AuthorMapping`, `Treasury`, `Democracy`
+// and `MultiSig`, whose fields are private to the pallets.
+
+/// `pallet_author_mapping::RegistrationInfo`.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+pub struct RegistrationInfo<B> {
+    pub account: AccountId,
+    pub deposit: B,
+}
+
+impl From<RegistrationInfo<OldBalance>> for RegistrationInfo<Balance> {
+    fn from(info: RegistrationInfo<OldBalance>) -> Self {
+        RegistrationInfo {
+            account: info.account,
+            deposit: info.deposit.into(),
+        }
+    }
+}
+
+/// `pallet_treasury::Proposal`.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+pub struct TreasuryProposal<B> {
+    pub proposer: AccountId,
+    pub value: B,
+    pub beneficiary: AccountId,
+    pub bond: B,
+}
+
+impl From<TreasuryProposal<OldBalance>> for TreasuryProposal<Balance> {
+    fn from(proposal: TreasuryProposal<OldBalance>) -> Self {
+        TreasuryProposal {
+            proposer: proposal.proposer,
+            value: proposal.value.into(),
+            beneficiary: proposal.beneficiary,
+            bond: proposal.bond.into(),
+        }
+    }
+}
+
+/// `pallet_democracy::PreimageStatus`.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+pub enum PreimageStatus<B> {
+    Missing(BlockNumber),
+    Available {
+        data: Vec<u8>,
+        provider: AccountId,
+        deposit: B,
+        since: BlockNumber,
+        expiry: Option<BlockNumber>,
+    },
+}
+
+impl From<PreimageStatus<OldBalance>> for PreimageStatus<Balance> {
+    fn from(status: PreimageStatus<OldBalance>) -> Self {
+        match status {
+            PreimageStatus::Missing(expiry) => PreimageStatus::Missing(expiry),
+            PreimageStatus::Available {
+                data,
+                provider,
+                deposit,
+                since,
+                expiry,
+            } => PreimageStatus::Available {
+                data,
+                provider,
+                deposit: deposit.into(),
+                since,
+                expiry,
+            },
+        }
+    }
+}
+
+/// `pallet_democracy::Tally`.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+pub struct Tally<B> {
+    pub ayes: B,
+    pub nays: B,
+    pub turnout: B,
+}
+
+impl From<Tally<OldBalance>> for Tally<Balance> {
+    fn from(tally: Tally<OldBalance>) -> Self {
+        Tally {
+            ayes: tally.ayes.into(),
+            nays: tally.nays.into(),
+            turnout: tally.turnout.into(),
+        }
+    }
+}
+
+/// `pallet_democracy::ReferendumInfo`.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+pub enum ReferendumInfo<B> {
+    Ongoing {
+        end: BlockNumber,
+        proposal_hash: Hash,
+        threshold: VoteThreshold,
+        delay: BlockNumber,
+        tally: Tally<B>,
+    },
+    Finished {
+        approved: bool,
+        end: BlockNumber,
+    },
+}
+
+impl From<ReferendumInfo<OldBalance>> for ReferendumInfo<Balance> {
+    fn from(info: ReferendumInfo<OldBalance>) -> Self {
+        match info {
+            ReferendumInfo::Ongoing {
+                end,
+                proposal_hash,
+                threshold,
+                delay,
+                tally,
+            } => ReferendumInfo::Ongoing {
+                end,
+                proposal_hash,
+                threshold,
+                delay,
+                tally: tally.into(),
+            },
+            ReferendumInfo::Finished { approved, end } => {
+                ReferendumInfo::Finished { approved, end }
+            }
+        }
+    }
+}
+
+/// `pallet_democracy::Delegations`.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+pub struct Delegations<B> {
+    pub votes: B,
+    pub capital: B,
+}
+
+impl From<Delegations<OldBalance>> for Delegations<Balance> {
+    fn from(delegations: Delegations<OldBalance>) -> Self {
+        Delegations {
+            votes: delegations.votes.into(),
+            capital: delegations.capital.into(),
+        }
+    }
+}
+
+/// `pallet_democracy::Voting`, the last field of both variants is the prior lock.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+pub enum Voting<B> {
+    Direct {
+        votes: Vec<(u32, AccountVote<B>)>,
+        delegations: Delegations<B>,
+        prior: (BlockNumber, B),
+    },
+    Delegating {
+        balance: B,
+        target: AccountId,
+        conviction: Conviction,
+        delegations: Delegations<B>,
+        prior: (BlockNumber, B),
+    },
+}
+
+impl From<Voting<OldBalance>> for Voting<Balance> {
+    fn from(voting: Voting<OldBalance>) -> Self {
+        match voting {
+            Voting::Direct {
+                votes,
+                delegations,
+                prior,
+            } => Voting::Direct {
+                votes: votes
+                    .into_iter()
+                    .map(|(index, vote)| {
+                        let vote = match vote {
+                            AccountVote::Standard { vote, balance } => AccountVote::Standard {
+                                vote,
+                                balance: balance.into(),
+                            },
+                            AccountVote::Split { aye, nay } => AccountVote::Split {
+                                aye: aye.into(),
+                                nay: nay.into(),
+                            },
+                        };
+                        (index, vote)
+                    })
+                    .collect(),
+                delegations: delegations.into(),
+                prior: (prior.0, prior.1.into()),
+            },
+            Voting::Delegating {
+                balance,
+                target,
+                conviction,
+                delegations,
+                prior,
+            } => Voting::Delegating {
+                balance: balance.into(),
+                target,
+                conviction,
+                delegations: delegations.into(),
+                prior: (prior.0, prior.1.into()),
+            },
+        }
+    }
+}
+
+/// `pallet_multisig::Multisig`.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+pub struct Multisig<B> {
+    pub when: Timepoint<BlockNumber>,
+    pub deposit: B,
+    pub depositor: AccountId,
+    pub approvals: Vec<AccountId>,
+}
+
+impl From<Multisig<OldBalance>> for Multisig<Balance> {
+    fn from(multisig: Multisig<OldBalance>) -> Self {
+        Multisig {
+            when: multisig.when,
+            deposit: multisig.deposit.into(),
+            depositor: multisig.depositor,
+            approvals: multisig.approvals,
+        }
+    }
+}
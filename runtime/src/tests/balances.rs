@@ -1,9 +1,13 @@
 /// Test balances in Runtime.
 use crate::tests::mock::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::Hooks};
+use module_currencies::CurrencyBalance;
 
-use sp_runtime::MultiAddress::Id as MultiId;
-use orml_traits::{currency::MultiCurrency, GetByKey};
+use sp_runtime::{MultiAddress::Id as MultiId, traits::Convert};
+use orml_traits::{
+    currency::{MultiCurrency, MultiLockableCurrency, MultiReservableCurrency},
+    GetByKey,
+};
 
 #[test]
 // Test correct native currency.
@@ -25,6 +29,26 @@ fn test_existential_deposits() {
     );
 }
 
+#[test]
+/// Test currencies metadata registered in genesis.
+fn test_currencies_metadata() {
+    RuntimeBuilder::new().build().execute_with(|| {
+        for currency_id in [CurrencyId::NATIVE, CurrencyId::KSM] {
+            let metadata = Currencies::currency_metadata(currency_id).unwrap();
+            assert_eq!(metadata.symbol, currency_id.symbol());
+            assert_eq!(Some(metadata.decimals), currency_id.decimals());
+            assert_eq!(
+                metadata.existential_deposit,
+                ExistentialDeposits::get(&currency_id)
+            );
+            assert_eq!(
+                metadata.location,
+                CurrencyIdConvert::convert(currency_id).map(Into::into)
+            );
+        }
+    });
+}
+
 #[test]
 /// Test transfer native currency using Balances pallet.
 fn transfer_native_currency_via_balances() {
@@ -212,6 +236,118 @@ fn transfer_tokens_via_currencies() {
         });
 }
 
+#[test]
+/// Test balances of all currencies of the account.
+fn test_currency_balances() {
+    let native_balance = to_unit(100, CurrencyId::NATIVE);
+    let ksm_balance = to_unit(10, CurrencyId::KSM);
+    let lock_id = *b"testlock";
+
+    RuntimeBuilder::new()
+        .set_balances(vec![
+            (
+                Accounts::ALICE.account(),
+                CurrencyId::NATIVE,
+                native_balance,
+            ),
+            (Accounts::ALICE.account(), CurrencyId::KSM, ksm_balance),
+        ])
+        .build()
+        .execute_with(|| {
+            let alice = Accounts::ALICE.account();
+            assert_ok!(Currencies::reserve(CurrencyId::NATIVE, &alice, 10));
+            assert_ok!(Currencies::set_lock(lock_id, CurrencyId::KSM, &alice, 20));
+
+            assert_eq!(
+                currency_balances(&alice),
+                vec![
+                    (
+                        CurrencyId::NATIVE,
+                        CurrencyBalance {
+                            free: native_balance - 10,
+                            reserved: 10,
+                            frozen: 0,
+                            locks: vec![],
+                        }
+                    ),
+                    (
+                        CurrencyId::KSM,
+                        CurrencyBalance {
+                            free: ksm_balance,
+                            reserved: 0,
+                            frozen: 20,
+                            locks: vec![(lock_id, 20)],
+                        }
+                    ),
+                ]
+            );
+
+            // Accounts without tokens have only native balance.
+            assert_eq!(
+                currency_balances(&Accounts::BOB.account()),
+                vec![(
+                    CurrencyId::NATIVE,
+                    CurrencyBalance {
+                        free: 0,
+                        reserved: 0,
+                        frozen: 0,
+                        locks: vec![],
+                    }
+                )]
+            );
+        });
+}
+
+#[test]
+/// Test tokens dust goes to treasury.
+fn tokens_dust_to_treasury() {
+    let currency_id = CurrencyId::KSM;
+    let initial_balance = to_unit(100, currency_id);
+    let dust = KSM_EXISTENTIAL_DEPOSIT / 2;
+
+    RuntimeBuilder::new()
+        .set_balances(vec![
+            (Accounts::ALICE.account(), CurrencyId::KSM, initial_balance),
+            (Accounts::BOB.account(), CurrencyId::KSM, initial_balance),
+        ])
+        .build()
+        .execute_with(|| {
+            // Alice's dust is transferred on reaping.
+            assert_ok!(Currencies::transfer(
+                Origin::signed(Accounts::ALICE.account()),
+                MultiId(Accounts::BOB.account()),
+                currency_id,
+                initial_balance - dust,
+            ));
+            assert_eq!(
+                Currencies::total_balance(currency_id, &Accounts::ALICE.account()),
+                0
+            );
+            assert_eq!(
+                Currencies::free_balance(currency_id, &TreasuryAccount::get()),
+                dust
+            );
+
+            // Bob's dust left by a storage migration is swept on idle.
+            orml_tokens::Accounts::<Runtime>::mutate(
+                Accounts::BOB.account(),
+                currency_id,
+                |account| account.free = dust,
+            );
+            for _ in Currencies::currencies_metadata() {
+                Currencies::on_idle(System::block_number(), Weight::MAX);
+            }
+            assert_eq!(
+                Currencies::total_balance(currency_id, &Accounts::BOB.account()),
+                0
+            );
+            assert_eq!(
+                Currencies::free_balance(currency_id, &TreasuryAccount::get()),
+                dust * 2
+            );
+        });
+}
+
 #[test]
 /// Test tokens transfer via tokens pallet.
 fn transfer_tokens_via_tokens() {
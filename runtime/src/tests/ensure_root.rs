@@ -10,13 +10,15 @@ fn ensure_root_in_transaction_pause() {
         assert_ok!(TransactionPause::pause_transaction(
             Origin::root(),
             b"Balances".to_vec(),
-            b"transfer".to_vec()
+            b"transfer".to_vec(),
+            None
         ));
         assert_err!(
             TransactionPause::pause_transaction(
                 Origin::signed(Accounts::BOB.account()),
                 b"Balances".to_vec(),
-                b"transfer".to_vec()
+                b"transfer".to_vec(),
+                None
             ),
             BadOrigin
         );
@@ -0,0 +1,482 @@
+/// Test storage migrations.
+use crate::tests::mock::*;
+use crate::migrations::{
+    Delegations, MigrateBalanceToU128, Multisig, OldBalance, PreimageStatus, ReferendumInfo,
+    RegistrationInfo, Tally, TreasuryProposal, Voting,
+};
+use codec::Encode;
+use polkadot_primitives::v1::MAX_POV_SIZE;
+use frame_support::{
+    assert_ok,
+    storage::unhashed,
+    traits::{Get, NamedReservableCurrency, OnRuntimeUpgrade},
+};
+use orml_traits::MultiLockableCurrency;
+use pallet_democracy::{AccountVote, Conviction, Vote, VoteThreshold};
+use pallet_multisig::Timepoint;
+
+/// Narrow balance to the old type.
+fn old(balance: Balance) -> OldBalance {
+    balance
+        .try_into()
+        .expect("test balances fit into the old type")
+}
+
+/// Re-encode genesis state of `System`, `Balances`, `Tokens` and `Vesting` with the old balance.
+fn put_old_genesis_state() {
+    unhashed::put(
+        &pallet_balances::TotalIssuance::<Runtime>::hashed_key(),
+        &old(Balances::total_issuance()),
+    );
+    for (who, info) in frame_system::Account::<Runtime>::iter() {
+        unhashed::put(
+            &frame_system::Account::<Runtime>::hashed_key_for(&who),
+            &frame_system::AccountInfo::<Index, pallet_balances::AccountData<OldBalance>> {
+                nonce: info.nonce,
+                consumers: info.consumers,
+                providers: info.providers,
+                sufficients: info.sufficients,
+                data: pallet_balances::AccountData {
+                    free: old(info.data.free),
+                    reserved: old(info.data.reserved),
+                    misc_frozen: old(info.data.misc_frozen),
+                    fee_frozen: old(info.data.fee_frozen),
+                },
+            },
+        );
+    }
+    for (who, locks) in pallet_balances::Locks::<Runtime>::iter() {
+        let locks: Vec<_> = locks
+            .into_iter()
+            .map(|lock| pallet_balances::BalanceLock::<OldBalance> {
+                id: lock.id,
+                amount: old(lock.amount),
+                reasons: lock.reasons,
+            })
+            .collect();
+        unhashed::put(
+            &pallet_balances::Locks::<Runtime>::hashed_key_for(&who),
+            &locks,
+        );
+    }
+    for (who, reserves) in pallet_balances::Reserves::<Runtime>::iter() {
+        let reserves: Vec<_> = reserves
+            .into_iter()
+            .map(
+                |reserve| pallet_balances::ReserveData::<[u8; 8], OldBalance> {
+                    id: reserve.id,
+                    amount: old(reserve.amount),
+                },
+            )
+            .collect();
+        unhashed::put(
+            &pallet_balances::Reserves::<Runtime>::hashed_key_for(&who),
+            &reserves,
+        );
+    }
+    for (currency_id, issuance) in orml_tokens::TotalIssuance::<Runtime>::iter() {
+        unhashed::put(
+            &orml_tokens::TotalIssuance::<Runtime>::hashed_key_for(currency_id),
+            &old(issuance),
+        );
+    }
+    for (who, currency_id, account) in orml_tokens::Accounts::<Runtime>::iter() {
+        unhashed::put(
+            &orml_tokens::Accounts::<Runtime>::hashed_key_for(&who, currency_id),
+            &orml_tokens::AccountData::<OldBalance> {
+                free: old(account.free),
+                reserved: old(account.reserved),
+                frozen: old(account.frozen),
+            },
+        );
+    }
+    for (who, currency_id, locks) in orml_tokens::Locks::<Runtime>::iter() {
+        let locks: Vec<_> = locks
+            .into_iter()
+            .map(|lock| orml_tokens::BalanceLock::<OldBalance> {
+                id: lock.id,
+                amount: old(lock.amount),
+            })
+            .collect();
+        unhashed::put(
+            &orml_tokens::Locks::<Runtime>::hashed_key_for(&who, currency_id),
+            &locks,
+        );
+    }
+    for (who, schedules) in pallet_vesting::Vesting::<Runtime>::iter() {
+        let schedules: Vec<_> = schedules
+            .into_iter()
+            .map(|schedule| {
+                pallet_vesting::VestingInfo::<OldBalance, BlockNumber>::new(
+                    old(schedule.locked()),
+                    old(schedule.per_block()),
+                    schedule.starting_block(),
+                )
+            })
+            .collect();
+        unhashed::put(
+            &pallet_vesting::Vesting::<Runtime>::hashed_key_for(&who),
+            &schedules,
+        );
+    }
+}
+
+#[test]
+/// Test balances of all pallets are migrated from `u64` to `u128` only once.
+fn test_migrate_balance_to_u128() {
+    let alice = Accounts::ALICE.account();
+    let bob = Accounts::BOB.account();
+
+    RuntimeBuilder::new()
+        .set_balances(vec![
+            (
+                alice.clone(),
+                CurrencyId::NATIVE,
+                CurrencyId::native_times(100),
+            ),
+            (
+                bob.clone(),
+                CurrencyId::NATIVE,
+                CurrencyId::native_times(200),
+            ),
+            (
+                alice.clone(),
+                CurrencyId::KSM,
+                CurrencyId::KSM.times(10).unwrap(),
+            ),
+        ])
+        .set_vesting(vec![(alice.clone(), 10, 100, CurrencyId::native_times(50))])
+        .build()
+        .execute_with(|| {
+            // Genesis state, with a reserve and a tokens lock added.
+            assert_ok!(Balances::reserve_named(
+                &[1; 8],
+                &bob,
+                CurrencyId::native_times(10)
+            ));
+            assert_ok!(<Tokens as MultiLockableCurrency<AccountId>>::set_lock(
+                *b"testlock",
+                CurrencyId::KSM,
+                &alice,
+                CurrencyId::KSM.times(5).unwrap(),
+            ));
+            let total_issuance = Balances::total_issuance();
+            let accounts: Vec<_> = frame_system::Account::<Runtime>::iter().collect();
+            let locks: Vec<_> = pallet_balances::Locks::<Runtime>::iter().collect();
+            let reserves: Vec<_> = pallet_balances::Reserves::<Runtime>::iter().collect();
+            let tokens_issuance: Vec<_> = orml_tokens::TotalIssuance::<Runtime>::iter().collect();
+            let tokens_accounts: Vec<_> = orml_tokens::Accounts::<Runtime>::iter().collect();
+            let tokens_locks: Vec<_> = orml_tokens::Locks::<Runtime>::iter().collect();
+            let vesting: Vec<_> = pallet_vesting::Vesting::<Runtime>::iter().collect();
+            assert!(!locks.is_empty() && !reserves.is_empty() && !vesting.is_empty());
+            assert!(!tokens_accounts.is_empty() && !tokens_locks.is_empty());
+            put_old_genesis_state();
+
+            // State of other pallets holding balances, encoded with the old balance.
+            let author_id = nimbus_primitives::NimbusId::from(Accounts::ALICE.public_key());
+            let registration = RegistrationInfo::<OldBalance> {
+                account: alice.clone(),
+                deposit: 100,
+            };
+            unhashed::put(
+                &pallet_author_mapping::MappingWithDeposit::<Runtime>::hashed_key_for(&author_id),
+                &registration,
+            );
+            let proposal = TreasuryProposal::<OldBalance> {
+                proposer: alice.clone(),
+                value: 1_000,
+                beneficiary: bob.clone(),
+                bond: 50,
+            };
+            unhashed::put(
+                &pallet_treasury::Proposals::<Runtime>::hashed_key_for(0),
+                &proposal,
+            );
+            let deposit: (Vec<AccountId>, OldBalance) = (vec![alice.clone(), bob.clone()], 10);
+            unhashed::put(
+                &pallet_democracy::DepositOf::<Runtime>::hashed_key_for(0),
+                &deposit,
+            );
+            let (available_hash, missing_hash) = (Hash::repeat_byte(1), Hash::repeat_byte(2));
+            let available = PreimageStatus::<OldBalance>::Available {
+                data: vec![1, 2, 3],
+                provider: alice.clone(),
+                deposit: 30,
+                since: 1,
+                expiry: Some(100),
+            };
+            let missing = PreimageStatus::<OldBalance>::Missing(100);
+            unhashed::put(
+                &pallet_democracy::Preimages::<Runtime>::hashed_key_for(available_hash),
+                &available,
+            );
+            unhashed::put(
+                &pallet_democracy::Preimages::<Runtime>::hashed_key_for(missing_hash),
+                &missing,
+            );
+            let ongoing = ReferendumInfo::<OldBalance>::Ongoing {
+                end: 100,
+                proposal_hash: available_hash,
+                threshold: VoteThreshold::SuperMajorityApprove,
+                delay: 10,
+                tally: Tally {
+                    ayes: 40,
+                    nays: 20,
+                    turnout: 60,
+                },
+            };
+            let finished = ReferendumInfo::<OldBalance>::Finished {
+                approved: true,
+                end: 50,
+            };
+            unhashed::put(
+                &pallet_democracy::ReferendumInfoOf::<Runtime>::hashed_key_for(0),
+                &ongoing,
+            );
+            unhashed::put(
+                &pallet_democracy::ReferendumInfoOf::<Runtime>::hashed_key_for(1),
+                &finished,
+            );
+            let direct = Voting::<OldBalance>::Direct {
+                votes: vec![
+                    (
+                        0,
+                        AccountVote::Standard {
+                            vote: Vote {
+                                aye: true,
+                                conviction: Conviction::Locked1x,
+                            },
+                            balance: 40,
+                        },
+                    ),
+                    (1, AccountVote::Split { aye: 5, nay: 15 }),
+                ],
+                delegations: Delegations {
+                    votes: 20,
+                    capital: 20,
+                },
+                prior: (10, 5),
+            };
+            let delegating = Voting::<OldBalance>::Delegating {
+                balance: 20,
+                target: alice.clone(),
+                conviction: Conviction::Locked1x,
+                delegations: Delegations {
+                    votes: 0,
+                    capital: 0,
+                },
+                prior: (0, 0),
+            };
+            unhashed::put(
+                &pallet_democracy::VotingOf::<Runtime>::hashed_key_for(&alice),
+                &direct,
+            );
+            unhashed::put(
+                &pallet_democracy::VotingOf::<Runtime>::hashed_key_for(&bob),
+                &delegating,
+            );
+            let call_hash = [1; 32];
+            let multisig = Multisig::<OldBalance> {
+                when: Timepoint {
+                    height: 1,
+                    index: 0,
+                },
+                deposit: 70,
+                depositor: alice.clone(),
+                approvals: vec![alice.clone()],
+            };
+            let call: (Vec<u8>, AccountId, OldBalance) = (vec![0, 1], alice.clone(), 20);
+            unhashed::put(
+                &pallet_multisig::Multisigs::<Runtime>::hashed_key_for(&alice, call_hash),
+                &multisig,
+            );
+            unhashed::put(
+                &pallet_multisig::Calls::<Runtime>::hashed_key_for(call_hash),
+                &call,
+            );
+
+            MigrateBalanceToU128::on_runtime_upgrade();
+
+            let assert_migrated = || {
+                assert_eq!(Balances::total_issuance(), total_issuance);
+                assert_eq!(
+                    frame_system::Account::<Runtime>::iter().collect::<Vec<_>>(),
+                    accounts
+                );
+                assert_eq!(
+                    pallet_balances::Locks::<Runtime>::iter().collect::<Vec<_>>(),
+                    locks
+                );
+                assert_eq!(
+                    pallet_balances::Reserves::<Runtime>::iter().collect::<Vec<_>>(),
+                    reserves
+                );
+                assert_eq!(
+                    orml_tokens::TotalIssuance::<Runtime>::iter().collect::<Vec<_>>(),
+                    tokens_issuance
+                );
+                assert_eq!(
+                    orml_tokens::Accounts::<Runtime>::iter().collect::<Vec<_>>(),
+                    tokens_accounts
+                );
+                assert_eq!(
+                    orml_tokens::Locks::<Runtime>::iter().collect::<Vec<_>>(),
+                    tokens_locks
+                );
+                assert_eq!(
+                    pallet_vesting::Vesting::<Runtime>::iter().collect::<Vec<_>>(),
+                    vesting
+                );
+            };
+            // Pallet types with private fields are compared by encoding of decoded values.
+            let state = || {
+                (
+                    pallet_author_mapping::MappingWithDeposit::<Runtime>::get(&author_id),
+                    pallet_treasury::Proposals::<Runtime>::get(0),
+                    pallet_democracy::DepositOf::<Runtime>::get(0),
+                    pallet_democracy::Preimages::<Runtime>::get(available_hash),
+                    pallet_democracy::Preimages::<Runtime>::get(missing_hash),
+                    pallet_democracy::ReferendumInfoOf::<Runtime>::get(0),
+                    pallet_democracy::ReferendumInfoOf::<Runtime>::get(1),
+                    pallet_democracy::VotingOf::<Runtime>::get(&alice),
+                    pallet_democracy::VotingOf::<Runtime>::get(&bob),
+                    pallet_multisig::Multisigs::<Runtime>::get(&alice, call_hash),
+                    pallet_multisig::Calls::<Runtime>::get(call_hash),
+                )
+                    .encode()
+            };
+            let expected = (
+                Some(RegistrationInfo::<Balance>::from(registration)),
+                Some(TreasuryProposal::<Balance>::from(proposal)),
+                Some((deposit.0, Balance::from(deposit.1))),
+                Some(PreimageStatus::<Balance>::from(available)),
+                Some(PreimageStatus::<Balance>::from(missing)),
+                Some(ReferendumInfo::<Balance>::from(ongoing)),
+                Some(ReferendumInfo::<Balance>::from(finished)),
+                Voting::<Balance>::from(direct),
+                Voting::<Balance>::from(delegating),
+                Some(Multisig::<Balance>::from(multisig)),
+                Some((call.0, call.1, Balance::from(call.2))),
+            )
+                .encode();
+            assert_eq!(state(), expected);
+            assert_migrated();
+
+            // Repeated upgrade doesn't touch migrated state.
+            MigrateBalanceToU128::on_runtime_upgrade();
+
+            assert_eq!(state(), expected);
+            assert_migrated();
+        });
+}
+
+#[test]
+/// Test reserves over the bound are truncated instead of dropping all reserves of the account.
+fn test_migrate_balance_to_u128_truncates_reserves() {
+    let bob = Accounts::BOB.account();
+
+    RuntimeBuilder::new()
+        .set_balances(vec![(
+            bob.clone(),
+            CurrencyId::NATIVE,
+            CurrencyId::native_times(200),
+        )])
+        .build()
+        .execute_with(|| {
+            put_old_genesis_state();
+            let max_reserves = <Runtime as pallet_balances::Config>::MaxReserves::get();
+            let reserves: Vec<_> = (0..=max_reserves)
+                .map(
+                    |index| pallet_balances::ReserveData::<[u8; 8], OldBalance> {
+                        id: [index as u8; 8],
+                        amount: 1,
+                    },
+                )
+                .collect();
+            unhashed::put(
+                &pallet_balances::Reserves::<Runtime>::hashed_key_for(&bob),
+                &reserves,
+            );
+
+            MigrateBalanceToU128::on_runtime_upgrade();
+
+            let migrated = pallet_balances::Reserves::<Runtime>::get(&bob);
+            assert_eq!(migrated.len(), max_reserves as usize);
+            assert_eq!(migrated[0].id, [0; 8]);
+            assert_eq!(migrated[0].amount, 1);
+        });
+}
+
+/// Number of accounts, each with a lock, a reserve and a `KSM` account,
+/// whose migration proof size is measured.
+const MEASURED_ACCOUNTS: u32 = 2_000;
+
+#[test]
+/// Test the proof of migrating `MEASURED_ACCOUNTS` accounts fits into a parachain block.
+fn test_migrate_balance_to_u128_proof_size() {
+    let mut ext = RuntimeBuilder::new().build();
+    ext.execute_with(|| {
+        put_old_genesis_state();
+        for index in 0..MEASURED_ACCOUNTS {
+            let mut raw = [0; 32];
+            raw[..4].copy_from_slice(&index.to_le_bytes());
+            let who = AccountId::from(raw);
+            unhashed::put(
+                &frame_system::Account::<Runtime>::hashed_key_for(&who),
+                &frame_system::AccountInfo::<Index, pallet_balances::AccountData<OldBalance>> {
+                    nonce: 1,
+                    consumers: 1,
+                    providers: 1,
+                    sufficients: 0,
+                    data: pallet_balances::AccountData {
+                        free: 1_000,
+                        reserved: 100,
+                        misc_frozen: 100,
+                        fee_frozen: 100,
+                    },
+                },
+            );
+            unhashed::put(
+                &pallet_balances::Locks::<Runtime>::hashed_key_for(&who),
+                &vec![pallet_balances::BalanceLock::<OldBalance> {
+                    id: *b"testlock",
+                    amount: 100,
+                    reasons: pallet_balances::Reasons::All,
+                }],
+            );
+            unhashed::put(
+                &pallet_balances::Reserves::<Runtime>::hashed_key_for(&who),
+                &vec![pallet_balances::ReserveData::<[u8; 8], OldBalance> {
+                    id: [1; 8],
+                    amount: 100,
+                }],
+            );
+            unhashed::put(
+                &orml_tokens::Accounts::<Runtime>::hashed_key_for(&who, CurrencyId::KSM),
+                &orml_tokens::AccountData::<OldBalance> {
+                    free: 1_000,
+                    reserved: 0,
+                    frozen: 0,
+                },
+            );
+        }
+    });
+    ext.commit_all().unwrap();
+
+    let (_, proof) = ext.execute_and_prove(MigrateBalanceToU128::on_runtime_upgrade);
+    let proof_size = proof.encoded_size();
+    println!(
+        "proof size of migrating {} accounts: {} bytes",
+        MEASURED_ACCOUNTS, proof_size
+    );
+    // half of the PoV is left to the rest of the block
+    assert!(proof_size < MAX_POV_SIZE as usize / 2);
+
+    ext.execute_with(|| {
+        let mut raw = [0; 32];
+        raw[..4].copy_from_slice(&(MEASURED_ACCOUNTS - 1).to_le_bytes());
+        let who = AccountId::from(raw);
+        assert_eq!(System::account(&who).data.free, 1_000);
+        assert_eq!(pallet_balances::Locks::<Runtime>::get(&who)[0].amount, 100);
+    });
+}
@@ -65,7 +65,7 @@ impl Into<[u8; 32]> for Accounts {
 
 /// Balance to currency unit (e.g. 1 PONT).
 pub fn to_unit(amount: Balance, currency_id: CurrencyId) -> Balance {
-    amount * u64::pow(10, currency_id.decimals() as u32)
+    currency_id.times(amount).unwrap()
 }
 
 /// Roll till next block.
@@ -86,7 +86,7 @@ pub fn run_to_block(till: u32) {
 pub struct RuntimeBuilder {
     balances: Vec<(AccountId, CurrencyId, Balance)>,
     vesting: Vec<(AccountId, BlockNumber, u32, Balance)>,
-    paused: Vec<(Vec<u8>, Vec<u8>)>,
+    paused: Vec<(Vec<u8>, Vec<u8>, Option<BlockNumber>)>,
     parachain_id: Option<u32>,
 }
 
@@ -114,7 +114,7 @@ impl RuntimeBuilder {
     }
 
     /// Set paused transactions.
-    pub fn set_paused(mut self, paused: Vec<(Vec<u8>, Vec<u8>)>) -> Self {
+    pub fn set_paused(mut self, paused: Vec<(Vec<u8>, Vec<u8>, Option<BlockNumber>)>) -> Self {
         self.paused = paused;
         self
     }
@@ -176,6 +176,13 @@ impl RuntimeBuilder {
         )
         .unwrap();
 
+        // Currencies locations depend on parachain id.
+        module_currencies::GenesisConfig::<Runtime> {
+            currencies: KnownCurrencies::get(),
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
         <pallet_xcm::GenesisConfig as GenesisBuild<Runtime>>::assimilate_storage(
             &pallet_xcm::GenesisConfig {
                 safe_xcm_version: Some(2),
@@ -1,7 +1,9 @@
 pub mod balances;
+pub mod migrations;
 pub mod mock;
 pub mod mvm;
 pub mod runtime_parachain;
+pub mod transaction_fees;
 pub mod transation_pause;
 pub mod vesting;
 
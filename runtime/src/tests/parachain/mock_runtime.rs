@@ -72,8 +72,8 @@ pub type Block = frame_system::mocking::MockBlock<Runtime>;
 pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 
 pub type AccountId = AccountId32;
-pub type Balance = u64;
-pub type Amount = i64;
+pub type Balance = u128;
+pub type Amount = i128;
 pub type Hash = sp_core::H256;
 pub type BlockNumber = u64;
 pub type Index = u64;
@@ -527,6 +527,24 @@ impl module_currencies::Config for Runtime {
     type WeightInfo = ();
     type SweepOrigin = EnsureRoot<AccountId>;
     type OnDust = ();
+    type DustAccounts = ();
+    type MaxDustSweepAccounts = ConstU32<0>;
+    type MaxBatchTransfers = ConstU32<10>;
+    type RegistryOrigin = EnsureRoot<AccountId>;
+    type StringLimit = ConstU32<32>;
+    type CurrencyIdConvert = CurrencyIdConvert;
+    type BuiltInCurrencies = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CurrencyBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CurrencyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl module_currencies::BenchmarkHelper<CurrencyId> for CurrencyBenchmarkHelper {
+    fn registrable_currency() -> CurrencyId {
+        CurrencyId::KSM
+    }
 }
 
 pub struct AccountIdToMultiLocation;
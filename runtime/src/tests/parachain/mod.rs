@@ -78,8 +78,8 @@ pub fn mock_para_ext(para_id: u32) -> TestExternalities {
 
     pallet_balances::GenesisConfig::<Runtime> {
         balances: vec![
-            (Accounts::ALICE.account(), CurrencyId::NATIVE * 2000),
-            (Accounts::BOB.account(), CurrencyId::NATIVE * 2000),
+            (Accounts::ALICE.account(), CurrencyId::native_times(2000)),
+            (Accounts::BOB.account(), CurrencyId::native_times(2000)),
         ],
     }
     .assimilate_storage(&mut t)
@@ -97,7 +97,7 @@ pub fn mock_para_ext(para_id: u32) -> TestExternalities {
         balances: vec![(
             Accounts::ALICE.account(),
             mock_runtime::CurrencyId::KSM,
-            2000 * dollar(CurrencyId::KSM) as u64,
+            2000 * dollar(CurrencyId::KSM).unwrap(),
         )],
     }
     .assimilate_storage(&mut t)
@@ -114,12 +114,12 @@ pub fn para_ext(parachain_id: u32) -> TestExternalities {
             (
                 Accounts::ALICE.account(),
                 CurrencyId::NATIVE,
-                CurrencyId::NATIVE.times(2000),
+                CurrencyId::native_times(2000),
             ),
             (
                 Accounts::ALICE.account(),
                 CurrencyId::KSM,
-                CurrencyId::KSM.times(2000),
+                CurrencyId::KSM.times(2000).unwrap(),
             ),
         ])
         .set_parachain_id(parachain_id)
@@ -172,7 +172,10 @@ pub fn relay_ext() -> TestExternalities {
         .unwrap();
 
     pallet_balances::GenesisConfig::<Runtime> {
-        balances: vec![(Accounts::ALICE.account(), 2000 * dollar(CurrencyId::KSM))],
+        balances: vec![(
+            Accounts::ALICE.account(),
+            2000 * dollar(CurrencyId::KSM).unwrap(),
+        )],
     }
     .assimilate_storage(&mut t)
     .unwrap();
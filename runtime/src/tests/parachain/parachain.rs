@@ -30,7 +30,7 @@ fn transfer_from_relay_chain() {
                 .into()
                 .into()
             ),
-            Box::new((Here, CurrencyId::KSM.times(100) as u128).into()),
+            Box::new((Here, CurrencyId::KSM.times(100).unwrap()).into()),
             0,
         ));
     });
@@ -48,15 +48,17 @@ fn send_relay_chain_asset_to_relay_chain() {
     TestNet::reset();
 
     Relay::execute_with(|| {
-        let _ =
-            RelayBalances::deposit_creating(&para_a_account(), CurrencyId::KSM.times(2).into());
+        let _ = RelayBalances::deposit_creating(
+            &para_a_account(),
+            CurrencyId::KSM.times(2).unwrap().into(),
+        );
     });
 
     ParaA::execute_with(|| {
         assert_ok!(ParaAXTokens::transfer(
             Some(Accounts::ALICE.account()).into(),
             CurrencyId::KSM,
-            CurrencyId::KSM.times(1),
+            CurrencyId::KSM.times(1).unwrap(),
             Box::new(
                 MultiLocation::new(
                     1,
@@ -71,14 +73,14 @@ fn send_relay_chain_asset_to_relay_chain() {
         ));
         assert_eq!(
             ParaATokens::free_balance(CurrencyId::KSM, &Accounts::ALICE.account()),
-            CurrencyId::KSM.times(1999)
+            CurrencyId::KSM.times(1999).unwrap()
         );
     });
 
     Relay::execute_with(|| {
         assert_eq!(
             RelayBalances::free_balance(&para_a_account()),
-            CurrencyId::KSM.times(1) as u128
+            CurrencyId::KSM.times(1).unwrap()
         );
         assert_eq!(
             RelayBalances::free_balance(&Accounts::BOB.account()),
@@ -95,13 +97,13 @@ fn cannot_lost_fund_on_send_failed() {
         assert_ok!(ParaATokens::deposit(
             CurrencyId::NATIVE,
             &Accounts::ALICE.account(),
-            CurrencyId::NATIVE * 1_000
+            CurrencyId::native_times(1_000)
         ));
         assert_noop!(
             ParaAXTokens::transfer(
                 Some(Accounts::ALICE.account()).into(),
                 CurrencyId::NATIVE,
-                CurrencyId::NATIVE * 500,
+                CurrencyId::native_times(500),
                 Box::new(
                     MultiLocation::new(
                         1,
@@ -115,14 +117,14 @@ fn cannot_lost_fund_on_send_failed() {
                     )
                     .into()
                 ),
-                CurrencyId::NATIVE * 30,
+                CurrencyId::native_times(30),
             ),
             Error::<crate::Runtime>::XcmExecutionFailed
         );
 
         assert_eq!(
             ParaATokens::free_balance(CurrencyId::NATIVE, &Accounts::ALICE.account()),
-            CurrencyId::NATIVE * 1_000
+            CurrencyId::native_times(1_000)
         );
     });
 }
@@ -132,15 +134,17 @@ fn send_relay_chain_asset_to_sibling() {
     TestNet::reset();
 
     Relay::execute_with(|| {
-        let _ =
-            RelayBalances::deposit_creating(&para_a_account(), CurrencyId::KSM.times(4).into());
+        let _ = RelayBalances::deposit_creating(
+            &para_a_account(),
+            CurrencyId::KSM.times(4).unwrap().into(),
+        );
     });
 
     ParaA::execute_with(|| {
         assert_ok!(ParaAXTokens::transfer(
             Some(Accounts::ALICE.account()).into(),
             CurrencyId::KSM,
-            CurrencyId::KSM.times(3),
+            CurrencyId::KSM.times(3).unwrap(),
             Box::new(
                 MultiLocation::new(
                     1,
@@ -158,7 +162,7 @@ fn send_relay_chain_asset_to_sibling() {
         ));
         assert_eq!(
             ParaATokens::free_balance(CurrencyId::KSM, &Accounts::ALICE.account()),
-            CurrencyId::KSM.times(1997)
+            CurrencyId::KSM.times(1997).unwrap()
         );
     });
 
@@ -179,7 +183,7 @@ fn send_self_parachain_asset_to_sibling() {
         assert_ok!(ParaAXTokens::transfer(
             Some(Accounts::ALICE.account()).into(),
             CurrencyId::NATIVE,
-            CurrencyId::NATIVE * 500,
+            CurrencyId::native_times(500),
             Box::new(
                 MultiLocation::new(
                     1,
@@ -205,7 +209,7 @@ fn send_self_parachain_asset_to_sibling() {
     ParaB::execute_with(|| {
         assert_eq!(
             ParaBTokens::free_balance(MockCurrencyId::NATIVE, &Accounts::BOB.account()),
-            CurrencyId::NATIVE * 500 - 4
+            CurrencyId::native_times(500) - 4
         );
     });
 
@@ -214,7 +218,7 @@ fn send_self_parachain_asset_to_sibling() {
         assert_ok!(ParaBXTokens::transfer(
             Some(Accounts::BOB.account()).into(),
             MockCurrencyId::NATIVE,
-            CurrencyId::NATIVE * 500 - 4,
+            CurrencyId::native_times(500) - 4,
             Box::new(
                 MultiLocation::new(
                     1,
@@ -240,7 +244,7 @@ fn send_self_parachain_asset_to_sibling() {
     ParaA::execute_with(|| {
         assert_eq!(
             ParaABalances::free_balance(&Accounts::BOB.account()),
-            CurrencyId::NATIVE * 500 - 8
+            CurrencyId::native_times(500) - 8
         );
     });
 }
@@ -256,7 +260,7 @@ fn transfer_no_reserve_assets_fails() {
                 Box::new(
                     MultiAsset {
                         id: xcm_emulator::Concrete(GeneralKey(NATIVE_SYM.to_vec()).into()),
-                        fun: (CurrencyId::NATIVE.times(100) as u128).into(),
+                        fun: CurrencyId::native_times(100).into(),
                     }
                     .into()
                 ),
@@ -291,7 +295,7 @@ fn transfer_to_self_chain_fails() {
                 Box::new(
                     MultiAsset {
                         id: (Parent, Parachain(2000), GeneralKey(NATIVE_SYM.to_vec())).into(),
-                        fun: (CurrencyId::NATIVE.times(100) as u128).into(),
+                        fun: CurrencyId::native_times(100).into(),
                     }
                     .into()
                 ),
@@ -326,7 +330,7 @@ fn transfer_to_invalid_dest_fails() {
                 Box::new(
                     MultiAsset {
                         id: (Parent, Parachain(2000), GeneralKey(NATIVE_SYM.to_vec())).into(),
-                        fun: (CurrencyId::NATIVE.times(100) as u128).into(),
+                        fun: CurrencyId::native_times(100).into(),
                     }
                     .into()
                 ),
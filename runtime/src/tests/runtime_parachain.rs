@@ -1,5 +1,7 @@
 /// Test parachains locations.
 use crate::tests::mock::*;
+use frame_support::assert_ok;
+use orml_traits::GetByKey;
 use sp_runtime::traits::Convert;
 
 #[test]
@@ -42,3 +44,35 @@ fn test_currency_id_convertations() {
         );
     });
 }
+
+#[test]
+fn test_foreign_asset_convertations() {
+    RuntimeBuilder::new().build().execute_with(|| {
+        let asset_id = CurrencyId::ForeignAsset(0);
+        let location = MultiLocation {
+            parents: 1,
+            interior: X2(Parachain(2001), GeneralKey(b"ASSET".to_vec())),
+        };
+
+        // Not registered assets are unknown.
+        assert_eq!(CurrencyIdConvert::convert(asset_id), None);
+        assert_eq!(CurrencyIdConvert::convert(location.clone()), None);
+        assert_eq!(ExistentialDeposits::get(&asset_id), Balance::MAX);
+
+        assert_ok!(Currencies::register_currency(
+            Origin::root(),
+            asset_id,
+            module_currencies::CurrencyMetadata {
+                name: b"Sibling asset".to_vec(),
+                symbol: b"ASSET".to_vec(),
+                decimals: 12,
+                existential_deposit: 1_000,
+                location: Some(location.clone().into()),
+            }
+        ));
+
+        assert_eq!(CurrencyIdConvert::convert(asset_id), Some(location.clone()));
+        assert_eq!(CurrencyIdConvert::convert(location), Some(asset_id));
+        assert_eq!(ExistentialDeposits::get(&asset_id), 1_000);
+    });
+}
@@ -0,0 +1,69 @@
+/// Test transaction fees paid in non-native currencies.
+use crate::tests::mock::*;
+use frame_support::{assert_ok, weights::GetDispatchInfo};
+use orml_traits::currency::MultiCurrency;
+use sp_runtime::{
+    MultiAddress::Id as MultiId,
+    traits::{Dispatchable, SignedExtension},
+};
+use currency_fees::ChargeTransactionFee;
+
+#[test]
+/// Pay fee of native currency transfer in KSM.
+fn transaction_fee_in_ksm() {
+    let initial_balance = to_unit(100, CurrencyId::NATIVE);
+    let initial_ksm = to_unit(100, CurrencyId::KSM);
+    let to_transfer = initial_balance / 2;
+
+    RuntimeBuilder::new()
+        .set_balances(vec![
+            (
+                Accounts::ALICE.account(),
+                CurrencyId::NATIVE,
+                initial_balance,
+            ),
+            (Accounts::ALICE.account(), CurrencyId::KSM, initial_ksm),
+        ])
+        .build()
+        .execute_with(|| {
+            let call = Call::Balances(pallet_balances::Call::transfer {
+                dest: MultiId(Accounts::BOB.account()),
+                value: to_transfer,
+            });
+            let info = call.get_dispatch_info();
+            let len = 100;
+
+            let native_fee = TransactionPayment::compute_fee(len as u32, &info, 0);
+            let fee = CurrencyFees::convert_fee(CurrencyId::KSM, native_fee).unwrap();
+            assert!(fee > 0);
+
+            let pre = ChargeTransactionFee::<Runtime>::new(0, Some(CurrencyId::KSM))
+                .pre_dispatch(&Accounts::ALICE.account(), &call, &info, len)
+                .unwrap();
+            let post_info = call
+                .clone()
+                .dispatch(Origin::signed(Accounts::ALICE.account()))
+                .unwrap();
+            assert_ok!(ChargeTransactionFee::<Runtime>::post_dispatch(
+                pre,
+                &info,
+                &post_info,
+                len,
+                &Ok(())
+            ));
+
+            // The whole fee is charged in KSM and sent to the treasury.
+            assert_eq!(
+                Currencies::free_balance(CurrencyId::KSM, &Accounts::ALICE.account()),
+                initial_ksm - fee
+            );
+            assert_eq!(
+                Currencies::free_balance(CurrencyId::KSM, &TreasuryAccount::get()),
+                fee
+            );
+            assert_eq!(
+                Currencies::free_balance(CurrencyId::NATIVE, &Accounts::ALICE.account()),
+                initial_balance - to_transfer
+            );
+        });
+}
@@ -39,7 +39,8 @@ fn transaction_pause_balance() {
             assert_ok!(TransactionPause::pause_transaction(
                 Origin::root(),
                 b"Balances".to_vec(),
-                b"transfer".to_vec()
+                b"transfer".to_vec(),
+                None
             ));
 
             assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(&call));
@@ -106,7 +107,8 @@ fn transaction_pause_bad_origin() {
             TransactionPause::pause_transaction(
                 Origin::signed(Accounts::BOB.account()),
                 b"Balances".to_vec(),
-                b"transfer".to_vec()
+                b"transfer".to_vec(),
+                None
             ),
             BadOrigin
         );
@@ -122,6 +124,44 @@ fn transaction_pause_bad_origin() {
     });
 }
 
+#[test]
+/// Test pallet and function names are checked against runtime calls.
+fn transaction_pause_unknown_call() {
+    RuntimeBuilder::new().build().execute_with(|| {
+        assert_noop!(
+            TransactionPause::pause_transaction(
+                Origin::root(),
+                b"Mvm".to_vec(),
+                b"execut".to_vec(),
+                None
+            ),
+            transaction_pause::Error::<Runtime>::UnknownCall
+        );
+        assert_noop!(
+            TransactionPause::pause_transaction(
+                Origin::root(),
+                b"Tokens".to_vec(),
+                b"transfer".to_vec(),
+                None
+            ),
+            transaction_pause::Error::<Runtime>::UnknownCall
+        );
+
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::root(),
+            b"Mvm".to_vec(),
+            b"execute".to_vec(),
+            None
+        ));
+        assert_ok!(TransactionPause::pause_transaction(
+            Origin::root(),
+            b"Groupsign".to_vec(),
+            b"*".to_vec(),
+            None
+        ));
+    });
+}
+
 #[test]
 /// Test if we can pause extrinsics via genesis.
 fn transaction_pause_genesis() {
@@ -137,8 +177,8 @@ fn transaction_pause_genesis() {
             initial_balance,
         )])
         .set_paused(vec![
-            (b"Balances".to_vec(), b"transfer".to_vec()),
-            (b"Mvm".to_vec(), b"execute".to_vec()),
+            (b"Balances".to_vec(), b"transfer".to_vec(), None),
+            (b"Mvm".to_vec(), b"execute".to_vec(), None),
         ])
         .build()
         .execute_with(|| {
@@ -211,3 +251,109 @@ fn transaction_pause_genesis() {
             );
         });
 }
+
+#[test]
+/// Test if genesis pause expires at the configured block.
+fn transaction_pause_genesis_expiry() {
+    let currency_id = GetNativeCurrencyId::get();
+
+    let initial_balance = to_unit(100, currency_id);
+    let to_transfer = initial_balance / 2;
+
+    RuntimeBuilder::new()
+        .set_balances(vec![(
+            Accounts::ALICE.account(),
+            CurrencyId::NATIVE,
+            initial_balance,
+        )])
+        .set_paused(vec![(b"Balances".to_vec(), b"transfer".to_vec(), Some(3))])
+        .build()
+        .execute_with(|| {
+            let call = <Runtime as frame_system::Config>::Call::Balances(
+                pallet_balances::Call::transfer {
+                    dest: MultiId(Accounts::BOB.account()),
+                    value: to_transfer,
+                },
+            );
+
+            assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(&call));
+
+            run_to_block(2);
+            assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(&call));
+
+            run_to_block(3);
+            assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+                &call
+            ));
+
+            assert_ok!(call.dispatch(Origin::signed(Accounts::ALICE.account())));
+
+            assert_eq!(
+                Currencies::free_balance(currency_id, &Accounts::ALICE.account()),
+                initial_balance - to_transfer
+            );
+        });
+}
+
+#[test]
+/// Test if paused calls are rejected by transaction pool, including calls wrapped into groupsign and multisig.
+fn transaction_pause_pool_validation() {
+    use frame_support::weights::DispatchInfo;
+    use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
+    use transaction_pause::CheckPausedTransaction;
+
+    RuntimeBuilder::new()
+        .set_paused(vec![(b"Balances".to_vec(), b"transfer".to_vec(), None)])
+        .build()
+        .execute_with(|| {
+            let info = DispatchInfo::default();
+            let transfer = <Runtime as frame_system::Config>::Call::Balances(
+                pallet_balances::Call::transfer {
+                    dest: MultiId(Accounts::BOB.account()),
+                    value: 1,
+                },
+            );
+            let remark =
+                <Runtime as frame_system::Config>::Call::System(frame_system::Call::remark {
+                    remark: vec![],
+                });
+
+            let groupsign = |calls: Vec<Call>| {
+                Call::Groupsign(groupsign::Call::groupsign_batch_call {
+                    calls,
+                    signers: vec![Accounts::ALICE.account()],
+                    signatures: vec![],
+                    valid_since: 0,
+                    valid_thru: 100,
+                })
+            };
+            let multisig = |call: Call| {
+                Call::MultiSig(pallet_multisig::Call::as_multi_threshold_1 {
+                    other_signatories: vec![Accounts::BOB.account()],
+                    call: Box::new(call),
+                })
+            };
+
+            let validate = |call: &Call| {
+                CheckPausedTransaction::<Runtime>::new().validate(
+                    &Accounts::ALICE.account(),
+                    call,
+                    &info,
+                    0,
+                )
+            };
+
+            assert_eq!(validate(&transfer), Err(InvalidTransaction::Call.into()));
+            assert_eq!(
+                validate(&groupsign(vec![remark.clone(), transfer.clone()])),
+                Err(InvalidTransaction::Call.into())
+            );
+            assert_eq!(
+                validate(&multisig(groupsign(vec![transfer]))),
+                Err(InvalidTransaction::Call.into())
+            );
+
+            assert_ok!(validate(&groupsign(vec![remark.clone()])));
+            assert_ok!(validate(&multisig(remark)));
+        });
+}